@@ -3,6 +3,7 @@ use serde::de::Deserializer;
 use serde::de::{EnumAccess, MapAccess, SeqAccess, VariantAccess};
 
 use crate::options_impl::ExtraOptions;
+use crate::path::PathSegment;
 use crate::reporter::Reporter;
 use crate::state::InterventionReason;
 use crate::util::DeserializeKind;
@@ -20,12 +21,16 @@ where
     pub(crate) inner: Inner,
     pub(crate) collection_has_ended: bool,
     pub(crate) inside_element: Option<InsideElement>,
+    /// How many sequence elements or map entries we have entered so far.
+    pub(crate) n_elements_entered: usize,
 }
 
 #[derive(Debug)]
 pub(crate) struct InsideElement {
     pub(crate) corresponding_halting_point: HaltingPoint,
     pub(crate) halting_point_is_on_stack: bool,
+    /// Whether we pushed a segment onto [`AttemptState::path`] for this element.
+    pub(crate) path_segment_is_on_stack: bool,
 }
 
 impl<Inner, Extra> Access<'_, Inner, Extra>
@@ -52,9 +57,20 @@ where
         self.inside_element = Some(InsideElement {
             corresponding_halting_point,
             halting_point_is_on_stack,
+            path_segment_is_on_stack: false,
         });
     }
 
+    /// Remember where we are in the data, until we leave the element.
+    fn enter_path_segment(&mut self, segment: PathSegment) {
+        let Some(inside_element) = self.inside_element.as_mut() else {
+            error!("access: path segment outside of element");
+            return;
+        };
+        inside_element.path_segment_is_on_stack = true;
+        self.attempt.path.push(segment);
+    }
+
     fn leave_element(&mut self) {
         trace!(?self.inside_element, ?self.attempt.halting_point_stack, "leaving");
         let Some(inside_element) = self.inside_element.take() else {
@@ -62,6 +78,10 @@ where
             return;
         };
 
+        if inside_element.path_segment_is_on_stack {
+            self.attempt.path.pop();
+        }
+
         if inside_element.halting_point_is_on_stack {
             loop {
                 let last_point = self
@@ -147,16 +167,21 @@ where
         };
 
         self.enter_element(this_halting_point);
+        self.enter_path_segment(PathSegment::Index(self.n_elements_entered));
+        self.n_elements_entered += 1;
 
         self.global.reporter.report_seq_next_element_start();
+        let n_data_visits_before = self.attempt.n_data_visits;
         let wrapped_seed = InnerDeserializeSeed {
             global: self.global,
             attempt: self.attempt,
             is_for_key_or_variant: false,
             is_for_map_value: false,
+            started_element: Some(self.n_elements_entered - 1),
             inner: seed,
         };
         let result = self.inner.next_element_seed(wrapped_seed);
+        let had_data = self.attempt.n_data_visits != n_data_visits_before;
         self.global.reporter.report_seq_next_element_finish(
             matches!(result, Ok(Some(_))),
             erase_error_ref(&result),
@@ -167,7 +192,22 @@ where
         }
         self.leave_element();
 
+        if matches!(result, Ok(None)) {
+            self.attempt.note_collection_ended();
+        }
+
         match result {
+            Ok(Some(_))
+                if !had_data
+                    && self.kind == DeserializeKind::Seq
+                    && self.global.config.behavior.skip_seq_item_without_data
+                    && !self.attempt.intervention_is_empty() =>
+            {
+                // Only fallbacks, so this element was not in the input.
+                self.global.reporter.report_fallback_no_element();
+                self.collection_has_ended = true;
+                Ok(None)
+            }
             Ok(Some(v)) => Ok(Some(v)),
             Ok(None) => {
                 self.collection_has_ended = true;
@@ -175,6 +215,7 @@ where
             }
             Err(_) if self.should_fallback_skip_item() => {
                 self.global.reporter.report_fallback_no_element();
+                self.attempt.note_fallback(self.kind);
                 self.collection_has_ended = true;
                 Ok(None)
             }
@@ -212,6 +253,8 @@ where
         };
 
         self.enter_element(this_halting_point);
+        self.attempt.pending_key = None;
+        self.n_elements_entered += 1;
 
         self.global.reporter.report_map_next_key_start();
         let wrapped_seed = InnerDeserializeSeed {
@@ -219,6 +262,7 @@ where
             attempt: self.attempt,
             is_for_key_or_variant: true,
             is_for_map_value: false,
+            started_element: Some(self.n_elements_entered - 1),
             inner: seed,
         };
         let result = self.inner.next_key_seed(wrapped_seed);
@@ -229,9 +273,15 @@ where
             self.attempt
                 .activate_intervention(InterventionReason::VisitError);
         }
-        if !matches!(result, Ok(Some(_))) {
+        if matches!(result, Ok(Some(_))) {
+            let key = self.attempt.pending_key.take().unwrap_or_default();
+            self.enter_path_segment(PathSegment::Key(key));
+        } else {
             self.leave_element();
         }
+        if matches!(result, Ok(None)) {
+            self.attempt.note_collection_ended();
+        }
 
         match result {
             Ok(Some(v)) => Ok(Some(v)),
@@ -241,6 +291,7 @@ where
             }
             Err(_) if self.should_fallback_skip_item() => {
                 self.global.reporter.report_fallback_no_element();
+                self.attempt.note_fallback(self.kind);
                 self.collection_has_ended = true;
                 Ok(None)
             }
@@ -265,6 +316,7 @@ where
             attempt: self.attempt,
             is_for_key_or_variant: false,
             is_for_map_value: true,
+            started_element: None,
             inner: seed,
         };
         let result = self.inner.next_value_seed(wrapped_seed);
@@ -302,6 +354,7 @@ where
             attempt: self.attempt,
             is_for_key_or_variant: true,
             is_for_map_value: false,
+            started_element: None,
             inner: seed,
         });
         self.global
@@ -322,6 +375,7 @@ where
                 inner: inner_variant,
                 collection_has_ended: false,
                 inside_element: None,
+                n_elements_entered: 0,
             },
         ))
     }
@@ -357,6 +411,7 @@ where
             Ok(()) => Ok(()),
             Err(_) if self.global.config.behavior.fallback_unit_variant => {
                 self.global.reporter.report_fallback(None);
+                self.attempt.note_fallback(self.kind);
                 Ok(())
             }
             Err(e) => Err(e),
@@ -373,6 +428,7 @@ where
             attempt: self.attempt,
             is_for_key_or_variant: false,
             is_for_map_value: true,
+            started_element: None,
             inner: seed,
         });
         process_variant_result(&result, self.attempt, &mut self.global.reporter);
@@ -85,6 +85,8 @@ where
                 .report_fallback(erase_error_ref(&result));
 
             if let Ok(value) = result {
+                deserializer.attempt.last_deserialize_used_fallback = true;
+                deserializer.attempt.note_fallback(kind);
                 return Ok(value);
             }
         } else {
@@ -93,6 +95,7 @@ where
         }
     }
 
+    deserializer.attempt.last_deserialize_used_fallback = false;
     match (result, value) {
         (Ok(()), Some(value)) => Ok(value),
         (Ok(()), None) => {
@@ -0,0 +1,77 @@
+use super::Deserializer;
+use crate::error::Error;
+use crate::incomplete::{STATUS_COMPLETE, STATUS_MISSING, STATUS_TRUNCATED};
+use crate::options_impl::ExtraOptions;
+
+/// Hands the visitor of [`crate::Incomplete`] its value, and then whether we had to
+/// intervene while deserializing that value.
+pub(crate) struct IncompleteAccess<'a, Inner, Extra>
+where
+    Extra: ExtraOptions,
+{
+    /// `Some` until we have deserialized the value.
+    deserializer: Option<Deserializer<'a, Inner, Extra>>,
+    /// `Some` after we have deserialized the value, until we handed out the status.
+    status: Option<&'static str>,
+}
+
+impl<'a, Inner, Extra> IncompleteAccess<'a, Inner, Extra>
+where
+    Extra: ExtraOptions,
+{
+    pub(crate) fn new(deserializer: Deserializer<'a, Inner, Extra>) -> Self {
+        Self {
+            deserializer: Some(deserializer),
+            status: None,
+        }
+    }
+}
+
+impl<'de, Inner, Extra> serde::de::SeqAccess<'de> for IncompleteAccess<'_, Inner, Extra>
+where
+    Inner: serde::Deserializer<'de>,
+    Extra: ExtraOptions,
+{
+    type Error = Error<Inner::Error>;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: serde::de::DeserializeSeed<'de>,
+    {
+        if let Some(Deserializer {
+            global,
+            attempt,
+            is_at_root,
+            is_for_key_or_variant,
+            is_for_map_value,
+            inner,
+        }) = self.deserializer.take()
+        {
+            let n_events_before = attempt.n_truncation_events();
+            let value = seed.deserialize(Deserializer {
+                global,
+                attempt: &mut *attempt,
+                is_at_root,
+                is_for_key_or_variant,
+                is_for_map_value,
+                inner,
+            })?;
+
+            self.status = Some(if attempt.last_deserialize_used_fallback {
+                STATUS_MISSING
+            } else if attempt.n_truncation_events() > n_events_before {
+                STATUS_TRUNCATED
+            } else {
+                STATUS_COMPLETE
+            });
+            return Ok(Some(value));
+        }
+
+        match self.status.take() {
+            Some(status) => seed
+                .deserialize(serde::de::value::BorrowedStrDeserializer::new(status))
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 mod access;
 mod deserializer;
 pub(crate) mod empty_access;
+mod incomplete_access;
 mod visit;
 
 use std::fmt::Display;
@@ -75,6 +76,8 @@ where
     pub(crate) attempt: &'a mut AttemptState<Extra>,
     pub(crate) is_for_key_or_variant: bool,
     pub(crate) is_for_map_value: bool,
+    /// If this is for an element of a sequence or the key of a map entry: its index.
+    pub(crate) started_element: Option<usize>,
     pub(crate) inner: Inner,
 }
 
@@ -89,15 +92,55 @@ where
     where
         D: serde::Deserializer<'de>,
     {
+        if let Some(index) = self.started_element {
+            self.attempt.note_element_started(index);
+        }
+
+        let deserializer = Deserializer {
+            global: self.global,
+            attempt: self.attempt,
+            is_at_root: false,
+            is_for_key_or_variant: self.is_for_key_or_variant,
+            is_for_map_value: self.is_for_map_value,
+            inner: deserializer,
+        };
+
+        #[cfg(feature = "erased-serde")]
+        if !self.is_for_key_or_variant
+            && (self.started_element.is_some() || self.is_for_map_value)
+            && deserializer.attempt.wants_callback()
+        {
+            return deserialize_for_callback(self.inner, deserializer);
+        }
+
         self.inner
-            .deserialize(Deserializer {
-                global: self.global,
-                attempt: self.attempt,
-                is_at_root: false,
-                is_for_key_or_variant: self.is_for_key_or_variant,
-                is_for_map_value: self.is_for_map_value,
-                inner: deserializer,
-            })
+            .deserialize(deserializer)
             .map_err(Error::unpack_or_make_custom)
     }
 }
+
+/// Read the value into memory first, so that we can show it to a callback once it is
+/// followed by a separator. Then give it to the seed.
+#[cfg(feature = "erased-serde")]
+fn deserialize_for_callback<'de, Inner, D, Extra>(
+    seed: Inner,
+    deserializer: Deserializer<'_, D, Extra>,
+) -> Result<Inner::Value, D::Error>
+where
+    Inner: serde::de::DeserializeSeed<'de>,
+    D: serde::Deserializer<'de>,
+    Extra: ExtraOptions,
+{
+    use serde::de::{Deserialize as _, Error as _};
+
+    let attempt = &mut *deserializer.attempt;
+    let n_truncation_events_before = attempt.n_truncation_events();
+    let deserializer = Deserializer {
+        attempt: &mut *attempt,
+        ..deserializer
+    };
+    let value = crate::callbacks::Captured::deserialize(deserializer)
+        .map_err(Error::unpack_or_make_custom)?;
+    attempt.note_value_for_callback(value.clone(), n_truncation_events_before);
+    seed.deserialize(value).map_err(D::Error::custom)
+}
@@ -1,6 +1,7 @@
 use std::error::Error as StdError;
 
 use super::access::Access;
+use super::incomplete_access::IncompleteAccess;
 use super::{erase_error_ref, Deserializer};
 use crate::options_impl::ExtraOptions;
 use crate::reporter::Reporter;
@@ -54,6 +55,17 @@ where
         .take()
         .expect("inner visitor is present when running Visitor");
 
+    if !matches!(
+        visitor.kind,
+        DeserializeKind::UnitStruct { .. }
+            | DeserializeKind::NewtypeStruct { .. }
+            | DeserializeKind::Tuple { .. }
+            | DeserializeKind::TupleStruct { .. }
+            | DeserializeKind::Struct { .. }
+    ) {
+        visitor.attempt.n_data_visits += 1;
+    }
+
     let result = do_visit(
         inner_visitor,
         (visitor.global, visitor.attempt, visitor.kind),
@@ -94,6 +106,9 @@ where
     where
         E: serde::de::Error,
     {
+        if self.is_for_key_or_variant {
+            self.attempt.note_key(v);
+        }
         self.global.reporter.report_recv_visit_start_bool(v);
 
         framework(
@@ -109,6 +124,9 @@ where
     where
         E: serde::de::Error,
     {
+        if self.is_for_key_or_variant {
+            self.attempt.note_key(v);
+        }
         self.global.reporter.report_recv_visit_start_i8(v);
 
         framework(
@@ -124,6 +142,9 @@ where
     where
         E: serde::de::Error,
     {
+        if self.is_for_key_or_variant {
+            self.attempt.note_key(v);
+        }
         self.global.reporter.report_recv_visit_start_i16(v);
 
         framework(
@@ -139,6 +160,9 @@ where
     where
         E: serde::de::Error,
     {
+        if self.is_for_key_or_variant {
+            self.attempt.note_key(v);
+        }
         self.global.reporter.report_recv_visit_start_i32(v);
 
         framework(
@@ -154,6 +178,9 @@ where
     where
         E: serde::de::Error,
     {
+        if self.is_for_key_or_variant {
+            self.attempt.note_key(v);
+        }
         self.global.reporter.report_recv_visit_start_i64(v);
 
         framework(
@@ -169,6 +196,9 @@ where
     where
         E: serde::de::Error,
     {
+        if self.is_for_key_or_variant {
+            self.attempt.note_key(v);
+        }
         self.global.reporter.report_recv_visit_start_i128(v);
 
         framework(
@@ -184,6 +214,9 @@ where
     where
         E: serde::de::Error,
     {
+        if self.is_for_key_or_variant {
+            self.attempt.note_key(v);
+        }
         self.global.reporter.report_recv_visit_start_u8(v);
 
         framework(
@@ -199,6 +232,9 @@ where
     where
         E: serde::de::Error,
     {
+        if self.is_for_key_or_variant {
+            self.attempt.note_key(v);
+        }
         self.global.reporter.report_recv_visit_start_u16(v);
 
         framework(
@@ -214,6 +250,9 @@ where
     where
         E: serde::de::Error,
     {
+        if self.is_for_key_or_variant {
+            self.attempt.note_key(v);
+        }
         self.global.reporter.report_recv_visit_start_u32(v);
 
         framework(
@@ -229,6 +268,9 @@ where
     where
         E: serde::de::Error,
     {
+        if self.is_for_key_or_variant {
+            self.attempt.note_key(v);
+        }
         self.global.reporter.report_recv_visit_start_u64(v);
 
         framework(
@@ -244,6 +286,9 @@ where
     where
         E: serde::de::Error,
     {
+        if self.is_for_key_or_variant {
+            self.attempt.note_key(v);
+        }
         self.global.reporter.report_recv_visit_start_u128(v);
 
         framework(
@@ -259,6 +304,9 @@ where
     where
         E: serde::de::Error,
     {
+        if self.is_for_key_or_variant {
+            self.attempt.note_key(v);
+        }
         self.global.reporter.report_recv_visit_start_f32(v);
 
         framework(
@@ -274,6 +322,9 @@ where
     where
         E: serde::de::Error,
     {
+        if self.is_for_key_or_variant {
+            self.attempt.note_key(v);
+        }
         self.global.reporter.report_recv_visit_start_f64(v);
 
         framework(
@@ -289,6 +340,9 @@ where
     where
         E: serde::de::Error,
     {
+        if self.is_for_key_or_variant {
+            self.attempt.note_key(v);
+        }
         self.global.reporter.report_recv_visit_start_char(v);
 
         framework(
@@ -304,6 +358,11 @@ where
     where
         E: serde::de::Error,
     {
+        if self.global.config.is_placeholder_stringlike(&v) {
+            return Err(E::custom(
+                "placeholder for a value that is not in the input",
+            ));
+        }
         if self.global.config.remove_tag_from_stringlike(&mut v) {
             if !self.is_for_key_or_variant
                 || self
@@ -313,6 +372,7 @@ where
                     .allow_incomplete_string_in_key_or_variant
             {
                 self.global.reporter.report_allow_incomplete_string();
+                self.attempt.note_incomplete_string();
             } else {
                 self.global.reporter.report_reject_incomplete_string();
                 return Err(E::custom(
@@ -321,6 +381,10 @@ where
             }
         }
 
+        if self.is_for_key_or_variant {
+            self.attempt.note_key(v);
+        }
+        self.attempt.n_string_bytes += v.len();
         self.global.reporter.report_recv_visit_start_str(v);
 
         framework(
@@ -336,6 +400,11 @@ where
     where
         E: serde::de::Error,
     {
+        if self.global.config.is_placeholder_stringlike(&v) {
+            return Err(E::custom(
+                "placeholder for a value that is not in the input",
+            ));
+        }
         if self.global.config.remove_tag_from_stringlike(&mut v) {
             if !self.is_for_key_or_variant
                 || self
@@ -345,6 +414,7 @@ where
                     .allow_incomplete_string_in_key_or_variant
             {
                 self.global.reporter.report_allow_incomplete_string();
+                self.attempt.note_incomplete_string();
             } else {
                 self.global.reporter.report_reject_incomplete_string();
                 return Err(E::custom(
@@ -353,6 +423,10 @@ where
             }
         }
 
+        if self.is_for_key_or_variant {
+            self.attempt.note_key(v);
+        }
+        self.attempt.n_string_bytes += v.len();
         self.global.reporter.report_recv_visit_start_borrowed_str(v);
 
         framework(
@@ -368,6 +442,11 @@ where
     where
         E: serde::de::Error,
     {
+        if self.global.config.is_placeholder_stringlike(&v) {
+            return Err(E::custom(
+                "placeholder for a value that is not in the input",
+            ));
+        }
         if self.global.config.remove_tag_from_stringlike(&mut v) {
             if !self.is_for_key_or_variant
                 || self
@@ -377,6 +456,7 @@ where
                     .allow_incomplete_string_in_key_or_variant
             {
                 self.global.reporter.report_allow_incomplete_string();
+                self.attempt.note_incomplete_string();
             } else {
                 self.global.reporter.report_reject_incomplete_string();
                 return Err(E::custom(
@@ -385,6 +465,10 @@ where
             }
         }
 
+        if self.is_for_key_or_variant {
+            self.attempt.note_key(&v);
+        }
+        self.attempt.n_string_bytes += v.len();
         self.global.reporter.report_recv_visit_start_string(&v);
 
         framework(
@@ -400,6 +484,11 @@ where
     where
         E: serde::de::Error,
     {
+        if self.global.config.is_placeholder_stringlike(&v) {
+            return Err(E::custom(
+                "placeholder for a value that is not in the input",
+            ));
+        }
         if self.global.config.remove_tag_from_stringlike(&mut v) {
             if !self.is_for_key_or_variant
                 || self
@@ -409,6 +498,7 @@ where
                     .allow_incomplete_string_in_key_or_variant
             {
                 self.global.reporter.report_allow_incomplete_string();
+                self.attempt.note_incomplete_string();
             } else {
                 self.global.reporter.report_reject_incomplete_string();
                 return Err(E::custom(
@@ -417,6 +507,10 @@ where
             }
         }
 
+        if self.is_for_key_or_variant {
+            self.attempt.note_key(String::from_utf8_lossy(v));
+        }
+        self.attempt.n_string_bytes += v.len();
         self.global.reporter.report_recv_visit_start_bytes(v);
 
         framework(
@@ -432,6 +526,11 @@ where
     where
         E: serde::de::Error,
     {
+        if self.global.config.is_placeholder_stringlike(&v) {
+            return Err(E::custom(
+                "placeholder for a value that is not in the input",
+            ));
+        }
         if self.global.config.remove_tag_from_stringlike(&mut v) {
             if !self.is_for_key_or_variant
                 || self
@@ -441,6 +540,7 @@ where
                     .allow_incomplete_string_in_key_or_variant
             {
                 self.global.reporter.report_allow_incomplete_string();
+                self.attempt.note_incomplete_string();
             } else {
                 self.global.reporter.report_reject_incomplete_string();
                 return Err(E::custom(
@@ -449,6 +549,10 @@ where
             }
         }
 
+        if self.is_for_key_or_variant {
+            self.attempt.note_key(String::from_utf8_lossy(v));
+        }
+        self.attempt.n_string_bytes += v.len();
         self.global
             .reporter
             .report_recv_visit_start_borrowed_bytes(v);
@@ -466,6 +570,11 @@ where
     where
         E: serde::de::Error,
     {
+        if self.global.config.is_placeholder_stringlike(&v) {
+            return Err(E::custom(
+                "placeholder for a value that is not in the input",
+            ));
+        }
         if self.global.config.remove_tag_from_stringlike(&mut v) {
             if !self.is_for_key_or_variant
                 || self
@@ -475,6 +584,7 @@ where
                     .allow_incomplete_string_in_key_or_variant
             {
                 self.global.reporter.report_allow_incomplete_string();
+                self.attempt.note_incomplete_string();
             } else {
                 self.global.reporter.report_reject_incomplete_string();
                 return Err(E::custom(
@@ -483,6 +593,10 @@ where
             }
         }
 
+        if self.is_for_key_or_variant {
+            self.attempt.note_key(String::from_utf8_lossy(&v));
+        }
+        self.attempt.n_string_bytes += v.len();
         self.global.reporter.report_recv_visit_start_byte_buf(&v);
 
         framework(
@@ -513,11 +627,41 @@ where
     where
         D: serde::Deserializer<'de>,
     {
+        // Inside `Some`, we know that there is an option in the input. If deserializing
+        // its contents fails, then we can still halt here and pretend it was `None`.
+        let mut stack_len_before = None;
+        if self.global.config.behavior.backtrack_option_none && self.attempt.intervention_is_empty()
+        {
+            let Some(this_halting_point) = self.attempt.new_halting_point_and_check_continue()
+            else {
+                return self.visit_none();
+            };
+            stack_len_before = Some(self.attempt.halting_point_stack.len());
+            self.attempt.halting_point_stack.push(this_halting_point);
+        }
+
         self.global.reporter.report_recv_visit_start_some();
         let is_at_root = self.is_at_root;
 
-        framework(
-            self,
+        let Visitor {
+            global,
+            attempt,
+            kind,
+            is_at_root: _,
+            is_for_key_or_variant,
+            inner,
+            value,
+        } = self;
+        let result = framework(
+            Visitor {
+                global,
+                attempt: &mut *attempt,
+                kind,
+                is_at_root,
+                is_for_key_or_variant,
+                inner,
+                value,
+            },
             |visitor, (global, attempt, _kind)| {
                 let wrapped = Deserializer {
                     global,
@@ -534,7 +678,13 @@ where
             |reporter, error| {
                 reporter.report_recv_visit_finish_some(error);
             },
-        )
+        );
+
+        if let (Ok(()), Some(stack_len_before)) = (&result, stack_len_before) {
+            // Later errors should not make us turn this `Some` into `None`.
+            attempt.halting_point_stack.truncate(stack_len_before);
+        }
+        result
     }
 
     fn visit_unit<E>(self) -> Result<Self::Value, E>
@@ -563,7 +713,7 @@ where
 
         framework(
             self,
-            |visitor, (global, attempt, _kind)| {
+            |visitor, (global, attempt, kind)| {
                 let wrapped = Deserializer {
                     global,
                     attempt,
@@ -572,9 +722,16 @@ where
                     is_for_map_value: false,
                     inner: deserializer,
                 };
-                visitor
-                    .visit_newtype_struct(wrapped)
-                    .map_err(Error::unpack_or_make_custom)
+                // `Incomplete` gets its value, and then whether we intervened in it.
+                let result = if matches!(
+                    kind,
+                    DeserializeKind::NewtypeStruct { name } if name == crate::incomplete::NEWTYPE_NAME
+                ) {
+                    visitor.visit_seq(IncompleteAccess::new(wrapped))
+                } else {
+                    visitor.visit_newtype_struct(wrapped)
+                };
+                result.map_err(Error::unpack_or_make_custom)
             },
             |reporter, error| {
                 reporter.report_recv_visit_finish_newtype_struct(error);
@@ -591,14 +748,18 @@ where
         framework(
             self,
             |visitor, (global, attempt, kind)| {
-                visitor.visit_seq(Access {
+                attempt.enter_collection();
+                let result = visitor.visit_seq(Access {
                     global,
-                    attempt,
+                    attempt: &mut *attempt,
                     kind,
                     inner: seq,
                     collection_has_ended: false,
                     inside_element: None,
-                })
+                    n_elements_entered: 0,
+                });
+                attempt.leave_collection();
+                result
             },
             |reporter, error| {
                 reporter.report_recv_visit_finish_seq(error);
@@ -615,14 +776,18 @@ where
         framework(
             self,
             |visitor, (global, attempt, kind)| {
-                visitor.visit_map(Access {
+                attempt.enter_collection();
+                let result = visitor.visit_map(Access {
                     global,
-                    attempt,
+                    attempt: &mut *attempt,
                     kind,
                     inner: map,
                     collection_has_ended: false,
                     inside_element: None,
-                })
+                    n_elements_entered: 0,
+                });
+                attempt.leave_collection();
+                result
             },
             |reporter, error| {
                 reporter.report_recv_visit_finish_map(error);
@@ -646,6 +811,7 @@ where
                     inner: data,
                     collection_has_ended: false,
                     inside_element: None,
+                    n_elements_entered: 0,
                 })
             },
             |reporter, error| {
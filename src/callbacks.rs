@@ -0,0 +1,514 @@
+//! Callbacks for sub-values that became complete, see [`Options::on_complete`].
+
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::Arc;
+
+use serde::de::{self, IntoDeserializer as _};
+use serde::ser::{SerializeMap as _, SerializeSeq as _};
+
+use crate::options_impl::ExtraOptions;
+use crate::path::{Path, PathSegment};
+use crate::Options;
+
+type CallbackFn = dyn Fn(&Path, &dyn erased_serde::Serialize) + Send + Sync;
+
+impl<Extra: ExtraOptions> Options<Extra> {
+    /// Call `callback` with every value at a path that matches `pattern`, as soon as the
+    /// value is complete.
+    ///
+    /// The pattern is a JSON Pointer like `/results/3/name`, where a `*` segment matches
+    /// any key or index. So `/results/*` matches every element of the `results` array.
+    /// A value is complete once the input has a separator or closing bracket after it.
+    /// Each path is reported once per parse. [`crate::IncrementalDeserializer`] remembers
+    /// what it reported across pushes, so you can use it to act on results while they
+    /// stream in.
+    ///
+    /// ```
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let seen = Arc::new(Mutex::new(Vec::new()));
+    /// let seen_in_callback = seen.clone();
+    /// let value: serde_json::Value = deser_incomplete::Options::new_json()
+    ///     .on_complete("/results/*", move |path, value| {
+    ///         let value = serde_json::to_string(value).unwrap();
+    ///         seen_in_callback.lock().unwrap().push(format!("{path} = {value}"));
+    ///     })
+    ///     .deserialize_from_json_str(r#"{"results": [{"id": 1}, {"id": 2}, {"id""#.into())
+    ///     .unwrap();
+    ///
+    /// assert_eq!(*seen.lock().unwrap(), [r#"/results/0 = {"id":1}"#, r#"/results/1 = {"id":2}"#]);
+    /// assert_eq!(value["results"][2], serde_json::json!({}));
+    /// ```
+    ///
+    /// To show the value to the callback, we first read it into memory, and only then give
+    /// it to your type. This needs a self-describing data format such as JSON, YAML or
+    /// MessagePack. Your type can't borrow from the input at these paths, and errors there
+    /// make us drop the whole value instead of applying fallbacks inside it.
+    ///
+    /// The root value is never reported.
+    ///
+    /// # Panics
+    ///
+    /// If the pattern is neither empty nor starts with `/`.
+    pub fn on_complete(
+        mut self,
+        pattern: &str,
+        callback: impl Fn(&Path, &dyn erased_serde::Serialize) + Send + Sync + 'static,
+    ) -> Self {
+        self.callbacks.add(pattern, Arc::new(callback));
+        self
+    }
+}
+
+/// The callbacks of an [`Options`].
+#[derive(Clone, Default)]
+pub(crate) struct Callbacks(Vec<Callback>);
+
+#[derive(Clone)]
+struct Callback {
+    pattern: Vec<PatternSegment>,
+    f: Arc<CallbackFn>,
+}
+
+/// Where we already called each callback, by its position in [`Callbacks`]. This is
+/// kept across attempts, and an incremental parse carries it over to the next push.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Reported(Vec<HashSet<Path>>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum PatternSegment {
+    Any,
+    Exact(String),
+}
+
+impl fmt::Debug for Callbacks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.0.iter().map(|callback| &callback.pattern))
+            .finish()
+    }
+}
+
+impl Callbacks {
+    pub(crate) fn add(&mut self, pattern: &str, f: Arc<CallbackFn>) {
+        self.0.push(Callback {
+            pattern: parse_pattern(pattern),
+            f,
+        });
+    }
+
+    /// Whether a callback still wants the value at this path.
+    pub(crate) fn wants(&self, path: &[PathSegment], reported: &Reported) -> bool {
+        let path = Path(path.to_vec());
+        self.0.iter().enumerate().any(|(i, callback)| {
+            callback.matches(&path.0) && !reported.0.get(i).is_some_and(|seen| seen.contains(&path))
+        })
+    }
+
+    /// Call every callback that matches, unless it saw this path before.
+    pub(crate) fn fire(&self, path: &Path, value: &Captured, reported: &mut Reported) {
+        for (i, callback) in self.0.iter().enumerate() {
+            if callback.matches(&path.0) && reported.insert(i, path) {
+                (callback.f)(path, value);
+            }
+        }
+    }
+}
+
+impl Reported {
+    /// Remember that callback `i` saw this path. Returns whether it is new.
+    fn insert(&mut self, i: usize, path: &Path) -> bool {
+        if self.0.len() <= i {
+            self.0.resize_with(i + 1, HashSet::new);
+        }
+        self.0[i].insert(path.clone())
+    }
+}
+
+impl Callback {
+    fn matches(&self, path: &[PathSegment]) -> bool {
+        self.pattern.len() == path.len()
+            && self
+                .pattern
+                .iter()
+                .zip(path)
+                .all(|(pattern, segment)| match (pattern, segment) {
+                    (PatternSegment::Any, _) => true,
+                    (PatternSegment::Exact(exact), PathSegment::Key(key)) => exact == key,
+                    (PatternSegment::Exact(exact), PathSegment::Index(index)) => {
+                        *exact == index.to_string()
+                    }
+                })
+    }
+}
+
+/// Parse a JSON Pointer where `*` matches any one segment.
+fn parse_pattern(pattern: &str) -> Vec<PatternSegment> {
+    if pattern.is_empty() {
+        return Vec::new();
+    }
+    let Some(rest) = pattern.strip_prefix('/') else {
+        panic!("path pattern {pattern:?} should be empty or start with `/`");
+    };
+    rest.split('/')
+        .map(|segment| match segment {
+            "*" => PatternSegment::Any,
+            _ => PatternSegment::Exact(segment.replace("~1", "/").replace("~0", "~")),
+        })
+        .collect()
+}
+
+/// A value that we read once, so that we can both show it to a callback and give it to
+/// the type that wanted it.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Captured {
+    Bool(bool),
+    I64(i64),
+    U64(u64),
+    F64(f64),
+    String(String),
+    Bytes(Vec<u8>),
+    None,
+    Some(Box<Captured>),
+    Unit,
+    Seq(Vec<Captured>),
+    Map(Vec<(Captured, Captured)>),
+}
+
+impl serde::Serialize for Captured {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Captured::Bool(v) => serializer.serialize_bool(*v),
+            Captured::I64(v) => serializer.serialize_i64(*v),
+            Captured::U64(v) => serializer.serialize_u64(*v),
+            Captured::F64(v) => serializer.serialize_f64(*v),
+            Captured::String(v) => serializer.serialize_str(v),
+            Captured::Bytes(v) => serializer.serialize_bytes(v),
+            Captured::None => serializer.serialize_none(),
+            Captured::Some(v) => serializer.serialize_some(v),
+            Captured::Unit => serializer.serialize_unit(),
+            Captured::Seq(elements) => {
+                let mut seq = serializer.serialize_seq(Some(elements.len()))?;
+                for element in elements {
+                    seq.serialize_element(element)?;
+                }
+                seq.end()
+            }
+            Captured::Map(entries) => {
+                let mut map = serializer.serialize_map(Some(entries.len()))?;
+                for (key, value) in entries {
+                    map.serialize_entry(key, value)?;
+                }
+                map.end()
+            }
+        }
+    }
+}
+
+impl<'de> de::Deserialize<'de> for Captured {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(CapturedVisitor)
+    }
+}
+
+struct CapturedVisitor;
+
+impl<'de> de::Visitor<'de> for CapturedVisitor {
+    type Value = Captured;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("any value")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Captured, E> {
+        Ok(Captured::Bool(v))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Captured, E> {
+        Ok(Captured::I64(v))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Captured, E> {
+        Ok(Captured::U64(v))
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Captured, E> {
+        Ok(Captured::F64(v))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Captured, E> {
+        Ok(Captured::String(v.to_owned()))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Captured, E> {
+        Ok(Captured::String(v))
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Captured, E> {
+        Ok(Captured::Bytes(v.to_owned()))
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Captured, E> {
+        Ok(Captured::Bytes(v))
+    }
+
+    fn visit_none<E>(self) -> Result<Captured, E> {
+        Ok(Captured::None)
+    }
+
+    fn visit_some<D: de::Deserializer<'de>>(self, deserializer: D) -> Result<Captured, D::Error> {
+        de::Deserialize::deserialize(deserializer).map(|v| Captured::Some(Box::new(v)))
+    }
+
+    fn visit_unit<E>(self) -> Result<Captured, E> {
+        Ok(Captured::Unit)
+    }
+
+    fn visit_newtype_struct<D: de::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Captured, D::Error> {
+        de::Deserialize::deserialize(deserializer)
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Captured, A::Error> {
+        let mut elements = Vec::new();
+        while let Some(element) = seq.next_element()? {
+            elements.push(element);
+        }
+        Ok(Captured::Seq(elements))
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Captured, A::Error> {
+        let mut entries = Vec::new();
+        while let Some(entry) = map.next_entry()? {
+            entries.push(entry);
+        }
+        Ok(Captured::Map(entries))
+    }
+}
+
+impl<'de> de::Deserializer<'de> for Captured {
+    type Error = de::value::Error;
+
+    fn deserialize_any<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self {
+            Captured::Bool(v) => visitor.visit_bool(v),
+            Captured::I64(v) => visitor.visit_i64(v),
+            Captured::U64(v) => visitor.visit_u64(v),
+            Captured::F64(v) => visitor.visit_f64(v),
+            Captured::String(v) => visitor.visit_string(v),
+            Captured::Bytes(v) => visitor.visit_byte_buf(v),
+            Captured::None => visitor.visit_none(),
+            Captured::Some(v) => visitor.visit_some(*v),
+            Captured::Unit => visitor.visit_unit(),
+            Captured::Seq(elements) => {
+                visitor.visit_seq(de::value::SeqDeserializer::new(elements.into_iter()))
+            }
+            Captured::Map(entries) => {
+                visitor.visit_map(de::value::MapDeserializer::new(entries.into_iter()))
+            }
+        }
+    }
+
+    fn deserialize_option<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self {
+            Captured::None | Captured::Unit => visitor.visit_none(),
+            Captured::Some(v) => visitor.visit_some(*v),
+            v => visitor.visit_some(v),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    /// Like self-describing formats do it: a unit variant is a string, and any other
+    /// variant is a map with one entry.
+    fn deserialize_enum<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        match self {
+            Captured::String(variant) => visitor.visit_enum(variant.into_deserializer()),
+            Captured::Map(entries) if entries.len() == 1 => {
+                visitor.visit_enum(de::value::MapAccessDeserializer::new(
+                    de::value::MapDeserializer::new(entries.into_iter()),
+                ))
+            }
+            other => other.deserialize_any(visitor),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+impl de::IntoDeserializer<'_> for Captured {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    /// Options that record every callback as `path = json`.
+    #[cfg(all(feature = "rand", feature = "serde_json"))]
+    fn recording(
+        pattern: &str,
+    ) -> (
+        Options<crate::options::JsonExtraOptions>,
+        Arc<Mutex<Vec<String>>>,
+    ) {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let seen_in_callback = seen.clone();
+        let options = Options::new_json().on_complete(pattern, move |path, value| {
+            let value = serde_json::to_string(value).unwrap();
+            seen_in_callback
+                .lock()
+                .unwrap()
+                .push(format!("{path} = {value}"));
+        });
+        (options, seen)
+    }
+
+    #[cfg(all(feature = "rand", feature = "serde_json"))]
+    #[test]
+    fn test_incremental() {
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        struct Response {
+            #[serde(default)]
+            results: Vec<Hit>,
+        }
+
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        struct Hit {
+            title: String,
+            #[serde(default)]
+            score: u32,
+        }
+
+        let (options, seen) = recording("/results/*");
+        let mut deserializer: crate::IncrementalDeserializer<
+            Response,
+            crate::source::JsonFormat,
+            _,
+        > = crate::IncrementalDeserializer::new(options);
+        let input =
+            r#"{"results": [{"title": "a", "score": 3}, {"title": "b, c"}, {"title": "d"}]}"#;
+        let mut n_seen_after = Vec::new();
+        for byte in input.bytes() {
+            deserializer.push(&[byte]).unwrap();
+            n_seen_after.push(seen.lock().unwrap().len());
+        }
+        let response = deserializer.finish().unwrap();
+        assert_eq!(response.results[1].title, "b, c");
+
+        assert_eq!(
+            *seen.lock().unwrap(),
+            [
+                r#"/results/0 = {"title":"a","score":3}"#,
+                r#"/results/1 = {"title":"b, c"}"#,
+                r#"/results/2 = {"title":"d"}"#,
+            ]
+        );
+        // Every element is reported when its separator arrives, not before.
+        let first_report = |n| n_seen_after.iter().position(|seen| *seen == n).unwrap() + 1;
+        assert_eq!(
+            &input[..first_report(1)],
+            r#"{"results": [{"title": "a", "score": 3},"#
+        );
+        assert_eq!(
+            &input[..first_report(2)],
+            r#"{"results": [{"title": "a", "score": 3}, {"title": "b, c"},"#
+        );
+        assert_eq!(&input[..first_report(3)], &input[..input.len() - 1]);
+    }
+
+    #[cfg(all(feature = "rand", feature = "serde_json"))]
+    #[test]
+    fn test_every_parse_reports() {
+        let (options, seen) = recording("/*");
+        for _ in 0..2 {
+            let _: Vec<u32> = options
+                .clone()
+                .deserialize_from_json_str("[1, 2".into())
+                .unwrap();
+        }
+        assert_eq!(*seen.lock().unwrap(), ["/0 = 1", "/0 = 1"]);
+    }
+
+    #[cfg(all(feature = "rand", feature = "serde_json"))]
+    #[test]
+    fn test_map_values_and_nesting() {
+        let (options, seen) = recording("/*/items/*");
+        let _: serde_json::Value = options
+            .deserialize_from_json_str(
+                r#"{"x": {"items": [1, "two", null]}, "y": {"items": {"k": [true], "l": "cut"#
+                    .into(),
+            )
+            .unwrap();
+        assert_eq!(
+            *seen.lock().unwrap(),
+            [
+                "/x/items/0 = 1",
+                r#"/x/items/1 = "two""#,
+                "/x/items/2 = null",
+                "/y/items/k = [true]",
+            ]
+        );
+    }
+
+    #[cfg(all(feature = "rand", feature = "serde_json"))]
+    #[test]
+    fn test_enums() {
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        enum Shape {
+            Point,
+            Circle { radius: f64 },
+        }
+
+        let (options, seen) = recording("/*");
+        let shapes: Vec<Shape> = options
+            .deserialize_from_json_str(r#"["Point", {"Circle": {"radius": 2.5}}, "Poi"#.into())
+            .unwrap();
+        assert_eq!(shapes, [Shape::Point, Shape::Circle { radius: 2.5 }]);
+        assert_eq!(
+            *seen.lock().unwrap(),
+            [r#"/0 = "Point""#, r#"/1 = {"Circle":{"radius":2.5}}"#]
+        );
+    }
+
+    #[test]
+    fn test_pattern() {
+        let callback = |pattern| Callback {
+            pattern: parse_pattern(pattern),
+            f: Arc::new(|_, _| {}),
+        };
+        let path = [PathSegment::Key("results".into()), PathSegment::Index(3)];
+        assert!(callback("/results/*").matches(&path));
+        assert!(callback("/*/3").matches(&path));
+        assert!(!callback("/results").matches(&path));
+        assert!(!callback("/results/*/*").matches(&path));
+        assert!(!callback("/items/*").matches(&path));
+
+        let path = [PathSegment::Key("a/b~c".into())];
+        assert!(callback("/a~1b~0c").matches(&path));
+    }
+}
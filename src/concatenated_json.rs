@@ -0,0 +1,146 @@
+//! JSON values written back to back, where only the last value may be cut off.
+
+use std::borrow::Cow;
+
+use crate::options_impl::ExtraOptions;
+use crate::{Error, Options};
+
+/// The values in an input of concatenated JSON values, like `{"a":1}{"a":2}{"a":`.
+///
+/// Get this from [`crate::from_concatenated_json_str`] or
+/// [`Options::deserialize_from_concatenated_json_str`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ConcatenatedJson<T> {
+    /// All values, in order. Only the last one may be partial.
+    pub values: Vec<T>,
+    /// The byte offset where the last value starts, if that value was cut off.
+    ///
+    /// Everything before it has been fully consumed, so once more input arrives, you can
+    /// drop those bytes and parse again from here.
+    ///
+    /// If not enough of the last value was there to deserialize it, then it is missing
+    /// from [`Self::values`], but this is still set.
+    pub partial_start: Option<usize>,
+}
+
+impl<T> ConcatenatedJson<T> {
+    /// Whether the last value looked complete.
+    pub fn is_complete(&self) -> bool {
+        self.partial_start.is_none()
+    }
+
+    pub fn into_values(self) -> Vec<T> {
+        self.values
+    }
+}
+
+impl<Extra: ExtraOptions> Options<Extra> {
+    /// Like [`crate::from_concatenated_json_str`], but with options. The options only
+    /// apply to the last value.
+    pub fn deserialize_from_concatenated_json_str<T>(
+        self,
+        json: &str,
+    ) -> Result<ConcatenatedJson<T>, Error<serde_json::Error>>
+    where
+        T: for<'de> serde::de::Deserialize<'de>,
+    {
+        let mut stream = serde_json::Deserializer::from_str(json).into_iter::<T>();
+        let mut values = Vec::new();
+        let mut partial_start = None;
+
+        loop {
+            let end_of_previous = stream.byte_offset();
+            match stream.next() {
+                None => break,
+                Some(Ok(value)) => values.push(value),
+                // Only running out of input means that the value was cut off.
+                Some(Err(err)) if err.is_eof() => {
+                    let rest = json[end_of_previous..].trim_start();
+                    let start = json.len() - rest.len();
+                    // The values before it are still good, even if this one is not.
+                    if let Ok(value) = self.deserialize_from_json_str(Cow::Borrowed(rest)) {
+                        values.push(value);
+                    }
+                    partial_start = Some(start);
+                    break;
+                }
+                Some(Err(err)) => return Err(Error::from_de(err)),
+            }
+        }
+
+        Ok(ConcatenatedJson {
+            values,
+            partial_start,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ConcatenatedJson;
+
+    #[test]
+    fn test_partial_last_value() {
+        let input = r#"{"a":1}{"a":2} {"a":3,"b":"xy"#;
+        let parsed: ConcatenatedJson<serde_json::Value> =
+            crate::from_concatenated_json_str(input).unwrap();
+        assert_eq!(
+            parsed.values,
+            [
+                serde_json::json!({"a": 1}),
+                serde_json::json!({"a": 2}),
+                serde_json::json!({"a": 3, "b": "xy"}),
+            ]
+        );
+        assert_eq!(parsed.partial_start, Some(15));
+        assert_eq!(&input[15..], r#"{"a":3,"b":"xy"#);
+    }
+
+    #[test]
+    fn test_complete() {
+        for input in ["[1][2]", "[1]\n[2]\n", ""] {
+            let parsed: ConcatenatedJson<Vec<u32>> =
+                crate::from_concatenated_json_str(input).unwrap();
+            assert!(parsed.is_complete(), "{input:?}");
+        }
+
+        let parsed: ConcatenatedJson<Vec<u32>> =
+            crate::from_concatenated_json_str("[1][2] [").unwrap();
+        assert_eq!(parsed.values, [vec![1], vec![2], vec![]]);
+        assert_eq!(parsed.partial_start, Some(7));
+    }
+
+    #[test]
+    fn test_last_value_too_short() {
+        let parsed: ConcatenatedJson<serde_json::Value> =
+            crate::from_concatenated_json_str(r#"{"a":1} {"b":2} [tr"#).unwrap();
+        assert_eq!(
+            parsed.values,
+            [
+                serde_json::json!({"a": 1}),
+                serde_json::json!({"b": 2}),
+                serde_json::json!([]),
+            ]
+        );
+        assert_eq!(parsed.partial_start, Some(16));
+
+        // Nothing of `tr` can be deserialized yet, but we keep the values before it.
+        let parsed: ConcatenatedJson<serde_json::Value> =
+            crate::from_concatenated_json_str(r#"{"a":1} {"b":2} tr"#).unwrap();
+        assert_eq!(
+            parsed.values,
+            [serde_json::json!({"a": 1}), serde_json::json!({"b": 2})]
+        );
+        assert_eq!(parsed.partial_start, Some(16));
+    }
+
+    #[test]
+    fn test_malformed_value_before_the_last() {
+        let err = crate::from_concatenated_json_str::<Vec<u32>>("[1]]{[2")
+            .unwrap_err()
+            .into_deserializer_error()
+            .unwrap();
+        assert!(err.is_syntax(), "{err}");
+    }
+}
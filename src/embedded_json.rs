@@ -0,0 +1,210 @@
+//! JSON inside other text, such as the prose or Markdown that an LLM writes around it.
+//!
+//! This is not a [`crate::Source`], because we may have to try several places where the
+//! JSON could start, and we return where we found it.
+
+use std::borrow::Cow;
+use std::ops::Range;
+
+use crate::options_impl::ExtraOptions;
+use crate::{Error, Options};
+
+/// A JSON value that we found inside other text.
+///
+/// Get this from [`crate::from_embedded_json_str`] or
+/// [`Options::deserialize_from_embedded_json_str`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct EmbeddedJson<T> {
+    pub value: T,
+    /// Where the JSON is in the text. If it was cut off, then this goes up to the end of
+    /// the text, or to the end of its code fence.
+    pub range: Range<usize>,
+    /// Whether the JSON was cut off.
+    pub is_partial: bool,
+}
+
+impl<T> EmbeddedJson<T> {
+    /// Whether the JSON looked complete.
+    pub fn is_complete(&self) -> bool {
+        !self.is_partial
+    }
+
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+impl<Extra: ExtraOptions> Options<Extra> {
+    /// Like [`crate::from_embedded_json_str`], but with options.
+    pub fn deserialize_from_embedded_json_str<T>(
+        self,
+        text: &str,
+    ) -> Result<Option<EmbeddedJson<T>>, Error<serde_json::Error>>
+    where
+        T: for<'de> serde::de::Deserialize<'de>,
+    {
+        for region in find_json_regions(text) {
+            let json = &text[region.clone()];
+
+            // Anything after a complete value is more prose, so leave it out.
+            let mut stream = serde_json::Deserializer::from_str(json).into_iter::<T>();
+            let len = match stream.next() {
+                Some(Ok(_)) => stream.byte_offset(),
+                Some(Err(err)) if err.is_eof() => json.trim_end().len(),
+                // This was not our JSON after all, like `{curly}` in prose.
+                _ => continue,
+            };
+            let range = region.start..region.start + len;
+
+            let outcome =
+                self.deserialize_from_json_str_outcome(Cow::Borrowed(&text[range.clone()]))?;
+            return Ok(Some(EmbeddedJson {
+                is_partial: !outcome.is_complete(),
+                value: outcome.into_value(),
+                range,
+            }));
+        }
+        Ok(None)
+    }
+}
+
+/// Where the JSON may start, each up to the end of its code fence or of the text.
+///
+/// If there is a code fence for JSON (or without a language), then the JSON is the
+/// contents of the first such fence. Otherwise, it may start at any `{` or `[`.
+fn find_json_regions(text: &str) -> Vec<Range<usize>> {
+    match find_json_fence(text) {
+        Some(contents) => text[contents.clone()]
+            .find(|c: char| !c.is_whitespace())
+            .map(|start| contents.start + start..contents.end)
+            .into_iter()
+            .collect(),
+        None => text
+            .match_indices(['{', '['])
+            .map(|(start, _)| start..text.len())
+            .collect(),
+    }
+}
+
+/// The contents of the first code fence for JSON, up to the closing fence or the end.
+///
+/// We only know the language of a fence once its first line is complete, so until then,
+/// this ignores the fence.
+fn find_json_fence(text: &str) -> Option<Range<usize>> {
+    let mut line_start = 0;
+    let mut in_other_fence = false;
+    for line in text.split_inclusive('\n') {
+        let info = line.strip_prefix("```").filter(|_| line.ends_with('\n'));
+        line_start += line.len();
+        let Some(info) = info else {
+            continue;
+        };
+        let language = info.trim();
+        if in_other_fence {
+            in_other_fence = false;
+        } else if language.is_empty() || language.eq_ignore_ascii_case("json") {
+            let end = text[line_start..]
+                .find("\n```")
+                .map_or(text.len(), |fence| line_start + fence);
+            return Some(line_start..end);
+        } else {
+            in_other_fence = true;
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::{find_json_regions, EmbeddedJson};
+
+    #[test]
+    fn test_find_json_regions() {
+        let region = |text: &'static str| {
+            find_json_regions(text)
+                .first()
+                .map(|range| &text[range.clone()])
+        };
+        assert_eq!(
+            region("Sure! Here it is: [1, 2] and {}"),
+            Some("[1, 2] and {}")
+        );
+        assert_eq!(
+            region("Use `{}`:\n```json\n  {\"a\": [\n```\nor [1]"),
+            Some("{\"a\": [")
+        );
+        assert_eq!(region("Here:\n```\n[1,"), Some("[1,"));
+        assert_eq!(region("Run:\n```sh\necho {}\n```\n```json\n[]"), Some("[]"));
+        // We don't know yet whether this fence is for JSON.
+        assert_eq!(region("Here:\n```js"), None);
+        assert_eq!(region("Here:\n```json\n  "), None);
+        assert_eq!(region("No JSON here."), None);
+    }
+
+    #[test]
+    fn test_streaming_fence() {
+        let text = "Sure! Here is the data:\n```json\n{\"items\": [1, 2], \"done\": true}\n```\nAnything else?";
+        let mut previous_items = Vec::new();
+        for (i, _) in text.char_indices().skip(1) {
+            let embedded: Option<EmbeddedJson<serde_json::Value>> =
+                crate::from_embedded_json_str(&text[..i]).unwrap();
+            let Some(embedded) = embedded else {
+                assert!(i <= text.find('{').unwrap(), "{}", &text[..i]);
+                continue;
+            };
+            let items = embedded.value["items"]
+                .as_array()
+                .cloned()
+                .unwrap_or_default();
+            assert!(items.starts_with(&previous_items), "{}", &text[..i]);
+            previous_items = items;
+        }
+
+        let embedded: EmbeddedJson<serde_json::Value> =
+            crate::from_embedded_json_str(text).unwrap().unwrap();
+        assert!(embedded.is_complete());
+        assert_eq!(&text[embedded.range], r#"{"items": [1, 2], "done": true}"#);
+    }
+
+    #[test]
+    fn test_prose() {
+        let text = r#"The answer is {"a": 1} I think, or maybe [2]."#;
+        let embedded: EmbeddedJson<serde_json::Value> =
+            crate::from_embedded_json_str(text).unwrap().unwrap();
+        assert_eq!(embedded.value, serde_json::json!({"a": 1}));
+        assert_eq!(embedded.range, 14..22);
+        assert!(embedded.is_complete());
+
+        let embedded: EmbeddedJson<serde_json::Value> =
+            crate::from_embedded_json_str(r#"The answer is {"a": "x"#)
+                .unwrap()
+                .unwrap();
+        assert_eq!(embedded.value, serde_json::json!({"a": "x"}));
+        assert_eq!(embedded.range, 14..22);
+        assert!(embedded.is_partial);
+    }
+
+    #[test]
+    fn test_skip_braces_in_prose() {
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        struct Answer {
+            a: u32,
+        }
+
+        for (text, range) in [
+            (r#"Use {curly} braces: {"a": 1"#, 20..27),
+            (r#"Use {} then {"a": 1}"#, 12..20),
+            (r#"Use [x] then {"a": 1} or {"a": 2}"#, 13..21),
+        ] {
+            let embedded: EmbeddedJson<Answer> =
+                crate::from_embedded_json_str(text).unwrap().unwrap();
+            assert_eq!(embedded.value, Answer { a: 1 }, "text = {text:?}");
+            assert_eq!(embedded.range, range, "text = {text:?}");
+        }
+
+        let embedded: Option<EmbeddedJson<serde_json::Value>> =
+            crate::from_embedded_json_str("Use {curly} braces.").unwrap();
+        assert_eq!(embedded, None);
+    }
+}
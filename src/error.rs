@@ -5,7 +5,8 @@ use serde::de::{Expected, Unexpected};
 #[cfg(doc)]
 use serde::de::{DeserializeSeed, Deserializer};
 
-/// Either `DeserializerErr` or [`InternalError`] or [`InconsistentDeserializerError`].
+/// Either `DeserializerErr` or [`InternalError`] or [`InconsistentDeserializerError`],
+/// or an I/O error when reading the input ourselves.
 ///
 /// Additional variants may be added in the future.
 #[derive(Debug, thiserror::Error)]
@@ -23,11 +24,19 @@ pub(crate) enum ErrorImpl<DeserializerErr> {
     /// The wrapped deserializer returned an error.
     #[error(transparent)]
     Deserializer(DeserializerErr),
+    /// The wrapped deserializer returned an error for one line of the input, and it only
+    /// saw that line.
+    #[error("on line {line}: {err}")]
+    DeserializerOnLine { line: usize, err: DeserializerErr },
     #[error(transparent)]
     Internal(InternalError),
     /// The deserializer behaved in an inconsistent / nondeterministic way.
     #[error(transparent)]
     InconsistentDeserializer(InconsistentDeserializerError),
+    /// Reading the input failed.
+    #[cfg(feature = "futures")]
+    #[error(transparent)]
+    Io(std::io::Error),
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -74,17 +83,42 @@ impl<DeserializerErr> Error<DeserializerErr> {
         }
     }
 
+    pub(crate) fn from_de_on_line(err: DeserializerErr, line: usize) -> Self {
+        Self {
+            err: Box::new(ErrorImpl::DeserializerOnLine { line, err }),
+        }
+    }
+
+    /// Say on which line an error from the wrapped deserializer happened, see
+    /// [`Self::line`]. Other errors stay as they are.
+    pub(crate) fn on_line(self, line: usize) -> Self {
+        match *self.err {
+            ErrorImpl::Deserializer(err) => Self::from_de_on_line(err, line),
+            err => Self { err: Box::new(err) },
+        }
+    }
+
     /// Was it an error from the wrapped deserializer?
     pub fn as_deserializer_error(&self) -> Option<&DeserializerErr> {
         match &*self.err {
-            ErrorImpl::Deserializer(err) => Some(err),
+            ErrorImpl::Deserializer(err) | ErrorImpl::DeserializerOnLine { err, .. } => Some(err),
             _ => None,
         }
     }
 
     pub fn into_deserializer_error(self) -> Option<DeserializerErr> {
         match *self.err {
-            ErrorImpl::Deserializer(err) => Some(err),
+            ErrorImpl::Deserializer(err) | ErrorImpl::DeserializerOnLine { err, .. } => Some(err),
+            _ => None,
+        }
+    }
+
+    /// The line number (starting at 1) of the input where the deserializer failed, if
+    /// it only saw that line, such as in [`crate::from_json_lines_str`]. Then the
+    /// position in the deserializer error is within that line.
+    pub fn line(&self) -> Option<usize> {
+        match &*self.err {
+            ErrorImpl::DeserializerOnLine { line, .. } => Some(*line),
             _ => None,
         }
     }
@@ -117,6 +151,24 @@ impl<DeserializerErr> Error<DeserializerErr> {
             _ => None,
         }
     }
+
+    /// Did reading the input fail?
+    #[cfg(feature = "futures")]
+    pub fn as_io_error(&self) -> Option<&std::io::Error> {
+        match &*self.err {
+            ErrorImpl::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+
+    /// Did reading the input fail? Then take the I/O error.
+    #[cfg(feature = "futures")]
+    pub fn into_io_error(self) -> Option<std::io::Error> {
+        match *self.err {
+            ErrorImpl::Io(err) => Some(err),
+            _ => None,
+        }
+    }
 }
 
 impl<DeserializerErr> Error<DeserializerErr>
@@ -131,6 +183,9 @@ where
     pub fn unpack_or_make_custom(self) -> DeserializerErr {
         match *self.err {
             ErrorImpl::Deserializer(err) => err,
+            ErrorImpl::DeserializerOnLine { line, err } => {
+                DeserializerErr::custom(format!("on line {line}: {err}"))
+            }
             _ => DeserializerErr::custom(format!("{}: {self}", std::env!("CARGO_PKG_NAME"))),
         }
     }
@@ -158,6 +213,15 @@ impl<DeserializerErr> From<BugEnum> for Error<DeserializerErr> {
     }
 }
 
+#[cfg(feature = "futures")]
+impl<DeserializerErr> From<std::io::Error> for Error<DeserializerErr> {
+    fn from(err: std::io::Error) -> Self {
+        Self {
+            err: Box::new(ErrorImpl::Io(err)),
+        }
+    }
+}
+
 impl<DeserializerErr> From<InconsistentDeserializerError> for Error<DeserializerErr> {
     fn from(err: InconsistentDeserializerError) -> Self {
         Self {
@@ -224,8 +288,14 @@ where
             ErrorImpl::Deserializer(err) => {
                 ErrorImpl::Deserializer(Box::new(err) as Box<dyn std::error::Error + Send + Sync>)
             }
+            ErrorImpl::DeserializerOnLine { line, err } => ErrorImpl::DeserializerOnLine {
+                line,
+                err: Box::new(err) as Box<dyn std::error::Error + Send + Sync>,
+            },
             ErrorImpl::Internal(err) => ErrorImpl::Internal(err),
             ErrorImpl::InconsistentDeserializer(err) => ErrorImpl::InconsistentDeserializer(err),
+            #[cfg(feature = "futures")]
+            ErrorImpl::Io(err) => ErrorImpl::Io(err),
         });
 
         Error { err }
@@ -0,0 +1,216 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+/// The newtype name that our deserializer recognizes, so that it can tell
+/// [`Incomplete`] whether its value was cut off.
+pub(crate) const NEWTYPE_NAME: &str = "$deser_incomplete::Incomplete";
+
+/// What our deserializer tells [`Incomplete`] after deserializing its value.
+pub(crate) const STATUS_COMPLETE: &str = "complete";
+pub(crate) const STATUS_TRUNCATED: &str = "truncated";
+pub(crate) const STATUS_MISSING: &str = "missing";
+
+/// Wrap a field in this to find out whether its value was cut off.
+///
+/// ```
+/// use deser_incomplete::Incomplete;
+///
+/// #[derive(Debug, serde::Deserialize)]
+/// struct Message {
+///     #[serde(default)]
+///     author: Incomplete<String>,
+///     #[serde(default)]
+///     text: Incomplete<String>,
+///     #[serde(default)]
+///     likes: Incomplete<u32>,
+/// }
+///
+/// let message: Message =
+///     deser_incomplete::from_json_str(r#"{"author": "bgeron", "text": "Hello wor"#).unwrap();
+/// assert_eq!(message.author, Incomplete::Complete("bgeron".to_string()));
+/// assert_eq!(message.text, Incomplete::Truncated("Hello wor".to_string()));
+/// assert_eq!(message.likes, Incomplete::Missing);
+/// ```
+///
+/// Use `#[serde(default)]` on the field, so that it becomes [`Incomplete::Missing`] when
+/// the input ends before the field.
+///
+/// This only works with the deserializer of this crate. With any other deserializer, the
+/// value is always [`Incomplete::Complete`].
+///
+/// Serializing is transparent, and [`Incomplete::Missing`] serializes as none. That does
+/// not deserialize back into [`Incomplete::Missing`]: JSON `null` is an error for
+/// `Incomplete<String>`. To round-trip, leave such fields out with
+/// `#[serde(default, skip_serializing_if = "Incomplete::is_missing")]`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Incomplete<T> {
+    /// The value was there and we did not need to intervene.
+    Complete(T),
+    /// The value was cut off, or we had to intervene to produce it. For instance, a
+    /// string that stopped halfway, or a sequence that lost its last elements.
+    Truncated(T),
+    /// The value was not there, or we had to use a fallback for it.
+    #[default]
+    Missing,
+}
+
+impl<T> Incomplete<T> {
+    pub fn is_complete(&self) -> bool {
+        matches!(self, Incomplete::Complete(_))
+    }
+
+    pub fn is_missing(&self) -> bool {
+        matches!(self, Incomplete::Missing)
+    }
+
+    /// The value, unless it was missing.
+    pub fn value(&self) -> Option<&T> {
+        match self {
+            Incomplete::Complete(value) | Incomplete::Truncated(value) => Some(value),
+            Incomplete::Missing => None,
+        }
+    }
+
+    /// The value, unless it was missing.
+    pub fn into_value(self) -> Option<T> {
+        match self {
+            Incomplete::Complete(value) | Incomplete::Truncated(value) => Some(value),
+            Incomplete::Missing => None,
+        }
+    }
+}
+
+impl<T: Serialize> Serialize for Incomplete<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Incomplete::Complete(value) | Incomplete::Truncated(value) => {
+                value.serialize(serializer)
+            }
+            Incomplete::Missing => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Incomplete<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_newtype_struct(NEWTYPE_NAME, IncompleteVisitor(PhantomData))
+    }
+}
+
+struct IncompleteVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for IncompleteVisitor<T> {
+    type Value = Incomplete<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a value")
+    }
+
+    /// Any other deserializer gets here.
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize(deserializer).map(Incomplete::Complete)
+    }
+
+    /// Our deserializer gives the value, followed by its status.
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let value: T = seq
+            .next_element()?
+            .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+        let status: String = seq
+            .next_element()?
+            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
+
+        match status.as_str() {
+            STATUS_COMPLETE => Ok(Incomplete::Complete(value)),
+            STATUS_TRUNCATED => Ok(Incomplete::Truncated(value)),
+            STATUS_MISSING => Ok(Incomplete::Missing),
+            _ => Err(serde::de::Error::unknown_variant(
+                &status,
+                &[STATUS_COMPLETE, STATUS_TRUNCATED, STATUS_MISSING],
+            )),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "rand", feature = "serde_json"))]
+mod test {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Row {
+        #[serde(default)]
+        name: Incomplete<String>,
+        #[serde(default)]
+        tags: Incomplete<Vec<String>>,
+    }
+
+    #[test]
+    fn test_incomplete_fields() {
+        let rows: Vec<Row> = crate::from_json_str(
+            r#"[{"name": "a", "tags": ["x"]}, {"name": "b", "tags": ["y", "z"#,
+        )
+        .unwrap();
+        assert_eq!(
+            rows,
+            [
+                Row {
+                    name: Incomplete::Complete("a".into()),
+                    tags: Incomplete::Complete(vec!["x".into()]),
+                },
+                Row {
+                    name: Incomplete::Complete("b".into()),
+                    tags: Incomplete::Truncated(vec!["y".into(), "z".into()]),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_serialize_missing() {
+        #[derive(Debug, Deserialize, serde::Serialize, PartialEq)]
+        struct Skipping {
+            #[serde(default, skip_serializing_if = "Incomplete::is_missing")]
+            name: Incomplete<String>,
+        }
+
+        let json = serde_json::to_string(&Incomplete::<String>::Missing).unwrap();
+        assert_eq!(json, "null");
+        assert!(serde_json::from_str::<Incomplete<String>>(&json).is_err());
+
+        let skipping = Skipping {
+            name: Incomplete::Missing,
+        };
+        let json = serde_json::to_string(&skipping).unwrap();
+        assert_eq!(json, "{}");
+        assert_eq!(serde_json::from_str::<Skipping>(&json).unwrap(), skipping);
+    }
+
+    #[test]
+    fn test_plain_deserializer_is_complete() {
+        let row: Row = serde_json::from_str(r#"{"name": "a"}"#).unwrap();
+        assert_eq!(
+            row,
+            Row {
+                name: Incomplete::Complete("a".into()),
+                tags: Incomplete::Missing,
+            }
+        );
+    }
+}
@@ -0,0 +1,488 @@
+use std::marker::PhantomData;
+
+use serde::de::DeserializeOwned;
+
+#[cfg(all(feature = "rand", feature = "serde_json"))]
+use crate::options::JsonExtraOptions;
+#[cfg(all(feature = "rand", feature = "rmp-serde"))]
+use crate::options::MsgPackExtraOptions;
+#[cfg(all(feature = "rand", feature = "ron"))]
+use crate::options::RonExtraOptions;
+#[cfg(all(feature = "rand", feature = "toml"))]
+use crate::options::TomlExtraOptions;
+#[cfg(all(feature = "rand", feature = "serde_yaml"))]
+use crate::options::YamlExtraOptions;
+use crate::options_impl::{DefaultExtraOptions, ExtraOptions};
+#[cfg(feature = "bincode")]
+use crate::source::BincodeFormat;
+#[cfg(feature = "serde_cbor")]
+use crate::source::CborFormat;
+#[cfg(feature = "serde_json")]
+use crate::source::JsonFormat;
+#[cfg(feature = "rmp-serde")]
+use crate::source::MsgPackFormat;
+#[cfg(feature = "postcard")]
+use crate::source::PostcardFormat;
+#[cfg(feature = "ron")]
+use crate::source::RonFormat;
+use crate::source::SliceFormat;
+#[cfg(feature = "toml")]
+use crate::source::TomlFormat;
+#[cfg(feature = "serde_yaml")]
+use crate::source::YamlFormat;
+use crate::state::{Carryover, Resume};
+use crate::stats::ParseStats;
+use crate::{Error, Options, Outcome};
+
+/// Owns a growing input, and deserializes the latest partial value every time
+/// more input is pushed.
+///
+/// ```
+/// use deser_incomplete::IncrementalDeserializer;
+///
+/// let mut incremental: IncrementalDeserializer<Vec<u32>, _, _> =
+///     IncrementalDeserializer::new_json();
+///
+/// assert_eq!(incremental.push(b"[3, 4").unwrap(), vec![3, 4]);
+/// assert_eq!(incremental.push(b"0, 5").unwrap(), vec![3, 40, 5]);
+/// assert_eq!(incremental.push(b"]").unwrap(), vec![3, 40, 5]);
+/// ```
+///
+/// The same [`Options`] are used for every parse, so the random tag is generated only
+/// once. Buffers are reused between pushes. If a chunk ends in the middle of a UTF-8
+/// character, then text formats don't see that character until the rest of it arrives.
+///
+/// Note that every push still deserializes the whole input from the start,
+/// because [`serde`] data formats cannot resume a parse halfway. We do keep where the
+/// last successful parse halted. If a push adds nothing that the data format gets to
+/// see, such as part of a UTF-8 character, then the first attempt halts there straight
+/// away instead of backtracking again. If that fails, then we start cold. When the
+/// data format does see more input, we always start cold, because that halting point
+/// would then be too early.
+pub struct IncrementalDeserializer<T, F, Extra: ExtraOptions = DefaultExtraOptions> {
+    options: Options<Extra>,
+
+    /// Everything that was pushed so far.
+    input: Vec<u8>,
+
+    /// The usable part of `input`, followed by the random trailer.
+    prepared: Vec<u8>,
+
+    /// Numbers about the last successful parse.
+    last_stats: Option<ParseStats>,
+
+    /// How many bytes of input the last successful parse saw, and how to halt where
+    /// it did.
+    resume: Option<(usize, Resume)>,
+
+    /// Where we already called callbacks, so that later pushes don't report the same
+    /// paths again.
+    #[cfg(feature = "erased-serde")]
+    reported: crate::callbacks::Reported,
+
+    /// Set if we never go back to a smaller value, see [`Self::monotonic`].
+    largest: Option<Largest<T>>,
+
+    /// Whether the last push returned an earlier value, because the new one was smaller.
+    last_held_back: bool,
+
+    /// Whether no more input will come, so that a number at the end is complete.
+    finished: bool,
+
+    _phantom: PhantomData<fn() -> (T, F)>,
+}
+
+/// The largest value so far.
+struct Largest<T> {
+    /// Not set before the first parse.
+    outcome: Option<Outcome<T>>,
+    data_size: usize,
+    /// Lets us return the value again, although [`IncrementalDeserializer::push`] does
+    /// not need `T: Clone`.
+    clone_outcome: fn(&Outcome<T>) -> Outcome<T>,
+}
+
+#[cfg(all(feature = "rand", feature = "serde_json"))]
+impl<T> IncrementalDeserializer<T, JsonFormat, JsonExtraOptions>
+where
+    T: DeserializeOwned,
+{
+    /// Incrementally deserialize JSON with [`Options::new_json`].
+    pub fn new_json() -> Self {
+        Self::new(Options::new_json())
+    }
+}
+
+#[cfg(all(feature = "rand", feature = "serde_yaml"))]
+impl<T> IncrementalDeserializer<T, YamlFormat, YamlExtraOptions>
+where
+    T: DeserializeOwned,
+{
+    /// Incrementally deserialize YAML with [`Options::new_yaml`].
+    pub fn new_yaml() -> Self {
+        Self::new(Options::new_yaml())
+    }
+}
+
+#[cfg(feature = "serde_cbor")]
+impl<T> IncrementalDeserializer<T, CborFormat, DefaultExtraOptions>
+where
+    T: DeserializeOwned,
+{
+    /// Incrementally deserialize CBOR with [`Options::new_no_nonce`].
+    pub fn new_cbor() -> Self {
+        Self::new(Options::new_no_nonce())
+    }
+}
+
+#[cfg(feature = "postcard")]
+impl<T> IncrementalDeserializer<T, PostcardFormat, DefaultExtraOptions>
+where
+    T: DeserializeOwned,
+{
+    /// Incrementally deserialize postcard with [`Options::new_postcard`].
+    pub fn new_postcard() -> Self {
+        Self::new(Options::new_postcard())
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<T> IncrementalDeserializer<T, BincodeFormat, DefaultExtraOptions>
+where
+    T: DeserializeOwned,
+{
+    /// Incrementally deserialize bincode with [`Options::new_bincode`].
+    pub fn new_bincode() -> Self {
+        Self::new(Options::new_bincode())
+    }
+}
+
+#[cfg(all(feature = "rand", feature = "rmp-serde"))]
+impl<T> IncrementalDeserializer<T, MsgPackFormat, MsgPackExtraOptions>
+where
+    T: DeserializeOwned,
+{
+    /// Incrementally deserialize MessagePack with [`Options::new_msgpack`].
+    pub fn new_msgpack() -> Self {
+        Self::new(Options::new_msgpack())
+    }
+}
+
+#[cfg(all(feature = "rand", feature = "ron"))]
+impl<T> IncrementalDeserializer<T, RonFormat, RonExtraOptions>
+where
+    T: DeserializeOwned,
+{
+    /// Incrementally deserialize RON with [`Options::new_ron`].
+    pub fn new_ron() -> Self {
+        Self::new(Options::new_ron())
+    }
+}
+
+#[cfg(all(feature = "rand", feature = "toml"))]
+impl<T> IncrementalDeserializer<T, TomlFormat, TomlExtraOptions>
+where
+    T: DeserializeOwned,
+{
+    /// Incrementally deserialize TOML with [`Options::new_toml`].
+    pub fn new_toml() -> Self {
+        Self::new(Options::new_toml())
+    }
+}
+
+impl<T, F, Extra> IncrementalDeserializer<T, F, Extra>
+where
+    T: DeserializeOwned,
+    F: SliceFormat,
+    Extra: ExtraOptions + Clone,
+{
+    /// Incrementally deserialize with custom options.
+    ///
+    /// The random trailer of the options is applied on every parse.
+    pub fn new(options: Options<Extra>) -> Self {
+        Self {
+            options,
+            input: Vec::new(),
+            prepared: Vec::new(),
+            last_stats: None,
+            resume: None,
+            #[cfg(feature = "erased-serde")]
+            reported: Default::default(),
+            largest: None,
+            last_held_back: false,
+            finished: false,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Never return a value that is smaller than an earlier one.
+    ///
+    /// Sometimes a longer input parses to less data than a shorter one did, for instance
+    /// when YAML without a random trailer drops a line with an unterminated string. In
+    /// a UI, that looks like flicker. With this option, we then return the largest
+    /// earlier value again, until a new value is at least as large.
+    /// [`Self::last_held_back`] tells you when this happened.
+    ///
+    /// "Large" is measured roughly: every value in the input counts as one, and strings
+    /// also count with their length. A value that is complete is always returned.
+    ///
+    /// We keep a clone of the largest value, so every push also clones the value.
+    pub fn monotonic(mut self) -> Self
+    where
+        T: Clone,
+    {
+        self.largest = Some(Largest {
+            outcome: None,
+            data_size: 0,
+            clone_outcome: Outcome::clone,
+        });
+        self
+    }
+
+    /// Append a chunk of input, and deserialize everything that was pushed so far.
+    pub fn push(&mut self, chunk: &[u8]) -> Result<T, Error<F::Error>> {
+        self.push_outcome(chunk).map(Outcome::into_value)
+    }
+
+    /// Like [`Self::push`], but also report whether the input looked complete.
+    ///
+    /// This is useful to decide whether to show that more data is still loading.
+    pub fn push_outcome(&mut self, chunk: &[u8]) -> Result<Outcome<T>, Error<F::Error>> {
+        self.input.extend_from_slice(chunk);
+        self.deserialize_prefix(F::usable_prefix_len(&self.input))
+    }
+
+    /// Deserialize everything that was pushed, because no more input will come.
+    ///
+    /// Unlike [`Self::push`], this also shows a partial UTF-8 character to the
+    /// data format.
+    pub fn finish(self) -> Result<T, Error<F::Error>> {
+        self.finish_outcome().map(Outcome::into_value)
+    }
+
+    /// Like [`Self::finish`], but also report whether the input looked complete.
+    pub fn finish_outcome(mut self) -> Result<Outcome<T>, Error<F::Error>> {
+        self.finished = true;
+        self.deserialize_prefix(self.input.len())
+    }
+
+    /// Everything that was pushed so far.
+    pub fn input(&self) -> &[u8] {
+        &self.input
+    }
+
+    /// How many times the last successful parse had to backtrack.
+    ///
+    /// This is useful for tuning [`Options::with_max_n_backtracks`].
+    pub fn last_n_backtracks(&self) -> Option<usize> {
+        self.last_stats.as_ref().map(ParseStats::n_backtracks)
+    }
+
+    /// Numbers about the last successful parse, such as how long it took.
+    pub fn last_stats(&self) -> Option<&ParseStats> {
+        self.last_stats.as_ref()
+    }
+
+    /// Whether the last push returned an earlier value instead of a smaller new one, see
+    /// [`Self::monotonic`].
+    pub fn last_held_back(&self) -> bool {
+        self.last_held_back
+    }
+
+    fn deserialize_prefix(&mut self, len: usize) -> Result<Outcome<T>, Error<F::Error>> {
+        let (outcome, data_size) = self.deserialize_prefix_sized(len)?;
+        self.last_held_back = false;
+        let Some(largest) = self.largest.as_mut() else {
+            return Ok(outcome);
+        };
+        match &largest.outcome {
+            Some(earlier) if data_size < largest.data_size && !outcome.is_complete() => {
+                self.last_held_back = true;
+                Ok((largest.clone_outcome)(earlier))
+            }
+            _ => {
+                largest.outcome = Some((largest.clone_outcome)(&outcome));
+                largest.data_size = data_size;
+                Ok(outcome)
+            }
+        }
+    }
+
+    /// Deserialize the first `len` bytes of input, and say how large the value is.
+    fn deserialize_prefix_sized(
+        &mut self,
+        len: usize,
+    ) -> Result<(Outcome<T>, usize), Error<F::Error>> {
+        self.prepared.clear();
+        self.prepared.extend_from_slice(&self.input[..len]);
+        if !self.finished {
+            let len = self.options.len_without_trailing_number(&self.prepared);
+            self.prepared.truncate(len);
+        }
+        // The input only grows, so the same length means the same bytes.
+        let seen_len = self.prepared.len();
+        let resume = self
+            .resume
+            .take()
+            .filter(|(len, _)| *len == seen_len)
+            .map(|(_, resume)| resume);
+        self.options.append_random_trailer(&mut self.prepared);
+
+        let carryover = Carryover {
+            resume,
+            #[cfg(feature = "erased-serde")]
+            reported: self.reported.clone(),
+        };
+
+        let mut stats = ParseStats::default();
+        let finished = self.options.clone().deserialize_seed_impl(
+            PhantomData,
+            F::source_from_slice(&self.prepared),
+            carryover,
+            &mut stats,
+        )?;
+
+        self.last_stats = Some(stats);
+        self.resume = finished.attempt.resume().map(|resume| (seen_len, resume));
+        #[cfg(feature = "erased-serde")]
+        let finished = {
+            let mut finished = finished;
+            self.reported = std::mem::take(&mut finished.attempt.reported);
+            finished
+        };
+        let data_size = finished.attempt.data_size();
+        Ok((finished.into_outcome(), data_size))
+    }
+}
+
+#[cfg(all(test, feature = "rand", feature = "serde_json"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_push_matches_from_json_slice() {
+        let full = r#"[{"name": "café", "n": 12}, {"name": "☕"#.as_bytes();
+
+        for chunk_size in 1..=4 {
+            let mut incremental: IncrementalDeserializer<serde_json::Value, _, _> =
+                IncrementalDeserializer::new_json();
+
+            for chunk in full.chunks(chunk_size) {
+                let pushed = incremental.push(chunk).unwrap();
+                let input = incremental.input();
+                let usable = &input[..crate::util::utf8_complete_prefix_len(input)];
+                let expected: serde_json::Value = crate::from_json_slice(usable).unwrap();
+                assert_eq!(pushed, expected, "chunk_size = {chunk_size}");
+            }
+
+            assert_eq!(
+                incremental.finish().unwrap(),
+                serde_json::json!([{"name": "café", "n": 12}, {"name": "☕"}])
+            );
+        }
+    }
+
+    #[test]
+    fn test_partial_utf8_char_is_held_back() {
+        let mut incremental: IncrementalDeserializer<String, _, _> =
+            IncrementalDeserializer::new_json();
+
+        assert_eq!(incremental.push(b"\"caf\xC3").unwrap(), "caf");
+        assert_eq!(incremental.push(b"\xA9").unwrap(), "café");
+    }
+
+    #[test]
+    fn test_push_outcome() {
+        let mut incremental: IncrementalDeserializer<Vec<String>, _, _> =
+            IncrementalDeserializer::new_json();
+
+        let outcome = incremental.push_outcome(br#"["a", "b"#).unwrap();
+        assert!(!outcome.is_complete());
+        assert_eq!(outcome.value(), &["a", "b"]);
+
+        let outcome = incremental.push_outcome(br#"c"]"#).unwrap();
+        assert_eq!(outcome, Outcome::Complete(vec!["a".into(), "bc".into()]));
+    }
+
+    #[test]
+    fn test_resume_halting_point() {
+        let mut incremental: IncrementalDeserializer<serde_json::Value, _, _> =
+            IncrementalDeserializer::new_json();
+
+        let outcome = incremental.push_outcome(br#"{"a": [1, 2], "b": "#).unwrap();
+        assert_eq!(outcome.value(), &serde_json::json!({"a": [1, 2]}));
+        let stats = incremental.last_stats().unwrap();
+        assert!(stats.n_attempts() > 1);
+        let halted_at = stats.attempts.last().unwrap().planned_halting_point;
+
+        // The data format does not see a partial UTF-8 character, so we halt at the same
+        // point straight away, with the same outcome.
+        assert_eq!(incremental.push_outcome(b"\xC3").unwrap(), outcome);
+        let stats = incremental.last_stats().unwrap();
+        assert_eq!(stats.n_attempts(), 1);
+        assert_eq!(stats.attempts[0].planned_halting_point, halted_at);
+
+        // With more input, we start cold again.
+        let mut incremental: IncrementalDeserializer<serde_json::Value, _, _> =
+            IncrementalDeserializer::new_json();
+        incremental.push(br#"{"a": [1, 2], "b": "#).unwrap();
+        assert_eq!(
+            incremental.push(b"3").unwrap(),
+            serde_json::json!({"a": [1, 2], "b": 3})
+        );
+        assert_eq!(
+            incremental.last_stats().unwrap().attempts[0].planned_halting_point,
+            None
+        );
+    }
+
+    #[test]
+    fn test_withhold_trailing_numbers() {
+        let mut incremental: IncrementalDeserializer<Vec<u32>, JsonFormat, _> =
+            IncrementalDeserializer::new(Options::new_json().withhold_trailing_numbers());
+
+        assert_eq!(incremental.push(b"[12").unwrap(), Vec::<u32>::new());
+        assert_eq!(incremental.push(b"3, 4").unwrap(), vec![123]);
+        assert_eq!(incremental.push(b"5").unwrap(), vec![123]);
+        assert_eq!(incremental.finish().unwrap(), vec![123, 45]);
+    }
+
+    #[cfg(feature = "serde_yaml")]
+    #[test]
+    fn test_monotonic() {
+        let input = b"a: 1\nb: \"xyz\"\n";
+        let mut plain: IncrementalDeserializer<serde_json::Value, YamlFormat, _> =
+            IncrementalDeserializer::new(Options::new_no_nonce());
+        let mut monotonic: IncrementalDeserializer<serde_json::Value, YamlFormat, _> =
+            IncrementalDeserializer::new(Options::new_no_nonce()).monotonic();
+
+        let mut held_back = Vec::new();
+        for byte in input {
+            let plain_value = plain.push(&[*byte]).unwrap();
+            let value = monotonic.push(&[*byte]).unwrap();
+            // The numbers are about the new input, even if we held back its value.
+            assert_eq!(monotonic.last_n_backtracks(), plain.last_n_backtracks());
+            if monotonic.last_held_back() {
+                held_back.push(String::from_utf8_lossy(monotonic.input()).into_owned());
+                assert_eq!(plain_value, serde_json::json!({"a": 1}));
+                assert_eq!(value, serde_json::json!({"a": 1, "b": null}));
+            } else {
+                assert_eq!(value, plain_value);
+            }
+        }
+        // The unterminated string makes the YAML parser drop the whole line.
+        assert_eq!(
+            held_back,
+            [
+                "a: 1\nb: \"",
+                "a: 1\nb: \"x",
+                "a: 1\nb: \"xy",
+                "a: 1\nb: \"xyz"
+            ]
+        );
+        assert_eq!(
+            monotonic.finish().unwrap(),
+            serde_json::json!({"a": 1, "b": "xyz"})
+        );
+    }
+}
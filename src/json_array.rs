@@ -0,0 +1,279 @@
+//! Elements of a huge top-level JSON array, one at a time.
+
+use std::borrow::Cow;
+use std::marker::PhantomData;
+
+use serde::de::DeserializeOwned;
+
+use crate::options::JsonExtraOptions;
+use crate::options_impl::ExtraOptions;
+use crate::source::{JsonFormat, SliceFormat as _};
+use crate::{Error, Options};
+
+/// Owns the input of a top-level JSON array, and hands out every element as soon as it
+/// is complete.
+///
+/// ```
+/// use deser_incomplete::JsonArrayElements;
+///
+/// let mut elements: JsonArrayElements<Vec<u32>> = JsonArrayElements::new_json();
+///
+/// assert_eq!(elements.push(b"[[1, 2], [3").unwrap(), [vec![1, 2]]);
+/// assert_eq!(elements.partial().unwrap(), Some(vec![3]));
+/// assert_eq!(elements.push(b"0], [4]").unwrap(), [vec![30]]);
+/// assert_eq!(elements.push(b"]").unwrap(), [vec![4]]);
+/// assert!(elements.is_finished());
+/// ```
+///
+/// Unlike [`crate::IncrementalDeserializer`], this forgets the input of an element once
+/// it is complete. So memory only grows with the largest element, and every byte is
+/// parsed once, except by [`Self::partial`].
+///
+/// Complete elements are parsed strictly with [`serde_json`]. Anything after the
+/// closing `]` is ignored.
+pub struct JsonArrayElements<T, Extra: ExtraOptions = JsonExtraOptions> {
+    options: Options<Extra>,
+
+    /// Input that we did not turn into an element yet. If we are inside an element,
+    /// then this starts with that element.
+    buffer: Vec<u8>,
+
+    /// How much of `buffer` we have already looked at.
+    n_scanned: usize,
+
+    position: Position,
+
+    /// Elements that were complete before an element that failed to deserialize. The
+    /// next push returns them.
+    ready: Vec<T>,
+
+    _phantom: PhantomData<fn() -> T>,
+}
+
+/// Where we are in the array, as far as we have scanned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Position {
+    BeforeArray,
+    BeforeElement,
+    InElement {
+        /// How many arrays and objects inside the element are open.
+        depth: usize,
+        in_string: bool,
+        /// Whether the previous byte was a backslash inside a string.
+        escaped: bool,
+    },
+    AfterArray,
+}
+
+impl<T: DeserializeOwned> JsonArrayElements<T, JsonExtraOptions> {
+    /// Use [`Options::new_json`] for the partial element.
+    pub fn new_json() -> Self {
+        Self::new(Options::new_json())
+    }
+}
+
+impl<T, Extra> JsonArrayElements<T, Extra>
+where
+    T: DeserializeOwned,
+    Extra: ExtraOptions + Clone,
+{
+    /// Use custom options for the partial element.
+    pub fn new(options: Options<Extra>) -> Self {
+        Self {
+            options,
+            buffer: Vec::new(),
+            n_scanned: 0,
+            position: Position::BeforeArray,
+            ready: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Append a chunk of input, and return the elements that it completed.
+    ///
+    /// If a complete element does not deserialize, then this returns the error. The
+    /// elements before it come with the next push, which also goes on after it.
+    pub fn push(&mut self, chunk: &[u8]) -> Result<Vec<T>, Error<serde_json::Error>> {
+        self.buffer.extend_from_slice(chunk);
+
+        let mut elements = std::mem::take(&mut self.ready);
+        let mut error = None;
+        // Bytes before this are no longer needed.
+        let mut n_consumed = 0;
+
+        while error.is_none() && self.n_scanned < self.buffer.len() {
+            let byte = self.buffer[self.n_scanned];
+            match &mut self.position {
+                Position::BeforeArray | Position::BeforeElement if byte.is_ascii_whitespace() => {}
+                Position::BeforeArray if byte == b'[' => self.position = Position::BeforeElement,
+                Position::BeforeArray => {
+                    return Err(Error::from_de(serde::de::Error::custom(
+                        "expected `[` at the start of the input",
+                    )))
+                }
+                Position::BeforeElement if byte == b']' => self.position = Position::AfterArray,
+                Position::BeforeElement => {
+                    n_consumed = self.n_scanned;
+                    self.position = Position::InElement {
+                        depth: usize::from(matches!(byte, b'[' | b'{')),
+                        in_string: byte == b'"',
+                        escaped: false,
+                    };
+                }
+                Position::InElement {
+                    in_string: in_string @ true,
+                    escaped,
+                    ..
+                } => match byte {
+                    _ if *escaped => *escaped = false,
+                    b'\\' => *escaped = true,
+                    b'"' => *in_string = false,
+                    _ => {}
+                },
+                Position::InElement {
+                    depth, in_string, ..
+                } => match byte {
+                    b'"' => *in_string = true,
+                    b'[' | b'{' => *depth += 1,
+                    b']' | b'}' if *depth > 0 => *depth -= 1,
+                    b',' | b']' if *depth == 0 => {
+                        match serde_json::from_slice(&self.buffer[n_consumed..self.n_scanned]) {
+                            Ok(element) => elements.push(element),
+                            Err(err) => error = Some(err),
+                        }
+                        n_consumed = self.n_scanned + 1;
+                        self.position = if byte == b',' {
+                            Position::BeforeElement
+                        } else {
+                            Position::AfterArray
+                        };
+                    }
+                    _ => {}
+                },
+                Position::AfterArray => {}
+            }
+            self.n_scanned += 1;
+        }
+
+        if !matches!(self.position, Position::InElement { .. }) {
+            n_consumed = self.n_scanned;
+        }
+        self.buffer.drain(..n_consumed);
+        self.n_scanned -= n_consumed;
+
+        match error {
+            Some(err) => {
+                self.ready = elements;
+                Err(Error::from_de(err))
+            }
+            None => Ok(elements),
+        }
+    }
+
+    /// Deserialize the element that is not complete yet, if the input ends inside one.
+    pub fn partial(&self) -> Result<Option<T>, Error<serde_json::Error>> {
+        if !matches!(self.position, Position::InElement { .. }) {
+            return Ok(None);
+        }
+        let usable = &self.buffer[..JsonFormat::usable_prefix_len(&self.buffer)];
+        self.options
+            .clone()
+            .deserialize_from_json_slice(Cow::Borrowed(usable))
+            .map(Some)
+    }
+
+    /// Whether we have seen the closing `]` of the array.
+    pub fn is_finished(&self) -> bool {
+        self.position == Position::AfterArray
+    }
+
+    /// The input of the element that is not complete yet. This is all the input that we
+    /// keep in memory.
+    pub fn buffered_input(&self) -> &[u8] {
+        &self.buffer
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::Deserialize;
+
+    use super::JsonArrayElements;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Entry {
+        message: String,
+        #[serde(default)]
+        tags: Vec<String>,
+    }
+
+    fn entry(message: &str, tags: &[&str]) -> Entry {
+        Entry {
+            message: message.into(),
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_byte_by_byte() {
+        let input = br#" [ {"message": "a, [b]", "tags": ["x\"]"]} , {"message": "{c}"}, "#;
+        let mut elements: JsonArrayElements<Entry> = JsonArrayElements::new_json();
+        let mut complete = Vec::new();
+        let mut max_buffered = 0;
+        for byte in input {
+            complete.extend(elements.push(&[*byte]).unwrap());
+            max_buffered = max_buffered.max(elements.buffered_input().len());
+        }
+        assert_eq!(complete, [entry("a, [b]", &["x\"]"]), entry("{c}", &[])]);
+        // The first element, and the space after it.
+        assert_eq!(max_buffered, 40);
+        assert_eq!(elements.partial().unwrap(), None);
+        assert!(!elements.is_finished());
+
+        complete.extend(elements.push(br#"{"message": "d", "tags": ["y"#).unwrap());
+        assert_eq!(complete.len(), 2);
+        assert_eq!(elements.partial().unwrap(), Some(entry("d", &["y"])));
+
+        complete.extend(elements.push(b"\"]}]\n").unwrap());
+        assert_eq!(complete[2], entry("d", &["y"]));
+        assert!(elements.is_finished());
+        assert_eq!(elements.buffered_input(), b"");
+    }
+
+    #[test]
+    fn test_scalars() {
+        let mut elements: JsonArrayElements<Option<u32>> = JsonArrayElements::new_json();
+        assert_eq!(elements.push(b"[1, null, 2").unwrap(), [Some(1), None]);
+        // The number may go on, so it is not complete yet.
+        assert_eq!(elements.partial().unwrap(), Some(Some(2)));
+        assert_eq!(elements.push(b"3]").unwrap(), [Some(23)]);
+
+        let mut elements: JsonArrayElements<u32> = JsonArrayElements::new_json();
+        assert_eq!(elements.push(b"[]").unwrap(), Vec::<u32>::new());
+        assert!(elements.is_finished());
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut elements: JsonArrayElements<u32> = JsonArrayElements::new_json();
+        assert!(elements.push(b"{").is_err());
+
+        let mut elements: JsonArrayElements<u32> = JsonArrayElements::new_json();
+        assert_eq!(elements.push(b"[1, ").unwrap(), [1]);
+        let err = elements.push(b"true, 3]").unwrap_err();
+        assert!(err.as_deserializer_error().unwrap().is_data(), "{err}");
+        assert_eq!(elements.push(b"").unwrap(), [3]);
+        assert!(elements.is_finished());
+    }
+
+    #[test]
+    fn test_push_after_error() {
+        let mut elements: JsonArrayElements<u32> = JsonArrayElements::new_json();
+        assert!(elements.push(br#"[1, "x", 3, 4"#).is_err());
+        assert_eq!(elements.push(b"").unwrap(), [1, 3]);
+        assert_eq!(elements.partial().unwrap(), Some(4));
+        assert_eq!(elements.push(b"5, 6]").unwrap(), [45, 6]);
+        assert!(elements.is_finished());
+        assert_eq!(elements.buffered_input(), b"");
+    }
+}
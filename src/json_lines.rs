@@ -0,0 +1,161 @@
+//! Newline-delimited JSON, where only the last line may be cut off.
+
+use std::borrow::Cow;
+
+use crate::options_impl::ExtraOptions;
+use crate::{Error, Options};
+
+/// The values on the lines of a [JSON Lines](https://jsonlines.org/) input.
+///
+/// Get this from [`crate::from_json_lines_str`] or
+/// [`Options::deserialize_from_json_lines_str`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct JsonLines<T> {
+    /// One value per non-blank line, in order.
+    pub values: Vec<T>,
+    /// The line number (starting at 1) of the last value, if that line was cut off.
+    pub partial_line: Option<usize>,
+}
+
+impl<T> JsonLines<T> {
+    /// Whether the last line looked complete.
+    pub fn is_complete(&self) -> bool {
+        self.partial_line.is_none()
+    }
+
+    pub fn into_values(self) -> Vec<T> {
+        self.values
+    }
+}
+
+impl<Extra: ExtraOptions> Options<Extra> {
+    /// Like [`crate::from_json_lines_str`], but with options. The options only apply to
+    /// the last line.
+    pub fn deserialize_from_json_lines_str<T>(
+        self,
+        json_lines: &str,
+    ) -> Result<JsonLines<T>, Error<serde_json::Error>>
+    where
+        T: for<'de> serde::de::Deserialize<'de>,
+    {
+        // Everything up to the last newline is complete.
+        let (complete, last_line) = match json_lines.rfind('\n') {
+            Some(newline) => json_lines.split_at(newline + 1),
+            None => ("", json_lines),
+        };
+
+        let mut values = Vec::new();
+        let mut n_lines = 0;
+        for line in complete.lines() {
+            n_lines += 1;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let value =
+                serde_json::from_str(line).map_err(|err| Error::from_de_on_line(err, n_lines))?;
+            values.push(value);
+        }
+
+        let mut partial_line = None;
+        if !last_line.trim().is_empty() {
+            n_lines += 1;
+            // A last line without a newline is often simply the end of the file, and
+            // then the outcome says that it is complete.
+            let outcome = match self.deserialize_from_json_str_outcome(Cow::Borrowed(last_line)) {
+                Ok(outcome) => outcome,
+                // If the line is invalid, and not only cut off, then say why.
+                Err(err) => {
+                    return Err(match serde_json::from_str::<T>(last_line) {
+                        Err(strict) if !strict.is_eof() => Error::from_de_on_line(strict, n_lines),
+                        _ => err.on_line(n_lines),
+                    })
+                }
+            };
+            if !outcome.is_complete() {
+                partial_line = Some(n_lines);
+            }
+            values.push(outcome.into_value());
+        }
+
+        Ok(JsonLines {
+            values,
+            partial_line,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::Deserialize;
+
+    use super::JsonLines;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Event {
+        id: u32,
+        #[serde(default)]
+        text: String,
+    }
+
+    fn event(id: u32, text: &str) -> Event {
+        Event {
+            id,
+            text: text.into(),
+        }
+    }
+
+    #[test]
+    fn test_partial_last_line() {
+        let parsed: JsonLines<Event> = crate::from_json_lines_str(
+            "{\"id\": 1, \"text\": \"a\"}\n\n{\"id\": 2}\n{\"id\": 3, \"text\": \"hel",
+        )
+        .unwrap();
+        assert_eq!(
+            parsed.values,
+            [event(1, "a"), event(2, ""), event(3, "hel")]
+        );
+        assert_eq!(parsed.partial_line, Some(4));
+    }
+
+    #[test]
+    fn test_complete() {
+        for input in ["{\"id\": 1}\n{\"id\": 2}\n", "{\"id\": 1}\r\n{\"id\": 2}"] {
+            let parsed: JsonLines<Event> = crate::from_json_lines_str(input).unwrap();
+            assert_eq!(parsed.values, [event(1, ""), event(2, "")], "{input:?}");
+            assert!(parsed.is_complete(), "{input:?}");
+        }
+
+        let parsed: JsonLines<Event> = crate::from_json_lines_str("").unwrap();
+        assert_eq!(parsed.values, []);
+    }
+
+    #[test]
+    fn test_malformed_line_in_the_middle() {
+        let err = crate::from_json_lines_str::<Event>(
+            "{\"id\": 1}\n{\"id\": 2, \"text\": \n{\"id\": 3}\n{\"id\": 4",
+        )
+        .unwrap_err();
+        assert_eq!(err.line(), Some(2), "{err}");
+        assert!(err.to_string().starts_with("on line 2: EOF"), "{err}");
+        let err = err.into_deserializer_error().unwrap();
+        assert!(err.is_eof(), "{err}");
+
+        let err =
+            crate::from_json_lines_str::<Event>("{\"id\": 1}\n\n{\"text\": \"\"}\n").unwrap_err();
+        assert_eq!(err.line(), Some(3), "{err}");
+        assert!(err.into_deserializer_error().unwrap().is_data());
+    }
+
+    #[test]
+    fn test_malformed_last_line() {
+        let err = crate::from_json_lines_str::<Event>("{\"id\": 1}\n{\"id\": x").unwrap_err();
+        assert_eq!(err.line(), Some(2), "{err}");
+        assert!(err.into_deserializer_error().unwrap().is_syntax());
+
+        let err =
+            crate::from_json_lines_str::<Event>("{\"id\": 1}\n\n{\"text\": \"\"}").unwrap_err();
+        assert_eq!(err.line(), Some(3), "{err}");
+        assert!(err.into_deserializer_error().unwrap().is_data());
+    }
+}
@@ -0,0 +1,296 @@
+//! Deltas between successive partial values, as [JSON Patch](https://www.rfc-editor.org/rfc/rfc6902).
+
+use serde::ser::SerializeStruct as _;
+use serde_json::Value;
+
+use crate::path::{Path, PathSegment};
+
+/// The changes from one value to the next, as an RFC 6902 JSON Patch.
+///
+/// This serializes as the JSON array that the RFC calls a patch document, so you can
+/// send it to a client, which applies it to its copy with any JSON Patch library.
+///
+/// ```
+/// use deser_incomplete::{JsonPatch, PatchOperation};
+///
+/// let before: serde_json::Value = deser_incomplete::from_json_str(r#"{"items": [1"#).unwrap();
+/// let after: serde_json::Value = deser_incomplete::from_json_str(r#"{"items": [1, 2"#).unwrap();
+///
+/// let patch = JsonPatch::diff(&before, &after);
+/// assert_eq!(
+///     serde_json::to_string(&patch).unwrap(),
+///     r#"[{"op":"add","path":"/items/1","value":2}]"#
+/// );
+/// ```
+///
+/// We don't look for moved or copied values, since partial values mostly grow at the
+/// end. Arrays that got longer turn into `add` operations for their new elements, but a
+/// string that got longer is replaced as a whole.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct JsonPatch(Vec<PatchOperation>);
+
+/// One operation in a [`JsonPatch`].
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum PatchOperation {
+    /// Insert a map entry or sequence element.
+    Add { path: Path, value: Value },
+    /// Delete a map entry or sequence element.
+    Remove { path: Path },
+    /// Overwrite the value at this path.
+    Replace { path: Path, value: Value },
+}
+
+impl JsonPatch {
+    /// The operations that turn `previous` into `current`.
+    pub fn diff(previous: &Value, current: &Value) -> Self {
+        let mut patch = JsonPatch::default();
+        patch.push_diff(&mut Vec::new(), previous, current);
+        patch
+    }
+
+    /// Like [`Self::diff`], for any serializable type.
+    pub fn diff_serialize<T>(previous: &T, current: &T) -> Result<Self, serde_json::Error>
+    where
+        T: serde::Serialize + ?Sized,
+    {
+        Ok(Self::diff(
+            &serde_json::to_value(previous)?,
+            &serde_json::to_value(current)?,
+        ))
+    }
+
+    pub fn operations(&self) -> &[PatchOperation] {
+        &self.0
+    }
+
+    pub fn into_operations(self) -> Vec<PatchOperation> {
+        self.0
+    }
+
+    /// Whether the two values were equal.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn push_diff(&mut self, path: &mut Vec<PathSegment>, previous: &Value, current: &Value) {
+        match (previous, current) {
+            _ if previous == current => {}
+            (Value::Object(previous), Value::Object(current)) => {
+                for key in previous.keys().filter(|key| !current.contains_key(*key)) {
+                    self.0.push(PatchOperation::Remove {
+                        path: child(path, PathSegment::Key(key.clone())),
+                    });
+                }
+                for (key, current_value) in current {
+                    match previous.get(key) {
+                        Some(previous_value) => {
+                            path.push(PathSegment::Key(key.clone()));
+                            self.push_diff(path, previous_value, current_value);
+                            path.pop();
+                        }
+                        None => self.0.push(PatchOperation::Add {
+                            path: child(path, PathSegment::Key(key.clone())),
+                            value: current_value.clone(),
+                        }),
+                    }
+                }
+            }
+            (Value::Array(previous), Value::Array(current)) => {
+                for (index, (previous_value, current_value)) in
+                    previous.iter().zip(current).enumerate()
+                {
+                    path.push(PathSegment::Index(index));
+                    self.push_diff(path, previous_value, current_value);
+                    path.pop();
+                }
+                // Remove from the end, so that the other indices stay valid.
+                for index in (current.len()..previous.len()).rev() {
+                    self.0.push(PatchOperation::Remove {
+                        path: child(path, PathSegment::Index(index)),
+                    });
+                }
+                for (index, value) in current.iter().enumerate().skip(previous.len()) {
+                    self.0.push(PatchOperation::Add {
+                        path: child(path, PathSegment::Index(index)),
+                        value: value.clone(),
+                    });
+                }
+            }
+            _ => self.0.push(PatchOperation::Replace {
+                path: Path(path.clone()),
+                value: current.clone(),
+            }),
+        }
+    }
+}
+
+fn child(path: &[PathSegment], segment: PathSegment) -> Path {
+    let mut path = path.to_vec();
+    path.push(segment);
+    Path(path)
+}
+
+impl serde::Serialize for JsonPatch {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(&self.0)
+    }
+}
+
+impl serde::Serialize for PatchOperation {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (op, path, value) = match self {
+            PatchOperation::Add { path, value } => ("add", path, Some(value)),
+            PatchOperation::Remove { path } => ("remove", path, None),
+            PatchOperation::Replace { path, value } => ("replace", path, Some(value)),
+        };
+        let mut operation =
+            serializer.serialize_struct("PatchOperation", 2 + usize::from(value.is_some()))?;
+        operation.serialize_field("op", op)?;
+        operation.serialize_field("path", &path.to_string())?;
+        if let Some(value) = value {
+            operation.serialize_field("value", value)?;
+        }
+        operation.end()
+    }
+}
+
+/// Remembers the last value, to give a [`JsonPatch`] for every next one.
+///
+/// This is meant to go with [`crate::IncrementalDeserializer`]: after every push, pass
+/// the partial value to [`Self::update`] and send the patch on. The first patch replaces
+/// the initial `null` with the whole value.
+///
+/// ```
+/// use deser_incomplete::{IncrementalDeserializer, JsonPatchTracker};
+///
+/// let mut incremental = IncrementalDeserializer::<Vec<String>, _, _>::new_json();
+/// let mut tracker = JsonPatchTracker::default();
+///
+/// let value = incremental.push(br#"["a", "b"#).unwrap();
+/// let patch = tracker.update(&value).unwrap();
+/// assert_eq!(serde_json::to_string(&patch).unwrap(), r#"[{"op":"replace","path":"","value":["a","b"]}]"#);
+///
+/// let value = incremental.push(br#"c", "#).unwrap();
+/// let patch = tracker.update(&value).unwrap();
+/// assert_eq!(serde_json::to_string(&patch).unwrap(), r#"[{"op":"replace","path":"/1","value":"bc"}]"#);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct JsonPatchTracker {
+    previous: Value,
+}
+
+impl JsonPatchTracker {
+    /// The patch from the previous value to `current`, which becomes the new previous
+    /// value.
+    pub fn update<T>(&mut self, current: &T) -> Result<JsonPatch, serde_json::Error>
+    where
+        T: serde::Serialize + ?Sized,
+    {
+        Ok(self.update_value(serde_json::to_value(current)?))
+    }
+
+    /// Like [`Self::update`], for a value that is JSON already.
+    pub fn update_value(&mut self, current: Value) -> JsonPatch {
+        let patch = JsonPatch::diff(&self.previous, &current);
+        self.previous = current;
+        patch
+    }
+
+    /// The value that the next patch will start from.
+    pub fn previous(&self) -> &Value {
+        &self.previous
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+
+    use super::{JsonPatch, PatchOperation};
+    use crate::path::PathSegment;
+
+    /// Apply the patch like a client would.
+    fn apply(value: &mut Value, patch: &JsonPatch) {
+        for operation in patch.operations() {
+            let (path, new_value) = match operation {
+                PatchOperation::Add { path, value } | PatchOperation::Replace { path, value } => {
+                    (path, Some(value.clone()))
+                }
+                PatchOperation::Remove { path } => (path, None),
+            };
+            let Some((last, parents)) = path.segments().split_last() else {
+                *value = new_value.unwrap();
+                continue;
+            };
+            let mut parent = &mut *value;
+            for segment in parents {
+                parent = match segment {
+                    PathSegment::Index(index) => &mut parent[*index],
+                    PathSegment::Key(key) => &mut parent[key.as_str()],
+                };
+            }
+            match (parent, last, new_value) {
+                (Value::Array(array), PathSegment::Index(index), Some(new_value)) => {
+                    if matches!(operation, PatchOperation::Add { .. }) {
+                        array.insert(*index, new_value);
+                    } else {
+                        array[*index] = new_value;
+                    }
+                }
+                (Value::Array(array), PathSegment::Index(index), None) => {
+                    array.remove(*index);
+                }
+                (Value::Object(object), PathSegment::Key(key), Some(new_value)) => {
+                    object.insert(key.clone(), new_value);
+                }
+                (Value::Object(object), PathSegment::Key(key), None) => {
+                    object.remove(key);
+                }
+                (parent, last, _) => panic!("cannot apply at {last}: {parent}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_diff() {
+        let previous = json!({"a": [1, 2, 3], "b": "x", "c": {"d": null}, "e/f": 1});
+        let current = json!({"a": [1, 5], "b": "xy", "c": {"d": null, "g": true}, "h": []});
+        let patch = JsonPatch::diff(&previous, &current);
+        assert_eq!(
+            serde_json::to_value(&patch).unwrap(),
+            json!([
+                {"op": "remove", "path": "/e~1f"},
+                {"op": "replace", "path": "/a/1", "value": 5},
+                {"op": "remove", "path": "/a/2"},
+                {"op": "replace", "path": "/b", "value": "xy"},
+                {"op": "add", "path": "/c/g", "value": true},
+                {"op": "add", "path": "/h", "value": []},
+            ])
+        );
+
+        let mut patched = previous.clone();
+        apply(&mut patched, &patch);
+        assert_eq!(patched, current);
+
+        assert!(JsonPatch::diff(&current, &current).is_empty());
+        assert_eq!(
+            serde_json::to_value(JsonPatch::diff(&json!([1]), &json!({}))).unwrap(),
+            json!([{"op": "replace", "path": "", "value": {}}])
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_every_prefix() {
+        let input = r#"{"title": "Report", "rows": [{"id": 1, "tags": ["x", "y"]}, {"id": 22}], "done": true}"#;
+        let mut tracker = super::JsonPatchTracker::default();
+        let mut client = Value::Null;
+        for (i, _) in input.char_indices().skip(1) {
+            let value: Value = crate::from_json_str(&input[..i]).unwrap();
+            let patch = tracker.update_value(value.clone());
+            apply(&mut client, &patch);
+            assert_eq!(client, value, "{}", &input[..i]);
+        }
+    }
+}
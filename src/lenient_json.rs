@@ -0,0 +1,241 @@
+//! JSON with the mistakes that hand-written or generated JSON often has.
+
+use std::borrow::Cow;
+
+use crate::options_impl::ExtraOptions;
+use crate::{Error, Options};
+
+impl<Extra: ExtraOptions> Options<Extra> {
+    /// Like [`crate::from_lenient_json_str`], but with options.
+    pub fn deserialize_from_lenient_json_str<T>(
+        self,
+        json: &str,
+    ) -> Result<T, Error<serde_json::Error>>
+    where
+        T: for<'de> serde::de::Deserialize<'de>,
+    {
+        self.deserialize_from_json_str(Cow::Owned(repair(json)))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Container {
+    Object { expect_key: bool },
+    Array,
+}
+
+/// Rewrite lenient JSON into strict JSON, token by token.
+///
+/// Whatever is cut off at the end stays cut off, so that we can then deserialize the
+/// result as usual: an unterminated string stays unterminated, a comma at the end is
+/// kept, and a literal like `Tru` becomes `tru`.
+pub(crate) fn repair(input: &str) -> String {
+    let chars: Vec<char> = input.chars().collect();
+    let mut out = String::with_capacity(input.len());
+    let mut stack: Vec<Container> = Vec::new();
+    // Where the last comma is in `out`, until we know whether it is a trailing comma.
+    let mut last_comma = None;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        i = match c {
+            '/' if chars.get(i + 1) == Some(&'/') => {
+                let newline = chars[i..].iter().position(|c| *c == '\n');
+                newline.map_or(chars.len(), |newline| i + newline)
+            }
+            '/' if chars.get(i + 1) == Some(&'*') => {
+                let end = chars[i + 2..].windows(2).position(|w| w == ['*', '/']);
+                end.map_or(chars.len(), |end| i + 2 + end + 2)
+            }
+            // Maybe the start of a comment.
+            '/' if i + 1 == chars.len() => i + 1,
+            c if c.is_whitespace() => {
+                out.push(c);
+                i + 1
+            }
+            ',' => {
+                last_comma = Some(out.len());
+                out.push(',');
+                if let Some(Container::Object { expect_key }) = stack.last_mut() {
+                    *expect_key = true;
+                }
+                i + 1
+            }
+            ']' | '}' => {
+                if let Some(comma) = last_comma.take() {
+                    out.remove(comma);
+                }
+                stack.pop();
+                out.push(c);
+                i + 1
+            }
+            _ => {
+                last_comma = None;
+                match c {
+                    '"' | '\'' => repair_string(&chars, i, &mut out),
+                    c if is_word_char(c) => {
+                        let is_key =
+                            matches!(stack.last(), Some(Container::Object { expect_key: true }));
+                        repair_word(&chars, i, is_key, &mut out)
+                    }
+                    c => {
+                        match c {
+                            '{' => stack.push(Container::Object { expect_key: true }),
+                            '[' => stack.push(Container::Array),
+                            ':' => {
+                                if let Some(Container::Object { expect_key }) = stack.last_mut() {
+                                    *expect_key = false;
+                                }
+                            }
+                            _ => {}
+                        }
+                        out.push(c);
+                        i + 1
+                    }
+                }
+            }
+        };
+    }
+
+    out
+}
+
+/// Copy the string that starts at `chars[start]` with double quotes, and return where
+/// it ends.
+fn repair_string(chars: &[char], start: usize, out: &mut String) -> usize {
+    let quote = chars[start];
+    out.push('"');
+    let mut i = start + 1;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => match chars.get(i + 1) {
+                Some('\'') => {
+                    out.push('\'');
+                    i += 1;
+                }
+                Some(escaped) => {
+                    out.push('\\');
+                    out.push(*escaped);
+                    i += 1;
+                }
+                None => out.push('\\'),
+            },
+            c if c == quote => {
+                out.push('"');
+                return i + 1;
+            }
+            '"' => out.push_str("\\\""),
+            c => out.push(c),
+        }
+        i += 1;
+    }
+    i
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphabetic() || c == '_' || c == '$'
+}
+
+/// Copy the bare word that starts at `chars[start]`, and return where it ends.
+///
+/// A key gets quotes. Python's `True`, `False` and `None` become JSON literals.
+fn repair_word(chars: &[char], start: usize, is_key: bool, out: &mut String) -> usize {
+    let mut end = start;
+    while end < chars.len() && (is_word_char(chars[end]) || chars[end].is_ascii_digit()) {
+        end += 1;
+    }
+    let word: String = chars[start..end].iter().collect();
+    let is_cut_off = end == chars.len();
+
+    if is_key {
+        out.push('"');
+        out.push_str(&word);
+        if !is_cut_off {
+            out.push('"');
+        }
+        return end;
+    }
+
+    let literal = [("true", "True"), ("false", "False"), ("null", "None")]
+        .into_iter()
+        .find(|(json, python)| {
+            if is_cut_off {
+                json.starts_with(&word) || python.starts_with(&word)
+            } else {
+                *json == word || *python == word
+            }
+        });
+    match literal {
+        Some((json, _)) => out.push_str(&json[..word.len()]),
+        None => out.push_str(&word),
+    }
+    end
+}
+
+#[cfg(test)]
+mod test {
+    use super::repair;
+
+    #[test]
+    fn test_repair() {
+        assert_eq!(
+            repair("{a: 'it\\'s', \"b\": [1, 2,], c_1: True, // note\n d: None,}"),
+            "{\"a\": \"it's\", \"b\": [1, 2], \"c_1\": true, \n \"d\": null}"
+        );
+        assert_eq!(
+            repair("['say \"hi\"', /* x */ False]"),
+            "[\"say \\\"hi\\\"\",  false]"
+        );
+        assert_eq!(
+            repair("{'a': 'b', \"c\": \"'\"}"),
+            "{\"a\": \"b\", \"c\": \"'\"}"
+        );
+        assert_eq!(repair("[1e5, -2.5E-3, NaN]"), "[1e5, -2.5E-3, NaN]");
+    }
+
+    #[test]
+    fn test_repair_cut_off() {
+        assert_eq!(repair("{a: 'hel"), "{\"a\": \"hel");
+        assert_eq!(repair("{abc"), "{\"abc");
+        assert_eq!(repair("[1,"), "[1,");
+        assert_eq!(repair("[1, Tru"), "[1, tru");
+        assert_eq!(repair("[1, No"), "[1, nu");
+        assert_eq!(repair("[1, /"), "[1, ");
+        assert_eq!(repair("[1, /* 2,"), "[1, ");
+    }
+
+    #[test]
+    fn test_every_prefix() {
+        #[derive(Debug, Default, PartialEq, serde::Deserialize)]
+        #[serde(default)]
+        struct Config {
+            name: String,
+            ports: Vec<u16>,
+            debug: Option<bool>,
+        }
+
+        let input = "{\n  // The service\n  name: 'web',\n  ports: [80, 443,],\n  debug: True,\n}";
+        let mut previous = Config::default();
+        for (i, _) in input.char_indices().skip(1) {
+            let config: Config = crate::from_lenient_json_str(&input[..i]).unwrap();
+            assert!(previous.name.is_empty() || config.name.starts_with(&previous.name));
+            // Numbers may still grow, but elements do not disappear.
+            assert!(
+                config.ports.len() >= previous.ports.len(),
+                "{}",
+                &input[..i]
+            );
+            previous = config;
+        }
+        let config: Config = crate::from_lenient_json_str(input).unwrap();
+        assert_eq!(
+            config,
+            Config {
+                name: "web".into(),
+                ports: vec![80, 443],
+                debug: Some(true),
+            }
+        );
+    }
+}
@@ -52,12 +52,52 @@
 //!
 //! assert_eq!(result.unwrap(), vec![3, 4]);
 //!
+//! # #[cfg(feature = "serde_yaml")] {
 //! let result: Result<Vec<bool>, deser_incomplete::Error<serde_yaml::Error>>
 //!    = deser_incomplete::from_yaml_str("- true\n- false\n- ");
 //!
 //! assert_eq!(result.unwrap(), vec![true, false]);
+//! # }
 //! ```
 //!
+//! If the input arrives in chunks, then [`IncrementalDeserializer`] can keep the buffer for you:
+//!
+//! ```
+//! let mut incremental: deser_incomplete::IncrementalDeserializer<Vec<u32>, _, _>
+//!     = deser_incomplete::IncrementalDeserializer::new_json();
+//!
+//! assert_eq!(incremental.push(b"[3, 4").unwrap(), vec![3, 4]);
+//! assert_eq!(incremental.push(b"0, 5").unwrap(), vec![3, 40, 5]);
+//! ```
+//!
+//! With the `futures` feature, it can also turn a `Stream` of chunks or an `AsyncRead`
+//! into a `Stream` of partial values, see [`IncrementalDeserializer::into_stream`].
+//!
+//! To tell whether the input was complete, or whether we still had to intervene, use
+//! [`IncrementalDeserializer::push_outcome`], which returns an [`Outcome`]
+//! that also says where the input stopped, and which elements can no longer change.
+//! To find out per field, wrap the field in [`Incomplete`].
+//!
+//! For newline-delimited JSON, [`from_json_lines_str`] parses every complete line strictly, and
+//! only tolerates the last line being cut off. Likewise, [`from_yaml_documents_str`] reads a stream of
+//! `---`-separated YAML documents, and [`from_concatenated_json_str`] reads JSON values that were written
+//! back to back.
+//!
+//! When JSON comes wrapped in prose or a Markdown code fence, [`from_embedded_json_str`] finds it and
+//! says where it is.
+//!
+//! When an API streams JSON as fragments inside Server-Sent Events, like the arguments of an
+//! LLM tool call, [`SseJsonFragments`] joins the fragments and deserializes them as they arrive.
+//!
+//! For a huge top-level JSON array, [`JsonArrayElements`] hands out each element as soon as it is
+//! complete, and then forgets its input.
+//!
+//! With the `erased-serde` feature, [`Options::on_complete`] calls you back with every value at a path
+//! like `/results/*`, as soon as that value is complete.
+//!
+//! To send partial values over the network, [`JsonPatchTracker`] turns each next value into a
+//! [`JsonPatch`] against the previous one, so that you only send what changed.
+//!
 //! Command line:
 //!
 //! ```sh
@@ -79,13 +119,25 @@
 //!   actually complete.
 //!
 //!   We also preprocess the input in [`from_yaml_str`], actually there it is even more important
-//!   for good results.
+//!   for good results. With the `ron` feature, [`from_ron_str`] also closes raw strings and
+//!   block comments. With the `toml` feature, [`from_toml_str`] closes any open strings, arrays
+//!   and inline tables, because [`toml`] rejects the whole document otherwise.
 //!
 //!   _Add preprocessing with [`Options::set_random_trailer`], or turn it off such preprocessing
 //!   with [`Options::disable_random_tag`]. You can see the effect of it with
 //!   `cargo run --example live -- --use-random-trailer false`._
 //!
-//!   I expect that binary formats don't need this preprocessing.
+//!   Binary formats prefix strings with their length, so a string that was cut off is an
+//!   error rather than a shorter string. [`from_cbor_slice`] (with the `serde_cbor` feature) leaves
+//!   such a string out. [`from_msgpack_slice`] (with the `rmp-serde` feature) encodes it again with a
+//!   trailer, so that we keep the part that is present. Both are tested on every prefix of
+//!   their input.
+//!
+//!   Postcard and bincode are not self-describing, so there is no trailer for them, and
+//!   a string that was cut off is missing. [`from_postcard_slice`] and [`from_bincode_slice`] (with the
+//!   `postcard` and `bincode` features) return the complete elements of a sequence, even
+//!   though its length says that there should be more. The last element keeps its
+//!   complete fields if the rest are `#[serde(default)]` or an `Option`.
 //!
 //!
 //! ## How this works internally
@@ -168,9 +220,12 @@
 //!
 //! 1. ("soundness") For any complete and valid JSON/YAML, if you call `deser-incomplete`
 //!    on a prefix, then its output should not contain data that doesn't exist in the
-//!    complete JSON/YAML.
+//!    complete JSON/YAML. The exception is a number at the end of the prefix: `[12`
+//!    gives `[12]`, even if the complete JSON is `[123]`, unless you use
+//!    [`Options::withhold_trailing_numbers`].
 //!
 //! 2. ("monotone") A larger prefix should not parse to a shorter output.
+//!    This is best effort, but [`IncrementalDeserializer::monotonic`] holds back values that got smaller.
 //!
 //! 3. ("prompt") Ideally, each prefix contains as much data as we can be certain of.
 //!
@@ -195,14 +250,17 @@
 //! - This approach lets us safely abort parsing and get a value, but
 //!   we cannot skip over invalid segments of input. (For that you need
 //!   an approach like [tree-sitter](https://tree-sitter.github.io/).)
+//!   The exception is a few common JSON mistakes, such as trailing commas and
+//!   unquoted keys, which [`from_lenient_json_str`] accepts.
 //!
 //! - We cannot distinguish eof from invalid input.
 //!
 //! - YAML works well in general, but it is a bit less exhaustively tested than JSON.
 //!   The randomized trailer is really important for YAML.
 //!
-//! - JSON: when parsing a floating-point number, if the end of input happens to fall
-//!   directly after the decimal point, then the number is missing from the output.
+//! - JSON without the randomized trailer: when parsing a floating-point number, if the
+//!   end of input happens to fall directly after the decimal point or the exponent
+//!   marker, then the number is missing from the output.
 //!
 //! - For YAML, the randomized trailer uses a heuristic to see if we are currently in
 //!   an escape sequence in a string --- but this heuristic can fail. In this case,
@@ -234,17 +292,42 @@ macro_rules! trace {
 }
 
 mod attempt;
+#[cfg(feature = "erased-serde")]
+mod callbacks;
+#[cfg(all(feature = "rand", feature = "serde_json"))]
+mod concatenated_json;
+#[cfg(all(feature = "rand", feature = "serde_json"))]
+mod embedded_json;
 pub mod error;
 mod fallback;
+mod incomplete;
+mod incremental;
+#[cfg(all(feature = "rand", feature = "serde_json"))]
+mod json_array;
+#[cfg(all(feature = "rand", feature = "serde_json"))]
+mod json_lines;
+#[cfg(feature = "serde_json")]
+mod json_patch;
+#[cfg(all(feature = "rand", feature = "serde_json"))]
+mod lenient_json;
 mod options_impl;
+mod outcome;
+mod path;
 #[cfg(feature = "rand")]
 pub mod random_trailer;
 #[cfg(not(feature = "rand"))]
 mod random_trailer;
 mod reporter;
 pub mod source;
+#[cfg(all(feature = "rand", feature = "serde_json"))]
+mod sse;
 mod state;
+mod stats;
+#[cfg(feature = "futures")]
+pub mod stream;
 mod util;
+#[cfg(all(feature = "rand", feature = "serde_yaml"))]
+mod yaml_documents;
 
 /// Types and traits that have to be public to satisfy rustc/rustdoc.
 ///
@@ -252,6 +335,12 @@ mod util;
 pub mod options {
     #[cfg(all(feature = "rand", feature = "serde_json"))]
     pub use crate::options_impl::JsonExtraOptions;
+    #[cfg(all(feature = "rand", feature = "rmp-serde"))]
+    pub use crate::options_impl::MsgPackExtraOptions;
+    #[cfg(all(feature = "rand", feature = "ron"))]
+    pub use crate::options_impl::RonExtraOptions;
+    #[cfg(all(feature = "rand", feature = "toml"))]
+    pub use crate::options_impl::TomlExtraOptions;
     #[cfg(all(feature = "rand", feature = "serde_yaml"))]
     pub use crate::options_impl::YamlExtraOptions;
     pub use crate::options_impl::{
@@ -272,23 +361,45 @@ pub mod unstable {
 
 #[allow(unused_imports)]
 mod collection_of_unstable_stuff {
+    pub use crate::attempt::HaltingPoint;
     pub use crate::fallback::Fallbacks;
     pub use crate::options_impl::{
         ExtraOptions, ExtraOptionsStruct, MakeFallbackProvider, MakeReporter,
         UnstableCustomBehavior,
     };
     pub use crate::reporter::{DefaultReporter, Reporter};
+    pub use crate::util::DeserializeKind;
     pub(crate) trait ExtraOptionsIsUnstable {}
 }
 
 use std::borrow::Cow;
 
+#[cfg(all(feature = "rand", feature = "serde_json"))]
+pub use concatenated_json::ConcatenatedJson;
+#[cfg(all(feature = "rand", feature = "serde_json"))]
+pub use embedded_json::EmbeddedJson;
 pub use error::Error;
+pub use incomplete::Incomplete;
+pub use incremental::IncrementalDeserializer;
+#[cfg(all(feature = "rand", feature = "serde_json"))]
+pub use json_array::JsonArrayElements;
+#[cfg(all(feature = "rand", feature = "serde_json"))]
+pub use json_lines::JsonLines;
+#[cfg(feature = "serde_json")]
+pub use json_patch::{JsonPatch, JsonPatchTracker, PatchOperation};
 pub use options_impl::Options;
 use options_impl::UnstableCustomBehavior;
+pub use outcome::Outcome;
+pub use path::{Path, PathSegment};
 #[cfg(doc)]
 use serde::{de::Visitor, Deserialize, Deserializer};
 pub use source::Source;
+#[cfg(all(feature = "rand", feature = "serde_json"))]
+pub use sse::SseJsonFragments;
+pub use state::InterventionReason;
+pub use stats::{AttemptStats, ParseStats};
+#[cfg(all(feature = "rand", feature = "serde_yaml"))]
+pub use yaml_documents::YamlDocuments;
 
 /// Main function. Robustly deserialize incomplete input with [`serde_json`].
 ///
@@ -312,6 +423,107 @@ where
     Options::new_json().deserialize_from_json_slice(Cow::Borrowed(json))
 }
 
+/// Deserialize [JSON Lines](https://jsonlines.org/), where only the last line may be cut
+/// off.
+///
+/// Complete lines are parsed strictly with [`serde_json`], so a malformed line in the
+/// middle is an error, and [`Error::line`] tells you which line. The last line goes
+/// through [`from_json_str`] if it does not end in a newline. If that fails because the
+/// line is malformed, and not only cut off, then [`Error::line`] tells you too. Blank
+/// lines are skipped.
+///
+/// ```
+/// let parsed = deser_incomplete::from_json_lines_str::<Vec<u32>>("[1, 2]\n[3]\n[4, 5").unwrap();
+/// assert_eq!(parsed.values, [vec![1, 2], vec![3], vec![4, 5]]);
+/// assert_eq!(parsed.partial_line, Some(3));
+/// ```
+#[cfg(all(feature = "rand", feature = "serde_json"))]
+pub fn from_json_lines_str<T>(json_lines: &str) -> Result<JsonLines<T>, Error<serde_json::Error>>
+where
+    T: for<'de> serde::Deserialize<'de>,
+{
+    Options::new_json().deserialize_from_json_lines_str(json_lines)
+}
+
+/// Deserialize JSON values that were written back to back, like `{"a":1}{"a":2}{"a":`,
+/// where only the last value may be cut off.
+///
+/// The complete values come from [`serde_json::StreamDeserializer`], so they are parsed
+/// strictly. If the input ends inside the last value, then it goes through
+/// [`from_json_str`], and [`ConcatenatedJson::partial_start`] says where it starts.
+///
+/// ```
+/// let parsed = deser_incomplete::from_concatenated_json_str::<Vec<u32>>("[1][2, 3][4, ").unwrap();
+/// assert_eq!(parsed.values, [vec![1], vec![2, 3], vec![4]]);
+/// assert_eq!(parsed.partial_start, Some(9));
+/// ```
+#[cfg(all(feature = "rand", feature = "serde_json"))]
+pub fn from_concatenated_json_str<T>(
+    json: &str,
+) -> Result<ConcatenatedJson<T>, Error<serde_json::Error>>
+where
+    T: for<'de> serde::Deserialize<'de>,
+{
+    Options::new_json().deserialize_from_concatenated_json_str(json)
+}
+
+/// Like [`from_json_str`], but also accept the mistakes that hand-written or generated
+/// JSON often has:
+///
+/// - trailing commas,
+/// - single-quoted strings,
+/// - unquoted keys,
+/// - Python's `True`, `False` and `None`,
+/// - `//` and `/* */` comments.
+///
+/// We first rewrite the input into strict JSON, and then deserialize that like
+/// [`from_json_str`], so the input may be cut off as well. Positions in errors refer to
+/// the rewritten JSON.
+///
+/// ```
+/// let json = "{name: 'web', ports: [80, 443,], debug: True, // more soon\n  tags: ['a";
+/// let value: serde_json::Value = deser_incomplete::from_lenient_json_str(json).unwrap();
+/// assert_eq!(
+///     value,
+///     serde_json::json!({"name": "web", "ports": [80, 443], "debug": true, "tags": ["a"]})
+/// );
+/// ```
+#[cfg(all(feature = "rand", feature = "serde_json"))]
+pub fn from_lenient_json_str<T>(json: &str) -> Result<T, Error<serde_json::Error>>
+where
+    T: for<'de> serde::Deserialize<'de>,
+{
+    Options::new_json().deserialize_from_lenient_json_str(json)
+}
+
+/// Find JSON inside other text, and deserialize it robustly like [`from_json_str`].
+///
+/// This is for the output of LLMs, which often put JSON in a Markdown code fence, or
+/// just somewhere in their prose, while it is still streaming in. The JSON is the
+/// contents of the first code fence for JSON (or without a language), or else it
+/// starts at the first `{` or `[` that JSON can start at, so `{curly}` braces in the
+/// prose are skipped. Only that part gets the random trailer. Returns `None` if there
+/// is no JSON yet.
+///
+/// ```
+/// let text = "Sure! Here is the data:\n```json\n{\"items\": [1, 2";
+/// let embedded = deser_incomplete::from_embedded_json_str::<serde_json::Value>(text)
+///     .unwrap()
+///     .unwrap();
+/// assert_eq!(embedded.value, serde_json::json!({"items": [1, 2]}));
+/// assert_eq!(&text[embedded.range], "{\"items\": [1, 2");
+/// assert!(embedded.is_partial);
+/// ```
+#[cfg(all(feature = "rand", feature = "serde_json"))]
+pub fn from_embedded_json_str<T>(
+    text: &str,
+) -> Result<Option<EmbeddedJson<T>>, Error<serde_json::Error>>
+where
+    T: for<'de> serde::Deserialize<'de>,
+{
+    Options::new_json().deserialize_from_embedded_json_str(text)
+}
+
 /// Robustly deserialize incomplete input with [`serde_yaml`].
 ///
 /// See methods on [`Options`] for more generic APIs.
@@ -333,3 +545,88 @@ where
 {
     Options::new_yaml().deserialize_from_yaml_slice(Cow::Borrowed(yaml))
 }
+
+/// Deserialize a YAML stream of `---`-separated documents, where only the last document
+/// may be cut off.
+///
+/// Documents before the last separator are parsed strictly with [`serde_yaml`]. The last
+/// document goes through [`from_yaml_str`], so only that one gets the random trailer.
+///
+/// ```
+/// let parsed = deser_incomplete::from_yaml_documents_str::<Vec<u32>>("- 1\n---\n- 2\n- 3").unwrap();
+/// assert_eq!(parsed.documents, [vec![1], vec![2, 3]]);
+/// ```
+#[cfg(all(feature = "rand", feature = "serde_yaml"))]
+pub fn from_yaml_documents_str<T>(yaml: &str) -> Result<YamlDocuments<T>, Error<serde_yaml::Error>>
+where
+    T: for<'de> serde::Deserialize<'de>,
+{
+    Options::new_yaml().deserialize_from_yaml_documents_str(yaml)
+}
+
+/// Robustly deserialize incomplete input with [`serde_cbor`].
+///
+/// See methods on [`Options`] for more generic APIs.
+#[cfg(feature = "serde_cbor")]
+pub fn from_cbor_slice<'de, T>(cbor: &'de [u8]) -> Result<T, Error<serde_cbor::Error>>
+where
+    T: serde::Deserialize<'de>,
+{
+    Options::new_no_nonce().deserialize_from_cbor_slice(cbor)
+}
+
+/// Robustly deserialize incomplete input with [`postcard`].
+///
+/// See methods on [`Options`] for more generic APIs.
+#[cfg(feature = "postcard")]
+pub fn from_postcard_slice<'de, T>(postcard: &'de [u8]) -> Result<T, Error<postcard::Error>>
+where
+    T: serde::Deserialize<'de>,
+{
+    Options::new_postcard().deserialize_from_postcard_slice(postcard)
+}
+
+/// Robustly deserialize incomplete input with [`bincode`], configured like
+/// [`bincode::deserialize`].
+///
+/// See methods on [`Options`] for more generic APIs.
+#[cfg(feature = "bincode")]
+pub fn from_bincode_slice<'de, T>(bincode: &'de [u8]) -> Result<T, Error<bincode::Error>>
+where
+    T: serde::Deserialize<'de>,
+{
+    Options::new_bincode().deserialize_from_bincode_slice(bincode)
+}
+
+/// Robustly deserialize incomplete input with [`rmp_serde`].
+///
+/// See methods on [`Options`] for more generic APIs.
+#[cfg(all(feature = "rand", feature = "rmp-serde"))]
+pub fn from_msgpack_slice<T>(msgpack: &[u8]) -> Result<T, Error<rmp_serde::decode::Error>>
+where
+    T: for<'de> serde::Deserialize<'de>,
+{
+    Options::new_msgpack().deserialize_from_msgpack_slice(Cow::Borrowed(msgpack))
+}
+
+/// Robustly deserialize incomplete input with [`ron`].
+///
+/// See methods on [`Options`] for more generic APIs.
+#[cfg(all(feature = "rand", feature = "ron"))]
+pub fn from_ron_str<T>(ron: &str) -> Result<T, Error<ron::Error>>
+where
+    T: for<'de> serde::Deserialize<'de>,
+{
+    Options::new_ron().deserialize_from_ron_str(Cow::Borrowed(ron))
+}
+
+/// Robustly deserialize incomplete input with [`toml`].
+///
+/// See methods on [`Options`] for more generic APIs.
+#[cfg(all(feature = "rand", feature = "toml"))]
+pub fn from_toml_str<T>(toml: &str) -> Result<T, Error<toml::de::Error>>
+where
+    T: for<'de> serde::Deserialize<'de>,
+{
+    Options::new_toml().deserialize_from_toml_str(Cow::Borrowed(toml))
+}
@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use std::marker::PhantomData;
 #[cfg(feature = "rand")]
 use std::sync::Arc;
+use std::time::Instant;
 
 use serde::de::DeserializeSeed;
 #[cfg(doc)]
@@ -13,9 +14,10 @@ use crate::error::InternalError;
 use crate::fallback::{DefaultFallbacks, Fallbacks};
 pub use crate::random_trailer::RandomTrailer;
 use crate::random_trailer::{InputPlusTrailer, NoopRandomTrailer, StringLike};
-use crate::state::AttemptState;
+use crate::state::{AttemptState, Carryover, Finished};
+use crate::stats::ParseStats;
 use crate::unstable::{DefaultReporter, ExtraOptionsIsUnstable};
-use crate::Source;
+use crate::{Outcome, Source};
 
 /// Number of times that we may backtrack.
 ///
@@ -37,6 +39,12 @@ const RANDOM_TAG_LEN: usize = 8;
 ///
 /// - [`Options::deserialize_from_json_str`] for JSON,
 /// - [`Options::deserialize_from_yaml_str`] for YAML,
+/// - [`Options::deserialize_from_toml_str`] for TOML,
+/// - [`Options::deserialize_from_ron_str`] for RON,
+/// - [`Options::deserialize_from_cbor_slice`] for CBOR,
+/// - [`Options::deserialize_from_msgpack_slice`] for MessagePack,
+/// - [`Options::deserialize_from_postcard_slice`] for postcard,
+/// - [`Options::deserialize_from_bincode_slice`] for bincode,
 /// - [`Options::deserialize_source`] for a generic source.
 #[derive(Clone, Debug)]
 pub struct Options<Extra: ExtraOptions = DefaultExtraOptions> {
@@ -51,6 +59,10 @@ pub struct Options<Extra: ExtraOptions = DefaultExtraOptions> {
 
     pub(crate) behavior: UnstableCustomBehavior,
 
+    /// See [`Options::on_complete`].
+    #[cfg(feature = "erased-serde")]
+    pub(crate) callbacks: crate::callbacks::Callbacks,
+
     pub(crate) extra: Extra,
 }
 
@@ -82,6 +94,62 @@ impl Options {
         base.set_random_trailer(crate::random_trailer::yaml::YamlRandomTrailer)
     }
 
+    /// Default config for RON.
+    ///
+    /// This will currently generate a short extra trailer on inputs
+    /// for improved deserialization of incomplete RON.
+    #[cfg(all(feature = "rand", feature = "ron"))]
+    pub fn new_ron() -> Options<RonExtraOptions> {
+        let base = Options {
+            ..Options::new_nonce()
+        };
+        base.set_random_trailer(crate::random_trailer::ron::RonRandomTrailer)
+    }
+
+    /// Default config for MessagePack.
+    ///
+    /// This will currently re-encode a string or byte array that was cut off,
+    /// so that we can still deserialize the part that is present.
+    #[cfg(all(feature = "rand", feature = "rmp-serde"))]
+    pub fn new_msgpack() -> Options<MsgPackExtraOptions> {
+        let base = Options {
+            ..Options::new_nonce()
+        };
+        base.set_random_trailer(crate::random_trailer::msgpack::MsgPackRandomTrailer)
+    }
+
+    /// Default config for TOML.
+    ///
+    /// [`toml`] parses the whole document before deserializing, so this
+    /// trailer closes any open strings, arrays and inline tables.
+    #[cfg(all(feature = "rand", feature = "toml"))]
+    pub fn new_toml() -> Options<TomlExtraOptions> {
+        let base = Options {
+            ..Options::new_nonce()
+        };
+        base.set_random_trailer(crate::random_trailer::toml::TomlRandomTrailer)
+    }
+
+    /// Default config for postcard.
+    ///
+    /// Postcard writes `Some` explicitly, so if the value inside it was cut off,
+    /// we can halt there and use `None` instead. Sequences announce their length
+    /// up front, so we drop an element if none of its data was in the input.
+    #[cfg(feature = "postcard")]
+    pub fn new_postcard() -> Options<DefaultExtraOptions> {
+        Options::new_no_nonce().for_length_prefixed_format()
+    }
+
+    /// Default config for bincode.
+    ///
+    /// Bincode writes `Some` explicitly, so if the value inside it was cut off,
+    /// we can halt there and use `None` instead. Sequences announce their length
+    /// up front, so we drop an element if none of its data was in the input.
+    #[cfg(feature = "bincode")]
+    pub fn new_bincode() -> Options<DefaultExtraOptions> {
+        Options::new_no_nonce().for_length_prefixed_format()
+    }
+
     /// Basic config, suitable for any data format.
     ///
     /// These options support adding a randomized trailer to the input.
@@ -116,12 +184,21 @@ impl Options {
             random_tag: None,
             max_n_backtracks: DEFAULT_MAX_BACKTRACKS,
             behavior: UnstableCustomBehavior::default(),
+            #[cfg(feature = "erased-serde")]
+            callbacks: Default::default(),
             extra: DefaultExtraOptions::default(),
         }
     }
 }
 
 impl<Extra: ExtraOptions> Options<Extra> {
+    #[cfg(any(feature = "postcard", feature = "bincode"))]
+    fn for_length_prefixed_format(mut self) -> Self {
+        self.behavior.backtrack_option_none = true;
+        self.behavior.skip_seq_item_without_data = true;
+        self
+    }
+
     pub fn with_max_n_backtracks(mut self, max_n_backtracks: Option<usize>) -> Self {
         self.max_n_backtracks = max_n_backtracks;
         self
@@ -150,6 +227,20 @@ impl<Extra: ExtraOptions> Options<Extra> {
         self.deserialize_from_json_slice_borrowed(&prepared)
     }
 
+    /// Like [`Self::deserialize_from_json_str`], but also report whether the input looked
+    /// complete. See [`Outcome`].
+    #[cfg(all(feature = "rand", feature = "serde_json"))]
+    pub fn deserialize_from_json_str_outcome<T>(
+        self,
+        json: Cow<str>,
+    ) -> Result<Outcome<T>, Error<serde_json::Error>>
+    where
+        T: for<'de> serde::de::Deserialize<'de>,
+    {
+        let InputPlusTrailer(prepared) = self.prepare_str_for_borrowed_deserialization(json);
+        self.deserialize_source_outcome(crate::source::JsonStr(&prepared))
+    }
+
     /// Like [`crate::from_yaml_str`], but with options. This applies the random trailer.
     #[cfg(all(feature = "rand", feature = "serde_yaml"))]
     pub fn deserialize_from_yaml_str<T>(self, yaml: Cow<str>) -> Result<T, Error<serde_yaml::Error>>
@@ -173,6 +264,39 @@ impl<Extra: ExtraOptions> Options<Extra> {
         self.deserialize_from_yaml_slice_borrowed(&prepared)
     }
 
+    /// Like [`crate::from_ron_str`], but with options. This applies the random trailer.
+    #[cfg(all(feature = "rand", feature = "ron"))]
+    pub fn deserialize_from_ron_str<T>(self, ron: Cow<str>) -> Result<T, Error<ron::Error>>
+    where
+        T: for<'de> serde::de::Deserialize<'de>,
+    {
+        let prepared = self.prepare_str_for_borrowed_deserialization(ron);
+        self.deserialize_from_ron_str_borrowed(&prepared)
+    }
+
+    /// Like [`crate::from_msgpack_slice`], but with options. This applies the random trailer.
+    #[cfg(all(feature = "rand", feature = "rmp-serde"))]
+    pub fn deserialize_from_msgpack_slice<T>(
+        self,
+        msgpack: Cow<[u8]>,
+    ) -> Result<T, Error<rmp_serde::decode::Error>>
+    where
+        T: for<'de> serde::de::Deserialize<'de>,
+    {
+        let prepared = self.prepare_slice_for_borrowed_deserialization(msgpack);
+        self.deserialize_from_msgpack_slice_borrowed(&prepared)
+    }
+
+    /// Like [`crate::from_toml_str`], but with options. This applies the random trailer.
+    #[cfg(all(feature = "rand", feature = "toml"))]
+    pub fn deserialize_from_toml_str<T>(self, toml: Cow<str>) -> Result<T, Error<toml::de::Error>>
+    where
+        T: for<'de> serde::de::Deserialize<'de>,
+    {
+        let prepared = self.prepare_str_for_borrowed_deserialization(toml);
+        self.deserialize_from_toml_str_borrowed(&prepared)
+    }
+
     /// Like [`Self::deserialize_from_json_slice`], but can deserialize borrowed strings and return them
     /// directly.
     ///
@@ -290,6 +414,89 @@ impl<Extra: ExtraOptions> Options<Extra> {
         self.deserialize_source(crate::source::YamlBytes(prepared_yaml.as_ref()))
     }
 
+    /// Like [`crate::from_cbor_slice`], but with options.
+    ///
+    /// CBOR does not need a random trailer, so the input is used as it is.
+    #[cfg(feature = "serde_cbor")]
+    pub fn deserialize_from_cbor_slice<'de, T>(
+        self,
+        cbor: &'de [u8],
+    ) -> Result<T, Error<serde_cbor::Error>>
+    where
+        T: serde::de::Deserialize<'de>,
+    {
+        self.deserialize_source(crate::source::CborBytes(cbor))
+    }
+
+    /// Like [`crate::from_postcard_slice`], but with options.
+    ///
+    /// Postcard is not self-describing, so there is no random trailer and the input is
+    /// used as it is.
+    #[cfg(feature = "postcard")]
+    pub fn deserialize_from_postcard_slice<'de, T>(
+        self,
+        postcard: &'de [u8],
+    ) -> Result<T, Error<postcard::Error>>
+    where
+        T: serde::de::Deserialize<'de>,
+    {
+        self.deserialize_source(crate::source::PostcardBytes(postcard))
+    }
+
+    /// Like [`crate::from_bincode_slice`], but with options.
+    ///
+    /// Bincode is not self-describing, so there is no random trailer and the input is
+    /// used as it is.
+    #[cfg(feature = "bincode")]
+    pub fn deserialize_from_bincode_slice<'de, T>(
+        self,
+        bincode: &'de [u8],
+    ) -> Result<T, Error<bincode::Error>>
+    where
+        T: serde::de::Deserialize<'de>,
+    {
+        self.deserialize_source(crate::source::BincodeBytes(bincode))
+    }
+
+    /// Advanced API. See [`Self::deserialize_from_json_str_borrowed`], or
+    /// use [`Self::deserialize_from_msgpack_slice`] for a simpler API.
+    #[cfg(feature = "rmp-serde")]
+    pub fn deserialize_from_msgpack_slice_borrowed<'de, T>(
+        self,
+        InputPlusTrailer(prepared_msgpack): &'de InputPlusTrailer<impl AsRef<[u8]>>,
+    ) -> Result<T, Error<rmp_serde::decode::Error>>
+    where
+        T: serde::de::Deserialize<'de>,
+    {
+        self.deserialize_source(crate::source::MsgPackBytes(prepared_msgpack.as_ref()))
+    }
+
+    /// Advanced API. See [`Self::deserialize_from_json_str_borrowed`], or
+    /// use [`Self::deserialize_from_ron_str`] for a simpler API.
+    #[cfg(feature = "ron")]
+    pub fn deserialize_from_ron_str_borrowed<'de, T>(
+        self,
+        InputPlusTrailer(prepared_ron): &'de InputPlusTrailer<impl AsRef<str>>,
+    ) -> Result<T, Error<ron::Error>>
+    where
+        T: serde::de::Deserialize<'de>,
+    {
+        self.deserialize_source(crate::source::RonStr(prepared_ron.as_ref()))
+    }
+
+    /// Advanced API. See [`Self::deserialize_from_json_str_borrowed`], or
+    /// use [`Self::deserialize_from_toml_str`] for a simpler API.
+    #[cfg(feature = "toml")]
+    pub fn deserialize_from_toml_str_borrowed<'de, T>(
+        self,
+        InputPlusTrailer(prepared_toml): &'de InputPlusTrailer<impl AsRef<str>>,
+    ) -> Result<T, Error<toml::de::Error>>
+    where
+        T: serde::de::Deserialize<'de>,
+    {
+        self.deserialize_source(crate::source::TomlStr(prepared_toml.as_ref()))
+    }
+
     /// Prepare a string for borrowed deserialization with a method
     /// like [`Self::deserialize_from_json_str_borrowed`], by appending the random trailer.
     ///
@@ -301,6 +508,11 @@ impl<Extra: ExtraOptions> Options<Extra> {
     ) -> InputPlusTrailer<Cow<'a, str>> {
         use RandomTrailer as _;
 
+        let len = self.len_without_trailing_number(input.as_bytes());
+        if len < input.len() {
+            Cow::to_mut(&mut input).truncate(len);
+        }
+
         #[cfg(feature = "rand")]
         if let Some(tag) = self.random_tag.as_ref() {
             self.extra
@@ -321,6 +533,11 @@ impl<Extra: ExtraOptions> Options<Extra> {
     ) -> InputPlusTrailer<Cow<'a, [u8]>> {
         use RandomTrailer as _;
 
+        let len = self.len_without_trailing_number(&input);
+        if len < input.len() {
+            Cow::to_mut(&mut input).truncate(len);
+        }
+
         #[cfg(feature = "rand")]
         if let Some(tag) = self.random_tag.as_ref() {
             self.extra
@@ -330,6 +547,27 @@ impl<Extra: ExtraOptions> Options<Extra> {
         InputPlusTrailer(input)
     }
 
+    /// How much of the input to deserialize, leaving out a trailing number if
+    /// [`UnstableCustomBehavior::withhold_trailing_number`] is set.
+    pub(crate) fn len_without_trailing_number(&self, input: &[u8]) -> usize {
+        if !self.behavior.withhold_trailing_number {
+            return input.len();
+        }
+        input.len() - self.extra.get_random_trailer().trailing_number_len(input)
+    }
+
+    /// Append the random trailer to an input that we own, if a random tag is enabled.
+    pub(crate) fn append_random_trailer(&self, input: &mut Vec<u8>) {
+        #![cfg_attr(not(feature = "rand"), allow(unused_variables, clippy::ptr_arg))]
+
+        #[cfg(feature = "rand")]
+        if let Some(tag) = self.random_tag.as_ref() {
+            self.extra
+                .get_random_trailer()
+                .prepare_vec_with_tag(input, tag);
+        }
+    }
+
     /// Customize internal behavior.
     ///
     /// This is meant for data formats where the defaults may not work well, but
@@ -361,6 +599,29 @@ impl<Extra: ExtraOptions> Options<Extra> {
         Options { behavior, ..self }
     }
 
+    /// Leave out a number at the end of the input, because it may not be complete yet.
+    ///
+    /// By default, `[12` deserializes as `[12]`, even though the input may continue as
+    /// `[123]`. With this option, the number only appears once something after it
+    /// shows that it is complete. This works for JSON and YAML.
+    ///
+    /// ```
+    /// use deser_incomplete::Options;
+    ///
+    /// let prices: Vec<u32> = Options::new_json()
+    ///     .withhold_trailing_numbers()
+    ///     .deserialize_from_json_str("[12, 34".into())
+    ///     .unwrap();
+    /// assert_eq!(prices, vec![12]);
+    /// ```
+    ///
+    /// Note that this also leaves out a number at the end of a complete document, such
+    /// as `12`, except in [`crate::IncrementalDeserializer::finish`].
+    pub fn withhold_trailing_numbers(mut self) -> Self {
+        self.behavior.withhold_trailing_number = true;
+        self
+    }
+
     /// Don't use a random tag. This can make deserialization a tiny bit cheaper,
     /// because the input does not have to be reallocated.
     #[cfg(feature = "rand")]
@@ -389,6 +650,8 @@ where
             random_tag,
             max_n_backtracks,
             behavior,
+            #[cfg(feature = "erased-serde")]
+            callbacks,
             extra,
         } = self;
 
@@ -396,6 +659,8 @@ where
             random_tag,
             max_n_backtracks,
             behavior,
+            #[cfg(feature = "erased-serde")]
+            callbacks,
             extra: ExtraOptionsStruct {
                 make_reporter: extra.make_reporter,
                 make_fallback_provider: extra.make_fallback_provider,
@@ -431,6 +696,8 @@ where
             random_tag,
             max_n_backtracks,
             behavior,
+            #[cfg(feature = "erased-serde")]
+            callbacks,
             extra,
         } = self;
 
@@ -438,6 +705,8 @@ where
             random_tag,
             max_n_backtracks,
             behavior,
+            #[cfg(feature = "erased-serde")]
+            callbacks,
             extra: ExtraOptionsStruct {
                 make_reporter: CustomReporter(reporter),
                 make_fallback_provider: extra.make_fallback_provider,
@@ -462,6 +731,8 @@ where
             random_tag,
             max_n_backtracks,
             behavior,
+            #[cfg(feature = "erased-serde")]
+            callbacks,
             extra,
         } = self;
 
@@ -469,6 +740,8 @@ where
             random_tag,
             max_n_backtracks,
             behavior,
+            #[cfg(feature = "erased-serde")]
+            callbacks,
             extra: ExtraOptionsStruct {
                 make_reporter: extra.make_reporter,
                 make_fallback_provider: CustomFallbackProvider(fallback_provider),
@@ -496,6 +769,21 @@ impl<Extra: ExtraOptions> Options<Extra> {
 
         false
     }
+
+    /// Whether the random trailer put this placeholder where the input did not
+    /// have a value yet.
+    pub(crate) fn is_placeholder_stringlike(&self, stringy: &impl StringLike) -> bool {
+        #![cfg_attr(not(feature = "rand"), allow(unused_variables))]
+
+        #[cfg(feature = "rand")]
+        {
+            if let Some(tag) = self.random_tag.as_ref() {
+                return self.extra.get_random_trailer().is_placeholder(stringy, tag);
+            }
+        }
+
+        false
+    }
 }
 
 /// Monomorphized options.
@@ -535,6 +823,24 @@ pub type YamlExtraOptions = ExtraOptionsStruct<
     MakeDefaultFallbacks,
     crate::random_trailer::yaml::YamlRandomTrailer,
 >;
+#[cfg(all(feature = "rand", feature = "rmp-serde"))]
+pub type MsgPackExtraOptions = ExtraOptionsStruct<
+    MakeDefaultReporter,
+    MakeDefaultFallbacks,
+    crate::random_trailer::msgpack::MsgPackRandomTrailer,
+>;
+#[cfg(all(feature = "rand", feature = "ron"))]
+pub type RonExtraOptions = ExtraOptionsStruct<
+    MakeDefaultReporter,
+    MakeDefaultFallbacks,
+    crate::random_trailer::ron::RonRandomTrailer,
+>;
+#[cfg(all(feature = "rand", feature = "toml"))]
+pub type TomlExtraOptions = ExtraOptionsStruct<
+    MakeDefaultReporter,
+    MakeDefaultFallbacks,
+    crate::random_trailer::toml::TomlRandomTrailer,
+>;
 
 #[doc(hidden)]
 #[derive(Debug, Clone, Default)]
@@ -689,6 +995,18 @@ pub struct UnstableCustomBehavior {
     /// just go in and visit a None.
     pub fallback_none: bool,
     pub fallback_none_at_mandatory: bool,
+    /// If deserializing the inside of a `Some` fails, then retry with `None`.
+    ///
+    /// This matters for formats that encode structs as sequences, such as postcard
+    /// and bincode: there, skipping an `Option` field that isn't `#[serde(default)]`
+    /// makes the whole struct fail.
+    ///
+    /// ## Caveat
+    ///
+    /// Only use this when `Some` is explicit in the data format. For instance,
+    /// [`serde_json`] visits `Some` for anything that is not `null`, even at the end
+    /// of the input, and this would generate spurious list elements.
+    pub backtrack_option_none: bool,
     /// In case [`Deserializer::deserialize_unit`] does not do anything, then
     /// just go in and visit a unit.
     ///
@@ -715,7 +1033,36 @@ pub struct UnstableCustomBehavior {
     /// `fallback_*_skip_item` are probably a bad idea, because they can mask a backtracking
     /// point that is better.
     pub fallback_seq_skip_item: bool,
+    /// If deserializing an element of a sequence fails, then on the next attempt,
+    /// halt just before that element.
+    ///
+    /// ## Length-prefixed sequences
+    ///
+    /// Formats like postcard and bincode write the number of elements before the
+    /// elements themselves. If the input announces N elements but only has k of them,
+    /// then the deserializer still asks for element k+1, and it fails at the end of
+    /// the input. This option makes us halt before that element, and we return the k
+    /// complete elements. The announced length is not checked again.
+    ///
+    /// Such formats also encode structs as sequences of fields, so the fields of the
+    /// last, incomplete element are only kept if the element can be built from them:
+    /// missing fields need `#[serde(default)]` (or be an `Option` with
+    /// [`backtrack_option_none`](Self::backtrack_option_none)). Strings and bytes that
+    /// were cut off are missing altogether, because these formats are not
+    /// self-describing and we cannot add a trailer.
     pub backtrack_seq_skip_item: bool,
+    /// Drop an element of a sequence if the deserializer did not visit any data for
+    /// it, and it was only built from fallbacks after we intervened.
+    ///
+    /// With length-prefixed sequences, the element after the last complete one is
+    /// often entered at the end of the input. Without this option, it would appear
+    /// as `None` or as a struct full of `#[serde(default)]` values.
+    ///
+    /// ## Caveat
+    ///
+    /// Only use this when structs and tuples are read without any delimiter, as in
+    /// postcard and bincode. We do not count the start of a struct as data.
+    pub skip_seq_item_without_data: bool,
     pub fallback_tuple_empty: bool,
     /// `fallback_*_skip_item` are probably a bad idea, because they can mask a backtracking
     /// point that is better.
@@ -753,6 +1100,18 @@ pub struct UnstableCustomBehavior {
     /// Whether incomplete strings should be allowed or rejected
     /// in [`MapAccess::next_key`] or [`EnumAccess::variant`].
     pub allow_incomplete_string_in_key_or_variant: bool,
+
+    /// Leave out a number at the end of the input, because more digits may follow.
+    ///
+    /// Without this, `[12` deserializes as `[12]`, even though the input may continue
+    /// as `[123]`. The random trailer decides what looks like a number, so this only
+    /// works for JSON and YAML.
+    ///
+    /// ## Caveat
+    ///
+    /// A complete document that ends in a number, such as `12`, also loses that
+    /// number, except in [`crate::IncrementalDeserializer::finish`].
+    pub withhold_trailing_number: bool,
 }
 
 impl Default for UnstableCustomBehavior {
@@ -769,6 +1128,7 @@ impl Default for UnstableCustomBehavior {
             fallback_bytes_empty: false,
             fallback_none: true,
             fallback_none_at_mandatory: true,
+            backtrack_option_none: false,
             fallback_unit: false,
             fallback_unit_at_mandatory: true,
             fallback_unit_struct: false,
@@ -778,6 +1138,7 @@ impl Default for UnstableCustomBehavior {
             backtrack_seq_empty_for_value: true,
             fallback_seq_skip_item: false,
             backtrack_seq_skip_item: true,
+            skip_seq_item_without_data: false,
             fallback_tuple_empty: false,
             fallback_tuple_skip_item: false,
             backtrack_tuple_skip_item: true,
@@ -798,6 +1159,7 @@ impl Default for UnstableCustomBehavior {
             fallback_other_skip_item: false,
             backtrack_other_skip_item: true,
             allow_incomplete_string_in_key_or_variant: false,
+            withhold_trailing_number: false,
         }
     }
 }
@@ -816,6 +1178,7 @@ impl UnstableCustomBehavior {
             fallback_bytes_empty,
             fallback_none,
             fallback_none_at_mandatory,
+            backtrack_option_none: _,
             fallback_unit,
             fallback_unit_at_mandatory,
             fallback_unit_struct,
@@ -825,6 +1188,7 @@ impl UnstableCustomBehavior {
             backtrack_seq_empty_for_value: _,
             fallback_seq_skip_item,
             backtrack_seq_skip_item: _,
+            skip_seq_item_without_data: _,
             fallback_tuple_empty,
             fallback_tuple_skip_item,
             backtrack_tuple_skip_item: _,
@@ -845,6 +1209,7 @@ impl UnstableCustomBehavior {
             fallback_other_skip_item,
             backtrack_other_skip_item: _,
             allow_incomplete_string_in_key_or_variant: allow_incomplete_string_in_key,
+            withhold_trailing_number: _,
         } = &mut self;
 
         *fallback_any_as_none = false;
@@ -896,6 +1261,7 @@ impl UnstableCustomBehavior {
             fallback_bytes_empty: false,
             fallback_none: false,
             fallback_none_at_mandatory: false,
+            backtrack_option_none: false,
             fallback_unit: false,
             fallback_unit_at_mandatory: false,
             fallback_unit_struct: false,
@@ -905,6 +1271,7 @@ impl UnstableCustomBehavior {
             backtrack_seq_empty_for_value: false,
             fallback_seq_skip_item: false,
             backtrack_seq_skip_item: false,
+            skip_seq_item_without_data: false,
             fallback_tuple_empty: false,
             fallback_tuple_skip_item: false,
             backtrack_tuple_skip_item: false,
@@ -925,6 +1292,7 @@ impl UnstableCustomBehavior {
             fallback_other_skip_item: false,
             backtrack_other_skip_item: false,
             allow_incomplete_string_in_key_or_variant: false,
+            withhold_trailing_number: false,
         }
     }
 
@@ -941,6 +1309,7 @@ impl UnstableCustomBehavior {
             fallback_bytes_empty: true,
             fallback_none: true,
             fallback_none_at_mandatory: true,
+            backtrack_option_none: false,
             fallback_unit: true,
             fallback_unit_at_mandatory: true,
             fallback_unit_struct: true,
@@ -950,6 +1319,7 @@ impl UnstableCustomBehavior {
             backtrack_seq_empty_for_value: true,
             fallback_seq_skip_item: true,
             backtrack_seq_skip_item: true,
+            skip_seq_item_without_data: false,
             fallback_tuple_empty: true,
             fallback_tuple_skip_item: true,
             backtrack_tuple_skip_item: true,
@@ -970,6 +1340,7 @@ impl UnstableCustomBehavior {
             fallback_other_skip_item: true,
             backtrack_other_skip_item: true,
             allow_incomplete_string_in_key_or_variant: true,
+            withhold_trailing_number: false,
         }
     }
 }
@@ -1000,14 +1371,121 @@ impl<Extra: ExtraOptions> Options<Extra> {
     pub fn deserialize_seed<'de, T, S>(
         self,
         seed: T,
-        mut source: S,
+        source: S,
     ) -> Result<T::Value, Error<S::Error>>
     where
         T: DeserializeSeed<'de> + Clone,
         S: Source<'de>,
     {
+        self.deserialize_seed_impl(
+            seed,
+            source,
+            Carryover::default(),
+            &mut ParseStats::default(),
+        )
+        .map(|finished| finished.value)
+    }
+
+    /// Like [`Self::deserialize_source`], but also return numbers about how it went,
+    /// whether or not it succeeded.
+    pub fn deserialize_source_with_stats<'de, T, S>(
+        self,
+        source: S,
+    ) -> (Result<T, Error<S::Error>>, ParseStats)
+    where
+        T: Deserialize<'de>,
+        S: Source<'de>,
+    {
+        self.deserialize_seed_with_stats(PhantomData, source)
+    }
+
+    /// Like [`Self::deserialize_seed`], but also return numbers about how it went,
+    /// whether or not it succeeded.
+    pub fn deserialize_seed_with_stats<'de, T, S>(
+        self,
+        seed: T,
+        source: S,
+    ) -> (Result<T::Value, Error<S::Error>>, ParseStats)
+    where
+        T: DeserializeSeed<'de> + Clone,
+        S: Source<'de>,
+    {
+        let mut stats = ParseStats::default();
+        let result = self
+            .deserialize_seed_impl(seed, source, Carryover::default(), &mut stats)
+            .map(|finished| finished.value);
+        (result, stats)
+    }
+
+    /// Like [`Self::deserialize_source`], but also report whether the input looked complete.
+    pub fn deserialize_source_outcome<'de, T, S>(
+        self,
+        source: S,
+    ) -> Result<Outcome<T>, Error<S::Error>>
+    where
+        T: Deserialize<'de>,
+        S: Source<'de>,
+    {
+        self.deserialize_seed_outcome(PhantomData, source)
+    }
+
+    /// Like [`Self::deserialize_seed`], but also report whether the input looked complete.
+    pub fn deserialize_seed_outcome<'de, T, S>(
+        self,
+        seed: T,
+        source: S,
+    ) -> Result<Outcome<T::Value>, Error<S::Error>>
+    where
+        T: DeserializeSeed<'de> + Clone,
+        S: Source<'de>,
+    {
+        self.deserialize_seed_impl(
+            seed,
+            source,
+            Carryover::default(),
+            &mut ParseStats::default(),
+        )
+        .map(Finished::into_outcome)
+    }
+
+    /// Like [`Self::deserialize_seed`], but also hands back the state of the
+    /// successful attempt, and fills in `stats`.
+    ///
+    /// With [`Carryover::resume`], the first attempt halts where an earlier parse of the
+    /// same input did. If that fails, we start over without it.
+    pub(crate) fn deserialize_seed_impl<'de, T, S>(
+        self,
+        seed: T,
+        source: S,
+        carryover: Carryover,
+        stats: &mut ParseStats,
+    ) -> Result<Finished<T::Value, Extra>, Error<S::Error>>
+    where
+        T: DeserializeSeed<'de> + Clone,
+        S: Source<'de>,
+    {
+        let start = Instant::now();
+        let result = self.deserialize_seed_attempts(seed, source, carryover, stats);
+        stats.elapsed = start.elapsed();
+        result
+    }
+
+    fn deserialize_seed_attempts<'de, T, S>(
+        self,
+        seed: T,
+        mut source: S,
+        carryover: Carryover,
+        stats: &mut ParseStats,
+    ) -> Result<Finished<T::Value, Extra>, Error<S::Error>>
+    where
+        T: DeserializeSeed<'de> + Clone,
+        S: Source<'de>,
+    {
+        source.check().map_err(Error::from_de)?;
+
         let mut state = self.build();
-        let mut attempt = AttemptState::initial(&state);
+        let mut resumed = carryover.resume.is_some();
+        let mut attempt = AttemptState::initial(&state, carryover);
 
         while {
             let max_n_backtracks = state.config.max_n_backtracks;
@@ -1029,19 +1507,28 @@ impl<Extra: ExtraOptions> Options<Extra> {
             };
 
             match seed.clone().deserialize(deserializer) {
-                Ok(value) => return Ok(value),
+                Ok(value) => {
+                    stats.attempts.push(attempt.take_stats(true));
+                    return Ok(Finished { value, attempt });
+                }
                 Err(error) => {
                     debug!(attempt = state.n_backtracks, %error, "attempt failed");
                 }
             }
-
-            attempt = match attempt.next_attempt_state_after_failure()? {
-                Some(new_attempt) => new_attempt,
-                None => {
-                    return Err(InternalError::NoPotentialBacktrackPoint {
-                        after_backtracks: state.n_backtracks,
+            stats.attempts.push(attempt.take_stats(false));
+
+            attempt = if std::mem::take(&mut resumed) {
+                debug!("could not halt where the earlier parse did, starting over");
+                attempt.restart(&state)
+            } else {
+                match attempt.next_attempt_state_after_failure()? {
+                    Some(new_attempt) => new_attempt,
+                    None => {
+                        return Err(InternalError::NoPotentialBacktrackPoint {
+                            after_backtracks: state.n_backtracks,
+                        }
+                        .into());
                     }
-                    .into());
                 }
             };
             state.n_backtracks += 1;
@@ -0,0 +1,250 @@
+use crate::path::Path;
+use crate::state::InterventionReason;
+
+/// A deserialized value, together with whether the input looked complete.
+///
+/// Get this from methods like [`crate::Options::deserialize_source_outcome`] or
+/// [`crate::IncrementalDeserializer::push_outcome`].
+///
+/// ```
+/// use deser_incomplete::{Options, Outcome};
+///
+/// let outcome = Options::new_json()
+///     .deserialize_from_json_str_outcome::<Vec<u32>>(r#"[1, 2"#.into())
+///     .unwrap();
+/// assert!(!outcome.is_complete());
+/// assert_eq!(outcome.into_value(), [1, 2]);
+///
+/// let outcome = Options::new_json()
+///     .deserialize_from_json_str_outcome::<Vec<u32>>(r#"[1, 2]"#.into())
+///     .unwrap();
+/// assert_eq!(outcome, Outcome::Complete(vec![1, 2]));
+/// ```
+///
+/// We can only notice truncation that the data format reports to us. For instance, the
+/// JSON `12` may be a truncated `123`, but it deserializes without any intervention, so
+/// we call it complete. The same goes for trailing data after the value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome<T> {
+    /// We did not need to intervene: the input looks complete and valid.
+    Complete(T),
+    /// We had to intervene to produce this value, for instance because the input was
+    /// cut off.
+    #[non_exhaustive]
+    Partial {
+        value: T,
+        /// Why we intervened, in the order in which it happened. This is never empty.
+        reasons: Vec<InterventionReason>,
+        /// Where the input seemed to stop, or where we first had to intervene.
+        path: Path,
+        /// The sequences and maps that the input stopped inside of, outermost first,
+        /// and how many of their first elements are final. See
+        /// [`Outcome::n_final_elements`].
+        n_final_elements: Vec<(Path, usize)>,
+    },
+}
+
+impl<T> Outcome<T> {
+    pub(crate) fn new(
+        value: T,
+        reasons: Vec<InterventionReason>,
+        path: Path,
+        n_final_elements: Vec<(Path, usize)>,
+    ) -> Self {
+        if reasons.is_empty() {
+            Outcome::Complete(value)
+        } else {
+            Outcome::Partial {
+                value,
+                reasons,
+                path,
+                n_final_elements,
+            }
+        }
+    }
+
+    /// Whether the input looked complete and valid.
+    pub fn is_complete(&self) -> bool {
+        matches!(self, Outcome::Complete(_))
+    }
+
+    pub fn value(&self) -> &T {
+        match self {
+            Outcome::Complete(value) | Outcome::Partial { value, .. } => value,
+        }
+    }
+
+    pub fn into_value(self) -> T {
+        match self {
+            Outcome::Complete(value) | Outcome::Partial { value, .. } => value,
+        }
+    }
+
+    /// Why we intervened. Empty if the input looked complete.
+    pub fn reasons(&self) -> &[InterventionReason] {
+        match self {
+            Outcome::Complete(_) => &[],
+            Outcome::Partial { reasons, .. } => reasons,
+        }
+    }
+
+    /// Where the input seemed to stop, unless it looked complete.
+    ///
+    /// ```
+    /// let outcome = deser_incomplete::Options::new_json()
+    ///     .deserialize_from_json_str_outcome::<serde_json::Value>(
+    ///         r#"{"items": [{}, {"benefit": "chea"#.into(),
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(outcome.path().unwrap().to_string(), "/items/1/benefit");
+    /// ```
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            Outcome::Complete(_) => None,
+            Outcome::Partial { path, .. } => Some(path),
+        }
+    }
+
+    /// If the input stopped inside the sequence or map at `path`: how many of its first
+    /// elements are final, because a separator came after them. More input can only
+    /// change the elements after those.
+    ///
+    /// `None` if the input did not stop inside it. Then the whole sequence or map is
+    /// final, if it is there at all.
+    ///
+    /// ```
+    /// use deser_incomplete::{Options, Path};
+    ///
+    /// let outcome = Options::new_json()
+    ///     .deserialize_from_json_str_outcome::<Vec<Vec<u32>>>("[[1], [2, 3], [4, 5".into())
+    ///     .unwrap();
+    /// assert_eq!(outcome.value(), &[vec![1], vec![2, 3], vec![4, 5]]);
+    /// assert_eq!(outcome.n_final_elements(&Path::default()), Some(2));
+    /// // `5` may still become `50`.
+    /// assert_eq!(outcome.n_final_elements(outcome.path().unwrap()), None);
+    /// ```
+    pub fn n_final_elements(&self, path: &Path) -> Option<usize> {
+        match self {
+            Outcome::Complete(_) => None,
+            Outcome::Partial {
+                n_final_elements, ..
+            } => n_final_elements
+                .iter()
+                .find(|(collection, _)| collection == path)
+                .map(|(_, n)| *n),
+        }
+    }
+
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Outcome<U> {
+        match self {
+            Outcome::Complete(value) => Outcome::Complete(f(value)),
+            Outcome::Partial {
+                value,
+                reasons,
+                path,
+                n_final_elements,
+            } => Outcome::Partial {
+                value: f(value),
+                reasons,
+                path,
+                n_final_elements,
+            },
+        }
+    }
+}
+
+#[cfg(all(test, feature = "rand", feature = "serde_json"))]
+mod test {
+    fn path_of(json: &str) -> Option<String> {
+        crate::Options::new_json()
+            .deserialize_from_json_str_outcome::<serde_json::Value>(json.into())
+            .unwrap()
+            .path()
+            .map(ToString::to_string)
+    }
+
+    #[test]
+    fn test_path() {
+        assert_eq!(path_of(r#"{"a": [1, {"b": 2}]}"#), None);
+        assert_eq!(path_of(r#"{"a": [1, {"b": 2}"#), Some("/a/2".into()));
+        assert_eq!(path_of(r#"{"a": [1, {"b": "xy"#), Some("/a/1/b".into()));
+        assert_eq!(path_of(r#"{"a": [1, {"b"#), Some("/a/1".into()));
+        assert_eq!(path_of(r#"{"a/b": ["#), Some("/a~1b/0".into()));
+        assert_eq!(path_of(r#""xy"#), Some("".into()));
+    }
+
+    #[test]
+    fn test_path_with_other_keys() {
+        use std::collections::BTreeMap;
+
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, serde::Deserialize)]
+        enum Key {
+            A,
+        }
+
+        fn path_of<T: serde::de::DeserializeOwned>(json: &str) -> Option<String> {
+            crate::Options::new_json()
+                .deserialize_from_json_str_outcome::<T>(json.into())
+                .unwrap()
+                .path()
+                .map(ToString::to_string)
+        }
+
+        type Values = Vec<String>;
+        assert_eq!(
+            path_of::<BTreeMap<bool, Values>>(r#"{"true": ["#),
+            Some("/true/0".into())
+        );
+        assert_eq!(
+            path_of::<BTreeMap<char, Values>>(r#"{"x": ["#),
+            Some("/x/0".into())
+        );
+        assert_eq!(
+            path_of::<BTreeMap<i8, Values>>(r#"{"-3": ["#),
+            Some("/-3/0".into())
+        );
+        assert_eq!(
+            path_of::<BTreeMap<Key, Values>>(r#"{"A": ["#),
+            Some("/A/0".into())
+        );
+    }
+
+    fn n_final_elements_of(json: &str) -> Vec<(String, usize)> {
+        let outcome = crate::Options::new_json()
+            .deserialize_from_json_str_outcome::<serde_json::Value>(json.into())
+            .unwrap();
+        let crate::Outcome::Partial {
+            n_final_elements, ..
+        } = outcome
+        else {
+            return Vec::new();
+        };
+        n_final_elements
+            .into_iter()
+            .map(|(path, n)| (path.to_string(), n))
+            .collect()
+    }
+
+    #[test]
+    fn test_n_final_elements() {
+        let cases: &[(&str, &[(&str, usize)])] = &[
+            (r#"[{"a":1},{"a":2},{"a":"#, &[("", 2), ("/2", 0)]),
+            (r#"[{"a":1},{"a":2},{"#, &[("", 2), ("/2", 0)]),
+            (r#"[{"a":1},{"a":2}"#, &[("", 1)]),
+            (r#"[{"a":1},{"a":2},"#, &[("", 2)]),
+            (r#"[1, 2"#, &[("", 1)]),
+            (r#"[1, 2,"#, &[("", 2)]),
+            (r#"[1, [2, 3], "ab"#, &[("", 2)]),
+            (r#"{"a": [1, 2], "b": {"c": 3, "d"#, &[("", 1), ("/b", 1)]),
+            (r#"["#, &[("", 0)]),
+            (r#"[1, 2]"#, &[]),
+        ];
+        for (json, expected) in cases {
+            let expected: Vec<(String, usize)> = expected
+                .iter()
+                .map(|(path, n)| (path.to_string(), *n))
+                .collect();
+            assert_eq!(n_final_elements_of(json), expected, "{json}");
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use std::fmt::{self, Display};
+
+/// Where in the data we were, as a list of map keys and sequence indices.
+///
+/// This displays as a [JSON Pointer](https://www.rfc-editor.org/rfc/rfc6901), such as
+/// `/items/3/benefit`. The root displays as the empty string.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Path(pub(crate) Vec<PathSegment>);
+
+/// One step in a [`Path`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PathSegment {
+    /// An element of a sequence, tuple, or tuple struct.
+    Index(usize),
+    /// The value for this key in a map or struct.
+    Key(String),
+}
+
+impl Path {
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.0
+    }
+
+    pub fn is_root(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Display for Path {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for segment in &self.0 {
+            write!(f, "/{segment}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Escapes `~` and `/` like JSON Pointer does.
+impl Display for PathSegment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathSegment::Index(index) => write!(f, "{index}"),
+            PathSegment::Key(key) => {
+                for c in key.chars() {
+                    match c {
+                        '~' => f.write_str("~0")?,
+                        '/' => f.write_str("~1")?,
+                        c => write!(f, "{c}")?,
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Path::default().to_string(), "");
+        let path = Path(vec![
+            PathSegment::Key("items".into()),
+            PathSegment::Index(3),
+            PathSegment::Key("a/b~c".into()),
+        ]);
+        assert_eq!(path.to_string(), "/items/3/a~1b~0c");
+    }
+}
@@ -32,10 +32,12 @@ pub struct JsonRandomTrailer;
 
 impl RandomTrailer for JsonRandomTrailer {
     fn prepare_string_with_tag(&self, input: &mut String, tag: &str) {
+        input.truncate(input.len() - unusable_suffix_len(input.as_bytes()));
         write!(input, "{}", TagSuffix { tag }).expect("writing to a string always succeeds")
     }
 
     fn prepare_vec_with_tag(&self, input: &mut Vec<u8>, tag: &str) {
+        input.truncate(input.len() - unusable_suffix_len(input));
         write!(input, "{}", TagSuffix { tag }).expect("writing to a vec always succeeds")
     }
 
@@ -52,6 +54,98 @@ impl RandomTrailer for JsonRandomTrailer {
             false
         }
     }
+
+    fn trailing_number_len(&self, input: &[u8]) -> usize {
+        if escapes_in_last_string(input).is_some() {
+            return 0;
+        }
+        let len = input
+            .iter()
+            .rev()
+            .take_while(|byte| matches!(byte, b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E'))
+            .count();
+        // Otherwise, this is the end of a literal like `true`.
+        match input[input.len() - len..].first() {
+            Some(b'0'..=b'9' | b'-') => len,
+            _ => 0,
+        }
+    }
+}
+
+/// How many bytes at the end of the input [`serde_json`] would reject, even with the
+/// trailer after them. We leave these out, and keep what comes before.
+fn unusable_suffix_len(input: &[u8]) -> usize {
+    incomplete_number_suffix_len(input) + incomplete_escape_len(input)
+}
+
+/// How many bytes at the end of the input stop a number where it cannot stop, such
+/// as `.` in `1.`, `e-` in `1e-`, or a lone `-`.
+fn incomplete_number_suffix_len(input: &[u8]) -> usize {
+    let number = &input[input.len() - JsonRandomTrailer.trailing_number_len(input)..];
+    number
+        .iter()
+        .rev()
+        .take_while(|byte| matches!(byte, b'-' | b'+' | b'.' | b'e' | b'E'))
+        .count()
+}
+
+/// How many bytes at the end of a string are a `\uXXXX` escape that was cut off, or
+/// the first half of a surrogate pair, such as `\ud83d` in `"\ud83d\ude`.
+///
+/// A lone `\` is fine, because the `t` of the trailer completes it.
+fn incomplete_escape_len(input: &[u8]) -> usize {
+    let Some(escapes) = escapes_in_last_string(input) else {
+        return 0;
+    };
+    let mut escapes = escapes.into_iter().rev();
+    let Some(last) = escapes.next() else {
+        return 0;
+    };
+
+    // We leave out everything from `start`, and a high surrogate must end at `end`.
+    let (mut start, end) = match &input[last..] {
+        b"\\" => (input.len(), last),
+        [b'\\', b'u', hex @ ..] if hex.len() < 4 => (last, last),
+        _ => (input.len(), input.len()),
+    };
+    let high_surrogate = if end == last {
+        escapes.next()
+    } else {
+        Some(last)
+    };
+    if let Some(escape) = high_surrogate {
+        if escape + 6 == end && is_high_surrogate(&input[escape..end]) {
+            start = escape;
+        }
+    }
+    input.len() - start
+}
+
+/// Whether this complete `\uXXXX` escape is the first half of a surrogate pair.
+fn is_high_surrogate(escape: &[u8]) -> bool {
+    std::str::from_utf8(&escape[2..])
+        .ok()
+        .and_then(|hex| u16::from_str_radix(hex, 16).ok())
+        .is_some_and(|code_unit| (0xD800..=0xDBFF).contains(&code_unit))
+}
+
+/// If the input stops inside a string, then where the escapes in that string start.
+fn escapes_in_last_string(input: &[u8]) -> Option<Vec<usize>> {
+    let mut escapes: Option<Vec<usize>> = None;
+    let mut i = 0;
+    while i < input.len() {
+        match (input[i], &mut escapes) {
+            (b'\\', Some(escapes)) => {
+                escapes.push(i);
+                i += 1;
+            }
+            (b'"', Some(_)) => escapes = None,
+            (b'"', None) => escapes = Some(Vec::new()),
+            _ => {}
+        }
+        i += 1;
+    }
+    escapes
 }
 
 #[cfg(test)]
@@ -126,4 +220,75 @@ mod test {
             )
         }
     }
+
+    #[test]
+    fn test_trailing_number_len() {
+        for (input, expected) in [
+            ("[12", 2),
+            ("[1, -3.5e+2", 7),
+            (r#"{"a": 0"#, 1),
+            ("12", 2),
+            ("[12,", 0),
+            ("[12 ", 0),
+            ("[true", 0),
+            (r#"["12"#, 0),
+            (r#"["a\"12"#, 0),
+            (r#"["a\\", 12"#, 2),
+            ("", 0),
+        ] {
+            assert_eq!(
+                JsonRandomTrailer.trailing_number_len(input.as_bytes()),
+                expected,
+                "input = {input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_incomplete_number() {
+        for (input, expected) in [
+            ("[1.", "[1"),
+            ("[1e", "[1"),
+            ("[-1.5E-", "[-1.5"),
+            ("[2, -", "[2, "),
+            ("[1.5", "[1.5"),
+            (r#"["1."#, r#"["1."#),
+        ] {
+            let mut prepared = input.to_string();
+            JsonRandomTrailer.prepare_string_with_tag(&mut prepared, TAG);
+            assert_eq!(prepared, format!("{expected}t{TAG}\""), "input = {input:?}");
+        }
+    }
+
+    #[test]
+    fn test_incomplete_escape() {
+        for (input, expected) in [
+            (r#""caf\u00"#, r#""caf"#),
+            (r#""caf\u"#, r#""caf"#),
+            (r#""caf\u00e9"#, r#""caf\u00e9"#),
+            (r#""a\ud83d"#, r#""a"#),
+            (r#""a\ud83d\"#, r#""a"#),
+            (r#""a\ud83d\ude0"#, r#""a"#),
+            (r#""a\ud83d\ude00"#, r#""a\ud83d\ude00"#),
+            (r#""a\\u00"#, r#""a\\u00"#),
+            (r#""a\"#, r#""a\"#),
+            (r#"["\u00", 1"#, r#"["\u00", 1"#),
+        ] {
+            let mut prepared = input.to_string();
+            JsonRandomTrailer.prepare_string_with_tag(&mut prepared, TAG);
+            assert_eq!(prepared, format!("{expected}t{TAG}\""), "input = {input:?}");
+        }
+
+        let mut prepared = r#""caf\u00e9 \ud83d\ude00 \ud83d\ude"#.to_string();
+        JsonRandomTrailer.prepare_string_with_tag(&mut prepared, TAG);
+        assert_eq!(
+            parse_and_undo_tag(&prepared),
+            (
+                CowString::VisitStr {
+                    cloned: "café 😀 ".to_string()
+                },
+                true
+            )
+        );
+    }
 }
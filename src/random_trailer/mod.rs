@@ -2,6 +2,12 @@
 
 #[cfg(feature = "serde_json")]
 pub(crate) mod json;
+#[cfg(feature = "rmp-serde")]
+pub(crate) mod msgpack;
+#[cfg(feature = "ron")]
+pub(crate) mod ron;
+#[cfg(feature = "toml")]
+pub(crate) mod toml;
 #[cfg(feature = "serde_yaml")]
 pub(crate) mod yaml;
 
@@ -13,6 +19,15 @@ pub(crate) mod yaml;
 /// - For YAML, we need this otherwise any incomplete string seems to
 ///   cause deserialization to fail.
 ///
+/// - For RON, this works like for JSON, but we also close raw strings and
+///   block comments.
+///
+/// - For TOML, the whole document must parse, so we close any open strings,
+///   arrays and inline tables.
+///
+/// - For MessagePack, strings are length-prefixed, so we encode a string that was
+///   cut off again, with the tag and a length that fits.
+///
 /// However, such a trailer then needs to be removed from any decoded
 /// strings.
 pub trait RandomTrailer {
@@ -36,6 +51,22 @@ pub trait RandomTrailer {
     /// This method will not be called when parsing was done without a random tag.
     #[must_use]
     fn remove_trailer(&self, string_like: &mut impl StringLike, random_tag: &str) -> bool;
+
+    /// Whether this parsed string or bytes is a placeholder from the trailer, which
+    /// stands in for a value that was not present in the input.
+    ///
+    /// We treat a placeholder as if the data format had stopped right there.
+    fn is_placeholder(&self, _string_like: &impl StringLike, _random_tag: &str) -> bool {
+        false
+    }
+
+    /// How many bytes at the end of the input are a number that may continue after
+    /// the input, such as `12` in `[12`.
+    ///
+    /// This is used for [`crate::Options::withhold_trailing_numbers`].
+    fn trailing_number_len(&self, _input: &[u8]) -> usize {
+        0
+    }
 }
 
 #[derive(Clone, Debug, Default)]
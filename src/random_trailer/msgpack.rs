@@ -0,0 +1,237 @@
+use super::{RandomTrailer, StringLike};
+
+/// MessagePack prefixes strings and binary data with their length, so [`rmp_serde`]
+/// fails on a string that was cut off, and we would lose it completely.
+///
+/// So if the input ends inside a str or bin, then we encode it again: with the bytes
+/// that we have, followed by the random tag, and with a length that matches. If
+/// [`rmp_serde`] then visits us with a string `Hello, woBERLIN`, only `Hello, wo` was
+/// part of the input.
+///
+/// If the input ends anywhere else, we leave it alone.
+#[derive(Clone, Debug, Default)]
+pub struct MsgPackRandomTrailer;
+
+impl RandomTrailer for MsgPackRandomTrailer {
+    fn prepare_string_with_tag(&self, _input: &mut String, _tag: &str) {
+        // MessagePack is binary: it is only valid UTF-8 if it contains no strings,
+        // maps or arrays at all. So there is nothing to do here.
+    }
+
+    fn prepare_vec_with_tag(&self, input: &mut Vec<u8>, tag: &str) {
+        let Some(Truncated {
+            start,
+            kind,
+            payload_start,
+        }) = scan(input)
+        else {
+            return;
+        };
+
+        let mut payload = input.split_off(payload_start.min(input.len()));
+        input.truncate(start);
+
+        if kind == Kind::Str {
+            // An incomplete UTF-8 sequence would make the whole string invalid.
+            let valid_len = match std::str::from_utf8(&payload) {
+                Ok(_) => payload.len(),
+                Err(err) => err.valid_up_to(),
+            };
+            payload.truncate(valid_len);
+        }
+
+        write_header(input, kind, payload.len() + tag.len());
+        input.extend_from_slice(&payload);
+        input.extend_from_slice(tag.as_bytes());
+    }
+
+    fn remove_trailer(&self, string_like: &mut impl StringLike, tag: &str) -> bool {
+        if string_like.ends_with_string(tag) {
+            let target_len = string_like.len() - tag.len();
+            string_like.truncate_to_bytes(target_len);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Kind {
+    Str,
+    Bin,
+    Other,
+}
+
+/// A str or bin at the end of the input, which was cut off in its header or payload.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Truncated {
+    /// Where its header starts.
+    start: usize,
+    kind: Kind,
+    /// Where its payload starts, or would have started. May be past the end of the input.
+    payload_start: usize,
+}
+
+/// Walk over the values in the input, to find out whether it ends inside a str or bin.
+///
+/// Arrays and maps are only a header followed by their elements, so we don't need to
+/// keep track of nesting.
+fn scan(input: &[u8]) -> Option<Truncated> {
+    let mut pos = 0;
+    while pos < input.len() {
+        let (kind, n_header_bytes, payload_len) = match input[pos] {
+            // positive fixint, fixmap, fixarray
+            0x00..=0x9f => (Kind::Other, 1, Some(0)),
+            marker @ 0xa0..=0xbf => (Kind::Str, 1, Some(usize::from(marker & 0x1f))),
+            // nil, never used, false, true
+            0xc0..=0xc3 => (Kind::Other, 1, Some(0)),
+            0xc4 => (Kind::Bin, 2, read_len(input, pos, 1)),
+            0xc5 => (Kind::Bin, 3, read_len(input, pos, 2)),
+            0xc6 => (Kind::Bin, 5, read_len(input, pos, 4)),
+            // ext 8/16/32: the length excludes the type byte
+            0xc7 => (Kind::Other, 3, read_len(input, pos, 1)),
+            0xc8 => (Kind::Other, 4, read_len(input, pos, 2)),
+            0xc9 => (Kind::Other, 6, read_len(input, pos, 4)),
+            // float 32, float 64
+            0xca => (Kind::Other, 5, Some(0)),
+            0xcb => (Kind::Other, 9, Some(0)),
+            // uint 8/16/32/64, int 8/16/32/64
+            marker @ 0xcc..=0xd3 => (Kind::Other, 1 + (1 << (marker & 0x03)), Some(0)),
+            // fixext 1/2/4/8/16
+            marker @ 0xd4..=0xd8 => (Kind::Other, 2 + (1 << (marker - 0xd4)), Some(0)),
+            0xd9 => (Kind::Str, 2, read_len(input, pos, 1)),
+            0xda => (Kind::Str, 3, read_len(input, pos, 2)),
+            0xdb => (Kind::Str, 5, read_len(input, pos, 4)),
+            // array 16/32, map 16/32
+            0xdc | 0xde => (Kind::Other, 3, Some(0)),
+            0xdd | 0xdf => (Kind::Other, 5, Some(0)),
+            // negative fixint
+            0xe0..=0xff => (Kind::Other, 1, Some(0)),
+        };
+
+        let end = payload_len.map(|payload_len| pos + n_header_bytes + payload_len);
+        match end {
+            Some(end) if end <= input.len() => pos = end,
+            _ if kind == Kind::Other => return None,
+            _ => {
+                return Some(Truncated {
+                    start: pos,
+                    kind,
+                    payload_start: pos + n_header_bytes,
+                })
+            }
+        }
+    }
+    None
+}
+
+/// Read a big-endian length of `n_bytes` after the marker at `pos`, if it is
+/// in the input.
+fn read_len(input: &[u8], pos: usize, n_bytes: usize) -> Option<usize> {
+    let bytes = input.get(pos + 1..pos + 1 + n_bytes)?;
+    Some(
+        bytes
+            .iter()
+            .fold(0, |len, &byte| (len << 8) | usize::from(byte)),
+    )
+}
+
+/// Write the smallest header for a str or bin of this length.
+fn write_header(output: &mut Vec<u8>, kind: Kind, len: usize) {
+    match (kind, len) {
+        (Kind::Str, 0..=0x1f) => output.push(0xa0 | len as u8),
+        (Kind::Str, 0x20..=0xff) => output.extend_from_slice(&[0xd9, len as u8]),
+        (Kind::Str, 0x100..=0xffff) => {
+            output.push(0xda);
+            output.extend_from_slice(&(len as u16).to_be_bytes());
+        }
+        (Kind::Str, _) => {
+            output.push(0xdb);
+            output.extend_from_slice(&(len as u32).to_be_bytes());
+        }
+        (_, 0..=0xff) => output.extend_from_slice(&[0xc4, len as u8]),
+        (_, 0x100..=0xffff) => {
+            output.push(0xc5);
+            output.extend_from_slice(&(len as u16).to_be_bytes());
+        }
+        (_, _) => {
+            output.push(0xc6);
+            output.extend_from_slice(&(len as u32).to_be_bytes());
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TAG: &str = "BERLIN";
+
+    fn parse_prepared(input: &[u8]) -> (String, bool) {
+        let mut prepared = input.to_vec();
+        MsgPackRandomTrailer.prepare_vec_with_tag(&mut prepared, TAG);
+        let mut parsed: &str = rmp_serde::from_slice(&prepared).unwrap();
+        let had_tag = MsgPackRandomTrailer.remove_trailer(&mut parsed, TAG);
+        (parsed.to_string(), had_tag)
+    }
+
+    #[test]
+    fn test_tag_suffix() {
+        let long = "x".repeat(40);
+        for (full, n_bytes, will_encounter_end, expected_result) in [
+            ("Hello, world", 10, true, "Hello, wo"),
+            ("Hello, world", 13, false, "Hello, world"),
+            // Cut off in the middle of `é`.
+            ("café", 5, true, "caf"),
+            // Only the marker.
+            ("Hello, world", 1, true, ""),
+            // The length of a str 8 was cut off.
+            (&long, 1, true, ""),
+            // With the tag, it no longer fits in a fixstr.
+            (
+                "Hello, world, and welcome to B",
+                30,
+                true,
+                "Hello, world, and welcome to ",
+            ),
+        ] {
+            let encoded = rmp_serde::to_vec(full).unwrap();
+            let (result, encountered_end) = parse_prepared(&encoded[..n_bytes]);
+            assert_eq!(
+                result, expected_result,
+                "full = {full:?}, n_bytes = {n_bytes}"
+            );
+            assert_eq!(
+                encountered_end, will_encounter_end,
+                "full = {full:?}, n_bytes = {n_bytes}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_scan() {
+        // [1, "ab", c4 02 "xy"] with the bin cut off
+        let input = [0x93, 0x01, 0xa2, b'a', b'b', 0xc4, 0x02, b'x'];
+        assert_eq!(
+            scan(&input),
+            Some(Truncated {
+                start: 5,
+                kind: Kind::Bin,
+                payload_start: 7
+            })
+        );
+        assert_eq!(scan(&input[..5]), None);
+        // A uint 32 that was cut off.
+        assert_eq!(scan(&[0x91, 0xce, 0x00]), None);
+        // A str 16 with only half of its length.
+        assert_eq!(
+            scan(&[0x91, 0xda, 0x00]),
+            Some(Truncated {
+                start: 1,
+                kind: Kind::Str,
+                payload_start: 4
+            })
+        );
+    }
+}
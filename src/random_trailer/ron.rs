@@ -0,0 +1,315 @@
+use std::fmt::Write as _;
+
+use super::{RandomTrailer, StringLike};
+
+/// Like the trailer for JSON, we append `t`, a random tag, and a double-quote. So if
+/// [`ron`] visits us with a string `Hello, wotBERLIN`, then only `Hello, wo` was part
+/// of the input.
+///
+/// This also works for identifiers: a struct field or enum variant `VariatBERLIN` was
+/// only `Varia` in the input.
+///
+/// [`ron`] has a few more syntaxes where this isn't enough, so we first look at how
+/// the input ends:
+///
+///   - A raw string like `r##"abc` needs `"##` to close it.
+///   - In a block comment, the trailer would be ignored. Worse, [`ron`] fails
+///     on an unclosed block comment before it even starts deserializing. So we close
+///     the comment instead.
+///   - [`ron`] only gives us a newtype or tuple variant like `Tuple("a", "b")` once it
+///     has seen the closing parenthesis. So if the input stops directly inside one,
+///     in a string or after a complete string, bracket or comma, then we close the
+///     parenthesis too. A tuple variant with too few elements still fails, as it
+///     should.
+#[derive(Clone, Debug, Default)]
+pub struct RonRandomTrailer;
+
+impl RandomTrailer for RonRandomTrailer {
+    fn prepare_string_with_tag(&self, input: &mut String, tag: &str) {
+        let suffix = suffix_for(scan(input.as_bytes()), tag);
+        input.push_str(&suffix);
+    }
+
+    fn prepare_vec_with_tag(&self, input: &mut Vec<u8>, tag: &str) {
+        let suffix = suffix_for(scan(input), tag);
+        input.extend_from_slice(suffix.as_bytes());
+    }
+
+    fn remove_trailer(&self, string_like: &mut impl StringLike, tag: &str) -> bool {
+        if string_like.ends_with_string(tag) {
+            let target_len = string_like.len()
+            - tag.len()
+            // `"t"`, `"\t"` and the `t` in a raw string are all 1 byte
+            - 1;
+
+            string_like.truncate_to_bytes(target_len);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum End {
+    Normal,
+    String,
+    RawString { n_hashes: usize },
+    BlockComment { depth: usize },
+}
+
+/// How the input ends, as far as the trailer cares.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Scanned {
+    end: End,
+    /// Whether the innermost bracket is the parenthesis of something like a newtype or
+    /// tuple variant, such as `Tuple(1, `, and not of a struct like `Struct(a: `.
+    in_tuple_variant: bool,
+    /// Whether the input ends in a string, char or bracket that is complete, or in a
+    /// comma after one.
+    after_value: bool,
+}
+
+/// A bracket that is still open.
+#[derive(Clone, Copy, Debug)]
+struct Open {
+    /// Whether this is a parenthesis directly after an identifier.
+    is_named_paren: bool,
+    /// Whether a `:` came directly inside, as in a struct or map.
+    has_colon: bool,
+}
+
+fn suffix_for(scanned: Scanned, tag: &str) -> String {
+    let mut suffix = String::new();
+    if scanned.in_tuple_variant && scanned.after_value {
+        suffix.push(')');
+        return suffix;
+    }
+    match scanned.end {
+        End::Normal | End::String => {
+            write!(suffix, r#"t{tag}""#).expect("writing to a string always succeeds")
+        }
+        End::RawString { n_hashes } => write!(suffix, r#"t{tag}"{}"#, "#".repeat(n_hashes))
+            .expect("writing to a string always succeeds"),
+        End::BlockComment { depth } => suffix.push_str(&"*/".repeat(depth)),
+    }
+    if scanned.in_tuple_variant && matches!(scanned.end, End::String | End::RawString { .. }) {
+        suffix.push(')');
+    }
+    suffix
+}
+
+/// Find out whether the input ends in a string or a comment, and in which brackets.
+fn scan(input: &[u8]) -> Scanned {
+    let mut end = End::Normal;
+    let mut open: Vec<Open> = Vec::new();
+    let mut i = 0;
+    while i < input.len() {
+        let c = input[i];
+        match end {
+            End::Normal => match c {
+                b'"' => end = End::String,
+                b'(' | b'[' | b'{' => {
+                    let before = input[..i].iter().rev().find(|c| !c.is_ascii_whitespace());
+                    open.push(Open {
+                        is_named_paren: c == b'(' && before.is_some_and(|&c| is_ident_char(c)),
+                        has_colon: false,
+                    });
+                }
+                b')' | b']' | b'}' => {
+                    open.pop();
+                }
+                b':' => {
+                    if let Some(open) = open.last_mut() {
+                        open.has_colon = true;
+                    }
+                }
+                b'r' if i == 0 || !is_ident_char(input[i - 1]) => {
+                    let n_hashes = input[i + 1..].iter().take_while(|&&c| c == b'#').count();
+                    if input.get(i + 1 + n_hashes) == Some(&b'"') {
+                        end = End::RawString { n_hashes };
+                        i += n_hashes + 1;
+                    }
+                }
+                b'\'' => {
+                    // A char literal. Skip it up to the closing quote, so that `'"'`
+                    // does not start a string. Its body may be several bytes long,
+                    // like `'é'` or `'\u{1F600}'`.
+                    i += 1;
+                    while i < input.len() && input[i] != b'\'' {
+                        if input[i] == b'\\' {
+                            i += 1;
+                        }
+                        i += 1;
+                    }
+                }
+                b'/' if input.get(i + 1) == Some(&b'/') => {
+                    match input[i..].iter().position(|&c| c == b'\n') {
+                        Some(newline) => i += newline,
+                        // The trailer will be part of the comment. That's fine: it
+                        // was never going to be in a string anyway.
+                        None => {
+                            end = End::Normal;
+                            break;
+                        }
+                    }
+                }
+                b'/' if input.get(i + 1) == Some(&b'*') => {
+                    end = End::BlockComment { depth: 1 };
+                    i += 1;
+                }
+                _ => {}
+            },
+            End::String => match c {
+                b'\\' => i += 1,
+                b'"' => end = End::Normal,
+                _ => {}
+            },
+            End::RawString { n_hashes } => {
+                if c == b'"'
+                    && input[i + 1..].len() >= n_hashes
+                    && input[i + 1..i + 1 + n_hashes].iter().all(|&c| c == b'#')
+                {
+                    end = End::Normal;
+                    i += n_hashes;
+                }
+            }
+            End::BlockComment { depth } => {
+                if input[i..].starts_with(b"/*") {
+                    end = End::BlockComment { depth: depth + 1 };
+                    i += 1;
+                } else if input[i..].starts_with(b"*/") {
+                    end = if depth == 1 {
+                        End::Normal
+                    } else {
+                        End::BlockComment { depth: depth - 1 }
+                    };
+                    i += 1;
+                }
+            }
+        }
+        i += 1;
+    }
+    Scanned {
+        end,
+        in_tuple_variant: open
+            .last()
+            .is_some_and(|open| open.is_named_paren && !open.has_colon),
+        after_value: end == End::Normal
+            && matches!(
+                input.iter().rev().find(|c| !c.is_ascii_whitespace()),
+                Some(b'"' | b'\'' | b')' | b']' | b'}' | b',')
+            ),
+    }
+}
+
+fn is_ident_char(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || c == b'_'
+}
+
+#[cfg(test)]
+mod test {
+    use serde::Deserialize;
+
+    use super::*;
+
+    const TAG: &str = "BERLIN";
+
+    fn parse_prepared(input: &str) -> (String, bool) {
+        let mut prepared = input.to_string();
+        RonRandomTrailer.prepare_string_with_tag(&mut prepared, TAG);
+        let mut parsed =
+            String::deserialize(&mut ron::Deserializer::from_str(&prepared).unwrap()).unwrap();
+        let had_tag = RonRandomTrailer.remove_trailer(&mut parsed, TAG);
+        (parsed, had_tag)
+    }
+
+    #[test]
+    fn test_tag_suffix() {
+        for (input, will_encounter_end, expected_result) in [
+            (r#""Hello, wo"#, true, "Hello, wo"),
+            // Unfinished backslash escape -> we can't know what it'll eventually represent.
+            (r#""Hello, wo\"#, true, "Hello, wo"),
+            (r#""Hello, world""#, false, "Hello, world"),
+            (r##"r#"Hello, "wo"##, true, r#"Hello, "wo"#),
+            (r###"r##"Hello, "#wo"###, true, r##"Hello, "#wo"##),
+            (r#"r"Hello, \"#, true, r"Hello, \"),
+            (r##"r#"Hello, world"#"##, false, "Hello, world"),
+            ("/* a /* nested */ comment */ \"Hello", true, "Hello"),
+        ] {
+            let (result, encountered_end) = parse_prepared(input);
+            assert_eq!(result, expected_result, "input = {input:?}");
+            assert_eq!(encountered_end, will_encounter_end, "input = {input:?}");
+        }
+    }
+
+    #[test]
+    fn test_scan() {
+        assert_eq!(scan(b"'\"' \"ab").end, End::String);
+        assert_eq!(scan(b"bar\"ab\" r#x").end, End::Normal);
+        assert_eq!(scan(b"/* a /* b */").end, End::BlockComment { depth: 1 });
+        assert_eq!(scan(b"// \"a\n r#\"a").end, End::RawString { n_hashes: 1 });
+        assert_eq!(scan("'é' \"ab".as_bytes()).end, End::String);
+        assert_eq!(
+            scan(b"'\\u{1F600}' r#\"a").end,
+            End::RawString { n_hashes: 1 }
+        );
+        assert_eq!(scan(b"'\\'' \"ab").end, End::String);
+        assert_eq!(scan(b"['a', '\\\\', \"ab").end, End::String);
+        assert_eq!(scan(b"['\"").end, End::Normal);
+    }
+
+    #[test]
+    fn test_scan_tuple_variant() {
+        assert!(scan(b"[Unit, Newtype(\"a").in_tuple_variant);
+        assert!(scan(b"Tuple (1, \"a").in_tuple_variant);
+        assert!(scan(b"Outer(Struct(a: 1), \"a").in_tuple_variant);
+        assert!(!scan(b"Struct(a: \"a").in_tuple_variant);
+        assert!(!scan(b"(1, \"a").in_tuple_variant);
+        assert!(!scan(b"[Newtype(\"a\"), \"b").in_tuple_variant);
+        assert!(!scan(b"{\"a\": \"b").in_tuple_variant);
+
+        assert_eq!(suffix_for(scan(b"Newtype(\"a"), TAG), format!("t{TAG}\")"));
+        assert_eq!(suffix_for(scan(b"Newtype(\"a\" "), TAG), ")");
+        assert_eq!(suffix_for(scan(b"Newtype([1]"), TAG), ")");
+        assert_eq!(suffix_for(scan(b"Tuple(\"a\","), TAG), ")");
+        assert_eq!(suffix_for(scan(b"Newtype(1"), TAG), format!("t{TAG}\""));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_header() {
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Struct {
+            a: Option<u32>,
+        }
+
+        assert_eq!(
+            crate::from_ron_str::<Struct>("#![enable(implicit_some)]\n(a: 1)").unwrap(),
+            Struct { a: Some(1) }
+        );
+        for input in [
+            "#![enab",
+            "#![enable(implicit_so",
+            "#![enable(implicit_some)",
+            "/* a",
+        ] {
+            assert_eq!(
+                crate::from_ron_str::<Vec<u32>>(input).unwrap(),
+                Vec::<u32>::new(),
+                "input = {input:?}"
+            );
+        }
+        for input in [
+            "#![enable(bogus)]",
+            "#![enable(bogus)]\n[1, 2]",
+            "#![enable(a b)]\n[1]",
+        ] {
+            let err = crate::from_ron_str::<Vec<u32>>(input).unwrap_err();
+            assert!(
+                err.as_deserializer_error().is_some(),
+                "input = {input:?}, err = {err}"
+            );
+        }
+    }
+}
@@ -0,0 +1,603 @@
+use serde::Deserialize as _;
+
+use super::{RandomTrailer, StringLike};
+
+/// [`toml`] parses the whole document before it deserializes anything, so unlike
+/// with JSON, we cannot let the data format stumble over the end of the input.
+/// Instead, we look at how the input ends, and append whatever makes it valid TOML
+/// again:
+///
+///   - An open string is closed with `t` + the random tag + the closing quotes. So
+///     if we see a string `Hello, wotBERLIN`, then only `Hello, wo` was part of the
+///     input. A key is extended in the same way, or gets an extra dotted part
+///     `.tBERLIN` if it may be complete.
+///   - Where a value should go, we put a placeholder: the random tag as a string.
+///     We treat the placeholder as if there was no value at all.
+///   - Open arrays and inline tables get a placeholder element, and are then closed.
+///     The placeholder makes sure that we notice the input was cut off.
+///
+/// To keep the result valid, we may also cut off an unfinished escape sequence or
+/// an unfinished bare value such as `tr` or `1.`.
+#[derive(Clone, Debug, Default)]
+pub struct TomlRandomTrailer;
+
+impl RandomTrailer for TomlRandomTrailer {
+    fn prepare_string_with_tag(&self, input: &mut String, tag: &str) {
+        let (truncate_to, suffix) = Scanner::scan(input.as_bytes()).trailer(input.as_bytes(), tag);
+        input.truncate(truncate_to);
+        input.push_str(&suffix);
+    }
+
+    fn prepare_vec_with_tag(&self, input: &mut Vec<u8>, tag: &str) {
+        let (truncate_to, suffix) = Scanner::scan(input).trailer(input, tag);
+        input.truncate(truncate_to);
+        input.extend_from_slice(suffix.as_bytes());
+    }
+
+    fn remove_trailer(&self, string_like: &mut impl StringLike, tag: &str) -> bool {
+        if string_like.ends_with_2_strings(CONTINUATION, tag) {
+            let target_len = string_like.len() - tag.len() - CONTINUATION.len();
+
+            string_like.truncate_to_bytes(target_len);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn is_placeholder(&self, string_like: &impl StringLike, tag: &str) -> bool {
+        string_like.len() == tag.len() && string_like.ends_with_string(tag)
+    }
+}
+
+/// Comes before the random tag when we close a string or key.
+const CONTINUATION: &str = "t";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Bracket {
+    Array,
+    InlineTable,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Header {
+    Table,
+    ArrayOfTables,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum StringKind {
+    Basic,
+    Literal,
+    MultiLineBasic,
+    MultiLineLiteral,
+}
+
+impl StringKind {
+    fn quote(self) -> u8 {
+        match self {
+            StringKind::Basic | StringKind::MultiLineBasic => b'"',
+            StringKind::Literal | StringKind::MultiLineLiteral => b'\'',
+        }
+    }
+
+    fn is_multi_line(self) -> bool {
+        matches!(
+            self,
+            StringKind::MultiLineBasic | StringKind::MultiLineLiteral
+        )
+    }
+
+    fn has_escapes(self) -> bool {
+        matches!(self, StringKind::Basic | StringKind::MultiLineBasic)
+    }
+
+    fn closing(self) -> &'static str {
+        match self {
+            StringKind::Basic => "\"",
+            StringKind::Literal => "'",
+            StringKind::MultiLineBasic => "\"\"\"",
+            StringKind::MultiLineLiteral => "'''",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum State {
+    /// At the top level, at the start of a line.
+    LineStart,
+    /// Expecting a key, or the next part of a dotted key.
+    KeyStart,
+    BareKey,
+    /// After a (part of a) key, expecting `.`, `=`, or the end of a table header.
+    AfterKey,
+    ExpectValue,
+    /// Inside a value that is not a string, array or inline table.
+    BareValue {
+        start: usize,
+    },
+    AfterValue,
+    /// After the first `]` of `]]`.
+    HeaderClosing,
+    String {
+        kind: StringKind,
+        is_key: bool,
+        /// Where the escape sequence at the end of the input starts, if it is unfinished.
+        unfinished_escape: Option<usize>,
+    },
+    Comment {
+        /// Whether we were inside an array, expecting a value.
+        expect_value: bool,
+    },
+    /// We don't understand the input. We won't add a trailer.
+    Broken,
+}
+
+/// Finds out how a TOML input ends, with a simple scan.
+///
+/// This does not validate the input, except where that is necessary to know
+/// where we are.
+struct Scanner {
+    stack: Vec<Bracket>,
+    header: Option<Header>,
+    state: State,
+}
+
+impl Scanner {
+    fn scan(input: &[u8]) -> Self {
+        let mut scanner = Scanner {
+            stack: Vec::new(),
+            header: None,
+            state: State::LineStart,
+        };
+
+        let mut i = 0;
+        while i < input.len() {
+            i = scanner.step(input, i);
+        }
+        scanner
+    }
+
+    fn in_array(&self) -> bool {
+        self.stack.last() == Some(&Bracket::Array)
+    }
+
+    fn in_inline_table(&self) -> bool {
+        self.stack.last() == Some(&Bracket::InlineTable)
+    }
+
+    /// Process the input at position `i`, and return where to continue.
+    fn step(&mut self, input: &[u8], i: usize) -> usize {
+        let c = input[i];
+        match self.state {
+            State::Broken => return input.len(),
+            State::String {
+                kind,
+                is_key,
+                unfinished_escape: _,
+            } => return self.step_string(input, i, kind, is_key),
+            State::Comment { expect_value } => {
+                if c == b'\n' {
+                    self.state = if self.stack.is_empty() {
+                        State::LineStart
+                    } else if expect_value {
+                        State::ExpectValue
+                    } else {
+                        State::AfterValue
+                    };
+                }
+            }
+            State::LineStart => match c {
+                b' ' | b'\t' | b'\r' | b'\n' => {}
+                b'#' => {
+                    self.state = State::Comment {
+                        expect_value: false,
+                    }
+                }
+                b'[' => {
+                    self.state = State::KeyStart;
+                    if input.get(i + 1) == Some(&b'[') {
+                        self.header = Some(Header::ArrayOfTables);
+                        return i + 2;
+                    }
+                    self.header = Some(Header::Table);
+                }
+                _ => return self.start_key(input, i),
+            },
+            State::KeyStart => match c {
+                b' ' | b'\t' => {}
+                b'}' if self.in_inline_table() => {
+                    self.stack.pop();
+                    self.state = State::AfterValue;
+                }
+                _ => return self.start_key(input, i),
+            },
+            State::BareKey => match c {
+                c if is_bare_key_char(c) => {}
+                b' ' | b'\t' => self.state = State::AfterKey,
+                _ => {
+                    self.state = State::AfterKey;
+                    return i;
+                }
+            },
+            State::AfterKey => match c {
+                b' ' | b'\t' => {}
+                b'.' => self.state = State::KeyStart,
+                b'=' if self.header.is_none() => self.state = State::ExpectValue,
+                b']' if self.header == Some(Header::Table) => {
+                    self.header = None;
+                    self.state = State::AfterValue;
+                }
+                b']' if self.header == Some(Header::ArrayOfTables) => {
+                    self.state = State::HeaderClosing;
+                }
+                _ => self.state = State::Broken,
+            },
+            State::HeaderClosing => {
+                if c == b']' {
+                    self.header = None;
+                    self.state = State::AfterValue;
+                } else {
+                    self.state = State::Broken;
+                }
+            }
+            State::ExpectValue => match c {
+                b' ' | b'\t' => {}
+                b'\r' | b'\n' if self.in_array() => {}
+                b'#' if self.in_array() => self.state = State::Comment { expect_value: true },
+                b']' if self.in_array() => {
+                    self.stack.pop();
+                    self.state = State::AfterValue;
+                }
+                b'[' => self.stack.push(Bracket::Array),
+                b'{' => {
+                    self.stack.push(Bracket::InlineTable);
+                    self.state = State::KeyStart;
+                }
+                b'"' | b'\'' => return self.start_string(input, i, false),
+                b'\r' | b'\n' | b'#' | b',' | b']' | b'}' => self.state = State::Broken,
+                _ => self.state = State::BareValue { start: i },
+            },
+            State::BareValue { start: _ } => {
+                if matches!(c, b' ' | b'\t' | b'\r' | b'\n' | b',' | b']' | b'}' | b'#') {
+                    self.state = State::AfterValue;
+                    return i;
+                }
+            }
+            State::AfterValue => match c {
+                b' ' | b'\t' | b'\r' => {}
+                b'\n' if self.stack.is_empty() => self.state = State::LineStart,
+                b'\n' if self.in_array() => {}
+                b'#' if self.stack.is_empty() || self.in_array() => {
+                    self.state = State::Comment {
+                        expect_value: false,
+                    }
+                }
+                b',' if self.in_array() => self.state = State::ExpectValue,
+                b',' if self.in_inline_table() => self.state = State::KeyStart,
+                b']' if self.in_array() => {
+                    self.stack.pop();
+                }
+                b'}' if self.in_inline_table() => {
+                    self.stack.pop();
+                }
+                _ => self.state = State::Broken,
+            },
+        }
+        i + 1
+    }
+
+    fn start_key(&mut self, input: &[u8], i: usize) -> usize {
+        match input[i] {
+            b'"' | b'\'' => self.start_string(input, i, true),
+            c if is_bare_key_char(c) => {
+                self.state = State::BareKey;
+                i + 1
+            }
+            _ => {
+                self.state = State::Broken;
+                i + 1
+            }
+        }
+    }
+
+    fn start_string(&mut self, input: &[u8], i: usize, is_key: bool) -> usize {
+        let quote = input[i];
+        let n_quotes = count_run(input, i, quote);
+        let (kind, len) = match (quote, n_quotes) {
+            (b'"', 3..) if !is_key => (StringKind::MultiLineBasic, 3),
+            (b'\'', 3..) if !is_key => (StringKind::MultiLineLiteral, 3),
+            (_, 2..) => {
+                // An empty string.
+                self.state = if is_key {
+                    State::AfterKey
+                } else {
+                    State::AfterValue
+                };
+                return i + 2;
+            }
+            (b'"', _) => (StringKind::Basic, 1),
+            (_, _) => (StringKind::Literal, 1),
+        };
+
+        self.state = State::String {
+            kind,
+            is_key,
+            unfinished_escape: None,
+        };
+        i + len
+    }
+
+    fn step_string(&mut self, input: &[u8], i: usize, kind: StringKind, is_key: bool) -> usize {
+        let c = input[i];
+        if c == kind.quote() {
+            let n_quotes = count_run(input, i, c);
+            if !kind.is_multi_line() {
+                self.end_string(is_key);
+                return i + 1;
+            }
+            if n_quotes >= 3 {
+                // Up to two quotes may be part of the content.
+                self.end_string(is_key);
+                return i + n_quotes.min(5);
+            }
+            return i + n_quotes;
+        }
+
+        if c == b'\n' && !kind.is_multi_line() {
+            self.state = State::Broken;
+            return i + 1;
+        }
+
+        if c == b'\\' && kind.has_escapes() {
+            match escape_len(input, i, kind.is_multi_line()) {
+                Some(len) => return i + len,
+                None => {
+                    self.state = State::String {
+                        kind,
+                        is_key,
+                        unfinished_escape: Some(i),
+                    };
+                    return input.len();
+                }
+            }
+        }
+
+        i + 1
+    }
+
+    fn end_string(&mut self, is_key: bool) {
+        self.state = if is_key {
+            State::AfterKey
+        } else {
+            State::AfterValue
+        };
+    }
+
+    /// Return the length to truncate the input to, and the trailer to append.
+    fn trailer(mut self, input: &[u8], tag: &str) -> (usize, String) {
+        let continuation = format!("{CONTINUATION}{tag}");
+        let placeholder = format!("\"{tag}\"");
+
+        let mut truncate_to = input.len();
+        let mut suffix = String::new();
+
+        match self.state {
+            State::Broken => return (truncate_to, suffix),
+            State::Comment { expect_value } => {
+                suffix.push('\n');
+                self.state = if self.stack.is_empty() {
+                    State::LineStart
+                } else if expect_value {
+                    State::ExpectValue
+                } else {
+                    State::AfterValue
+                };
+            }
+            State::String {
+                kind,
+                is_key,
+                unfinished_escape,
+            } => {
+                if let Some(escape_start) = unfinished_escape {
+                    truncate_to = escape_start;
+                }
+                suffix.push_str(&continuation);
+                suffix.push_str(kind.closing());
+                self.end_string(is_key);
+            }
+            State::KeyStart | State::BareKey => {
+                suffix.push_str(&continuation);
+                self.state = State::AfterKey;
+            }
+            State::BareValue { start } => {
+                if is_valid_value(&input[start..]) {
+                    self.state = State::AfterValue;
+                } else {
+                    truncate_to = start;
+                    self.state = State::ExpectValue;
+                }
+            }
+            State::HeaderClosing => {
+                suffix.push(']');
+                self.header = None;
+                self.state = State::AfterValue;
+            }
+            State::AfterKey => {
+                // The key may continue, for instance `server` may become `server.port`.
+                // If we used the key as it is, then it may clash with an earlier table.
+                suffix.push('.');
+                suffix.push_str(&continuation);
+            }
+            State::LineStart | State::ExpectValue | State::AfterValue => {}
+        }
+
+        let mut last_value_is_placeholder = false;
+        loop {
+            match self.state {
+                State::AfterKey => match self.header.take() {
+                    Some(Header::Table) => {
+                        suffix.push(']');
+                        self.state = State::AfterValue;
+                    }
+                    Some(Header::ArrayOfTables) => {
+                        suffix.push_str("]]");
+                        self.state = State::AfterValue;
+                    }
+                    None => {
+                        suffix.push_str(" = ");
+                        self.state = State::ExpectValue;
+                    }
+                },
+                State::ExpectValue => {
+                    suffix.push_str(&placeholder);
+                    last_value_is_placeholder = true;
+                    self.state = State::AfterValue;
+                }
+                State::AfterValue => {
+                    match self.stack.pop() {
+                        None => break,
+                        Some(Bracket::Array) => {
+                            if !last_value_is_placeholder {
+                                suffix.push_str(", ");
+                                suffix.push_str(&placeholder);
+                            }
+                            suffix.push(']');
+                        }
+                        Some(Bracket::InlineTable) => {
+                            if !last_value_is_placeholder {
+                                suffix.push_str(", ");
+                                suffix.push_str(&continuation);
+                                suffix.push_str(" = ");
+                                suffix.push_str(&placeholder);
+                            }
+                            suffix.push('}');
+                        }
+                    }
+                    last_value_is_placeholder = false;
+                }
+                _ => break,
+            }
+        }
+
+        (truncate_to, suffix)
+    }
+}
+
+fn is_bare_key_char(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || c == b'_' || c == b'-'
+}
+
+/// How many times `c` occurs in a row, starting at `i`.
+fn count_run(input: &[u8], i: usize, c: u8) -> usize {
+    input[i..].iter().take_while(|&&x| x == c).count()
+}
+
+/// The length of the escape sequence that starts with a backslash at `i`, or
+/// `None` if the input stops before it is finished.
+fn escape_len(input: &[u8], i: usize, is_multi_line: bool) -> Option<usize> {
+    let n_hex_digits = match input.get(i + 1)? {
+        b'u' => 4,
+        b'U' => 8,
+        b' ' | b'\t' | b'\r' | b'\n' if is_multi_line => {
+            // A line ending backslash: it must be followed by a newline.
+            let whitespace = input[i + 1..]
+                .iter()
+                .take_while(|c| matches!(c, b' ' | b'\t' | b'\r' | b'\n'))
+                .collect::<Vec<_>>();
+            return whitespace.contains(&&b'\n').then_some(1 + whitespace.len());
+        }
+        _ => return Some(2),
+    };
+    (input.len() >= i + 2 + n_hex_digits).then_some(2 + n_hex_digits)
+}
+
+/// Whether this is a whole value, such as `true` or `12`, and not for instance `tr`.
+fn is_valid_value(value: &[u8]) -> bool {
+    std::str::from_utf8(value).is_ok_and(|value| {
+        toml::Value::deserialize(toml::de::ValueDeserializer::new(value)).is_ok()
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TAG: &str = "BERLIN";
+
+    fn prepare(input: &str) -> String {
+        let mut prepared = input.to_string();
+        TomlRandomTrailer.prepare_string_with_tag(&mut prepared, TAG);
+        prepared
+    }
+
+    #[test]
+    fn test_trailer() {
+        for (input, expected) in [
+            ("", ""),
+            ("a = 1\n", "a = 1\n"),
+            ("a", r#"atBERLIN = "BERLIN""#),
+            ("a.", r#"a.tBERLIN = "BERLIN""#),
+            ("a =", r#"a ="BERLIN""#),
+            ("a = tr", r#"a = "BERLIN""#),
+            ("a = 12", "a = 12"),
+            (r#"a = "Hello, wo"#, r#"a = "Hello, wotBERLIN""#),
+            (r#"a = "Hello, wo\"#, r#"a = "Hello, wotBERLIN""#),
+            (r#"a = "caf\u00e"#, r#"a = "caftBERLIN""#),
+            ("a = 'C:\\", "a = 'C:\\tBERLIN'"),
+            ("a = \"\"\"x\"\"", "a = \"\"\"x\"\"tBERLIN\"\"\""),
+            ("a = '''x\n", "a = '''x\ntBERLIN'''"),
+            ("a = \"\"\"x \\  ", "a = \"\"\"x tBERLIN\"\"\""),
+            ("a = [1, 2", r#"a = [1, 2, "BERLIN"]"#),
+            ("a = [1, [2,", r#"a = [1, [2,"BERLIN"], "BERLIN"]"#),
+            ("a = [1, # one\n", "a = [1, # one\n\"BERLIN\"]"),
+            ("a = {", r#"a = {tBERLIN = "BERLIN"}"#),
+            ("a = {b = 1", r#"a = {b = 1, tBERLIN = "BERLIN"}"#),
+            ("a = {b = ", r#"a = {b = "BERLIN"}"#),
+            ("[tab", "[tabtBERLIN]"),
+            ("[[tab]", "[[tab]]"),
+            ("[tab ", "[tab .tBERLIN]"),
+            ("a.b ", r#"a.b .tBERLIN = "BERLIN""#),
+            ("a = {b ", r#"a = {b .tBERLIN = "BERLIN"}"#),
+            ("[tab]", "[tab]"),
+            ("a = 1 # comment", "a = 1 # comment\n"),
+        ] {
+            assert_eq!(prepare(input), expected, "input = {input:?}");
+            assert!(
+                toml::from_str::<toml::Value>(&prepare(input)).is_ok(),
+                "input = {input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_remove_trailer() {
+        let mut cut = "Hello, wotBERLIN";
+        assert!(TomlRandomTrailer.remove_trailer(&mut cut, TAG));
+        assert_eq!(cut, "Hello, wo");
+
+        let placeholder = "BERLIN";
+        assert!(TomlRandomTrailer.is_placeholder(&placeholder, TAG));
+        assert!(!TomlRandomTrailer.is_placeholder(&cut, TAG));
+    }
+
+    #[test]
+    fn test_slice_format_utf8() {
+        use std::collections::BTreeMap;
+
+        use crate::source::{SliceFormat, TomlFormat};
+
+        let parse = |input: &[u8]| {
+            crate::Options::new_no_nonce().deserialize_source::<BTreeMap<String, String>, _>(
+                TomlFormat::source_from_slice(input),
+            )
+        };
+
+        assert_eq!(
+            parse(b"a = \"caf\"\n\xC3").unwrap(),
+            BTreeMap::from([("a".to_string(), "caf".to_string())])
+        );
+        let err = parse(b"a = \"caf\xFF\"\nb = \"x\"\n").unwrap_err();
+        assert!(err.as_deserializer_error().is_some(), "err = {err}");
+    }
+}
@@ -60,6 +60,75 @@ impl RandomTrailer for YamlRandomTrailer {
             false
         }
     }
+
+    fn trailing_number_len(&self, input: &[u8]) -> usize {
+        let line_start = input
+            .iter()
+            .rposition(|byte| *byte == b'\n')
+            .map_or(0, |i| i + 1);
+        let line = &input[line_start..];
+        if heuristic_ends_inside_quotes(line) {
+            return 0;
+        }
+        let len = line
+            .iter()
+            .rev()
+            .take_while(|byte| {
+                !byte.is_ascii_whitespace() && !matches!(byte, b'[' | b']' | b'{' | b'}' | b',')
+            })
+            .count();
+        if !matches!(
+            line[line.len() - len..].first(),
+            Some(b'0'..=b'9' | b'-' | b'+' | b'.')
+        ) {
+            return 0;
+        }
+
+        // Without its value, `a: 12` would be `a: null`, and `- 12` would be `- null`.
+        // So then we leave out the whole entry, and the entries around it that become
+        // empty too, like `a:` in `a:\n  b: 12`.
+        let mut start = input.len() - len;
+        loop {
+            let before_len = input[..start]
+                .iter()
+                .rposition(|byte| !byte.is_ascii_whitespace())
+                .map_or(0, |i| i + 1);
+            if !matches!(input[..before_len].last(), Some(b':' | b'-')) {
+                break;
+            }
+            start = input[..before_len - 1]
+                .iter()
+                .rposition(|byte| matches!(byte, b'\n' | b',' | b'[' | b'{'))
+                .map_or(0, |i| i + 1);
+        }
+        input.len() - start
+    }
+}
+
+/// Whether the last line of the input stops inside a quoted string.
+///
+/// We only look at this line, so we miss strings that started on an earlier line. Then
+/// we may withhold the end of a string, which is still sound.
+fn heuristic_ends_inside_quotes(line: &[u8]) -> bool {
+    let mut quote = None;
+    let mut i = 0;
+    while i < line.len() {
+        let byte = line[i];
+        let after_separator = i == 0
+            || line[i - 1].is_ascii_whitespace()
+            || matches!(line[i - 1], b'[' | b'{' | b',' | b':');
+        match quote {
+            None if matches!(byte, b'"' | b'\'') && after_separator => quote = Some(byte),
+            None if byte == b'#' && after_separator => return false,
+            // An escape in a double-quoted string, or `''` in a single-quoted string.
+            Some(b'"') if byte == b'\\' => i += 1,
+            Some(b'\'') if byte == b'\'' && line.get(i + 1) == Some(&b'\'') => i += 1,
+            Some(q) if byte == q => quote = None,
+            _ => {}
+        }
+        i += 1;
+    }
+    quote.is_some()
 }
 
 fn heuristic_should_add_backslash(s: impl StringLike) -> bool {
@@ -82,6 +151,8 @@ mod test {
     #[path = "../../tests/output/yaml_output/cow_string.rs"]
     mod cow_string;
 
+    use std::collections::BTreeMap;
+
     use cow_string::CowString;
     use serde::Deserialize;
 
@@ -178,4 +249,47 @@ mod test {
             )
         }
     }
+
+    #[test]
+    fn test_withhold_mapping_value() {
+        // Not `{"a": None}`, because the input may continue as `a: 12`.
+        for (input, expected) in [("a: 1", vec![]), ("x: 1\na: 1", vec![("x", Some(1))])] {
+            let value: BTreeMap<String, Option<u32>> = crate::Options::new_yaml()
+                .withhold_trailing_numbers()
+                .deserialize_from_yaml_str(input.into())
+                .unwrap();
+            let expected = expected
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value))
+                .collect::<BTreeMap<_, _>>();
+            assert_eq!(value, expected, "input = {input:?}");
+        }
+    }
+
+    #[test]
+    fn test_trailing_number_len() {
+        for (input, expected) in [
+            ("12", 2),
+            ("- 12", 4),
+            ("a: -3.5", 7),
+            ("[1, 12", 2),
+            ("{x: 1, a: 12", 6),
+            ("a: 12\n", 0),
+            ("a: true", 0),
+            ("a: x12", 0),
+            ("a: \"x 12", 0),
+            ("a: 'it''s 12", 0),
+            ("a: it's 12", 2),
+            ("a: 'x'\nb: 1", 4),
+            ("x: 1\na:\n  - b: 12", 12),
+            ("- [1, 2]\n- 3", 3),
+            ("", 0),
+        ] {
+            assert_eq!(
+                YamlRandomTrailer.trailing_number_len(input.as_bytes()),
+                expected,
+                "input = {input:?}"
+            );
+        }
+    }
 }
@@ -0,0 +1,55 @@
+use bincode::config::{
+    AllowTrailing, DefaultOptions, FixintEncoding, WithOtherIntEncoding, WithOtherTrailing,
+};
+use bincode::de::read::SliceReader;
+use bincode::Options as _;
+
+use super::{SliceFormat, Source};
+
+/// The configuration of [`bincode::deserialize`].
+type BincodeOptions =
+    WithOtherTrailing<WithOtherIntEncoding<DefaultOptions, FixintEncoding>, AllowTrailing>;
+
+/// Essentially [`bincode::deserialize`], with the same configuration.
+///
+/// Bincode is not self-describing, so we can't tell where a string ends without
+/// knowing the type, and we don't use a random trailer. A string that was cut off is
+/// missing from the output.
+pub struct BincodeBytes<'de, T: std::borrow::Borrow<[u8]> + ?Sized>(pub &'de T);
+
+impl<'de, T: std::borrow::Borrow<[u8]> + ?Sized> Source<'de> for BincodeBytes<'de, T> {
+    type DeserializerStorage = bincode::Deserializer<SliceReader<'de>, BincodeOptions>;
+    type Deserializer<'storage>
+        = &'storage mut bincode::Deserializer<SliceReader<'de>, BincodeOptions>
+    where
+        'de: 'storage;
+    type Error = bincode::Error;
+
+    fn recreate_deserializer_storage(&mut self) -> Self::DeserializerStorage {
+        let options = DefaultOptions::new()
+            .with_fixint_encoding()
+            .allow_trailing_bytes();
+        bincode::Deserializer::from_slice(self.0.borrow(), options)
+    }
+
+    fn use_deserializer_from_storage<'storage>(
+        storage: &'storage mut Option<bincode::Deserializer<SliceReader<'de>, BincodeOptions>>,
+    ) -> &'storage mut bincode::Deserializer<SliceReader<'de>, BincodeOptions> {
+        storage
+            .as_mut()
+            .expect("use_deserializer_from_storage only called on Some")
+    }
+}
+
+/// Bincode as a [`SliceFormat`], using [`BincodeBytes`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BincodeFormat;
+
+impl SliceFormat for BincodeFormat {
+    type Source<'de> = BincodeBytes<'de, [u8]>;
+    type Error = bincode::Error;
+
+    fn source_from_slice(input: &[u8]) -> BincodeBytes<'_, [u8]> {
+        BincodeBytes(input)
+    }
+}
@@ -0,0 +1,42 @@
+use super::{SliceFormat, Source};
+
+/// Essentially [`serde_cbor::from_slice`].
+///
+/// CBOR does not need a random trailer: strings and byte strings are length-prefixed,
+/// so [`serde_cbor`] never visits part of one. A string that was cut off is missing
+/// from the output instead.
+pub struct CborBytes<'de, T: std::borrow::Borrow<[u8]> + ?Sized>(pub &'de T);
+
+impl<'de, T: std::borrow::Borrow<[u8]> + ?Sized> Source<'de> for CborBytes<'de, T> {
+    type DeserializerStorage = serde_cbor::Deserializer<serde_cbor::de::SliceRead<'de>>;
+    type Deserializer<'storage>
+        = &'storage mut serde_cbor::Deserializer<serde_cbor::de::SliceRead<'de>>
+    where
+        'de: 'storage;
+    type Error = serde_cbor::Error;
+
+    fn recreate_deserializer_storage(&mut self) -> Self::DeserializerStorage {
+        serde_cbor::Deserializer::from_slice(self.0.borrow())
+    }
+
+    fn use_deserializer_from_storage<'storage>(
+        storage: &'storage mut Option<serde_cbor::Deserializer<serde_cbor::de::SliceRead<'de>>>,
+    ) -> &'storage mut serde_cbor::Deserializer<serde_cbor::de::SliceRead<'de>> {
+        storage
+            .as_mut()
+            .expect("use_deserializer_from_storage only called on Some")
+    }
+}
+
+/// CBOR as a [`SliceFormat`], using [`CborBytes`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CborFormat;
+
+impl SliceFormat for CborFormat {
+    type Source<'de> = CborBytes<'de, [u8]>;
+    type Error = serde_cbor::Error;
+
+    fn source_from_slice(input: &[u8]) -> CborBytes<'_, [u8]> {
+        CborBytes(input)
+    }
+}
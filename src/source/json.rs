@@ -1,4 +1,5 @@
-use super::Source;
+use super::{SliceFormat, Source};
+use crate::util::utf8_complete_prefix_len;
 
 /// Essentially [`serde_json::from_str`].
 pub struct JsonStr<'de, T: std::borrow::Borrow<str> + ?Sized>(pub &'de T);
@@ -47,3 +48,20 @@ impl<'de, T: std::borrow::Borrow<[u8]> + ?Sized> Source<'de> for JsonBytes<'de,
             .expect("use_deserializer_from_storage only called on Some")
     }
 }
+
+/// JSON as a [`SliceFormat`], using [`JsonBytes`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct JsonFormat;
+
+impl SliceFormat for JsonFormat {
+    type Source<'de> = JsonBytes<'de, [u8]>;
+    type Error = serde_json::Error;
+
+    fn source_from_slice(input: &[u8]) -> JsonBytes<'_, [u8]> {
+        JsonBytes(input)
+    }
+
+    fn usable_prefix_len(input: &[u8]) -> usize {
+        utf8_complete_prefix_len(input)
+    }
+}
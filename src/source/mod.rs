@@ -4,15 +4,39 @@
 
 use serde::Deserializer;
 
+#[cfg(feature = "bincode")]
+mod bincode;
+#[cfg(feature = "serde_cbor")]
+mod cbor;
 #[cfg(feature = "serde_json")]
 mod json;
+#[cfg(feature = "rmp-serde")]
+mod msgpack;
+#[cfg(feature = "postcard")]
+mod postcard;
+#[cfg(feature = "ron")]
+mod ron;
+#[cfg(feature = "toml")]
+mod toml;
 #[cfg(feature = "serde_yaml")]
 mod yaml;
 
+#[cfg(feature = "bincode")]
+pub use bincode::{BincodeBytes, BincodeFormat};
+#[cfg(feature = "serde_cbor")]
+pub use cbor::{CborBytes, CborFormat};
 #[cfg(feature = "serde_json")]
-pub use json::{JsonBytes, JsonStr};
+pub use json::{JsonBytes, JsonFormat, JsonStr};
+#[cfg(feature = "rmp-serde")]
+pub use msgpack::{MsgPackBytes, MsgPackFormat};
+#[cfg(feature = "postcard")]
+pub use postcard::{PostcardBytes, PostcardFormat};
+#[cfg(feature = "ron")]
+pub use ron::{RonBytes, RonFormat, RonStr};
+#[cfg(feature = "toml")]
+pub use toml::{TomlBytes, TomlFormat, TomlStr};
 #[cfg(feature = "serde_yaml")]
-pub use yaml::{YamlBytes, YamlStr};
+pub use yaml::{YamlBytes, YamlFormat, YamlStr};
 
 /// Describes a resource that can be deserialized multiple times:
 /// a [`Deserializer`] together with the data it works on, such as a string
@@ -54,4 +78,35 @@ pub trait Source<'de> {
     fn use_deserializer_from_storage(
         storage: &mut Option<Self::DeserializerStorage>,
     ) -> Self::Deserializer<'_>;
+
+    /// Report an error that no deserialization attempt could work around, such as
+    /// a malformed header that the data format reads before it starts deserializing.
+    ///
+    /// Will be called once, before the first attempt. By default, there is no such
+    /// error.
+    fn check(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// A data format that can make a [`Source`] out of any byte slice.
+///
+/// This is for APIs like [`crate::IncrementalDeserializer`], which own their
+/// input buffer and need to create a new [`Source`] whenever it changes.
+///
+/// Implemented by marker types, such as [`JsonFormat`] and [`YamlFormat`].
+pub trait SliceFormat {
+    type Source<'de>: Source<'de, Error = Self::Error>;
+
+    type Error: serde::de::Error;
+
+    fn source_from_slice(input: &[u8]) -> Self::Source<'_>;
+
+    /// How much of a possibly incomplete input is worth showing to the data format.
+    ///
+    /// Text formats should leave out a partial UTF-8 character at the end, since
+    /// the data format would reject it as invalid.
+    fn usable_prefix_len(input: &[u8]) -> usize {
+        input.len()
+    }
 }
@@ -0,0 +1,42 @@
+use super::{SliceFormat, Source};
+
+type ReadRefReader<'de> = rmp_serde::decode::ReadRefReader<'de, [u8]>;
+
+/// Essentially [`rmp_serde::from_slice`].
+///
+/// This does not apply the random trailer; see [`crate::Options::deserialize_from_msgpack_slice`].
+pub struct MsgPackBytes<'de, T: std::borrow::Borrow<[u8]> + ?Sized>(pub &'de T);
+
+impl<'de, T: std::borrow::Borrow<[u8]> + ?Sized> Source<'de> for MsgPackBytes<'de, T> {
+    type DeserializerStorage = rmp_serde::Deserializer<ReadRefReader<'de>>;
+    type Deserializer<'storage>
+        = &'storage mut rmp_serde::Deserializer<ReadRefReader<'de>>
+    where
+        'de: 'storage;
+    type Error = rmp_serde::decode::Error;
+
+    fn recreate_deserializer_storage(&mut self) -> Self::DeserializerStorage {
+        rmp_serde::Deserializer::from_read_ref(self.0.borrow())
+    }
+
+    fn use_deserializer_from_storage<'storage>(
+        storage: &'storage mut Option<rmp_serde::Deserializer<ReadRefReader<'de>>>,
+    ) -> &'storage mut rmp_serde::Deserializer<ReadRefReader<'de>> {
+        storage
+            .as_mut()
+            .expect("use_deserializer_from_storage only called on Some")
+    }
+}
+
+/// MessagePack as a [`SliceFormat`], using [`MsgPackBytes`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MsgPackFormat;
+
+impl SliceFormat for MsgPackFormat {
+    type Source<'de> = MsgPackBytes<'de, [u8]>;
+    type Error = rmp_serde::decode::Error;
+
+    fn source_from_slice(input: &[u8]) -> MsgPackBytes<'_, [u8]> {
+        MsgPackBytes(input)
+    }
+}
@@ -0,0 +1,44 @@
+use super::{SliceFormat, Source};
+
+type Slice<'de> = postcard::de_flavors::Slice<'de>;
+
+/// Essentially [`postcard::from_bytes`].
+///
+/// Postcard is not self-describing, so we can't tell where a string ends without
+/// knowing the type, and we don't use a random trailer. A string that was cut off is
+/// missing from the output.
+pub struct PostcardBytes<'de, T: std::borrow::Borrow<[u8]> + ?Sized>(pub &'de T);
+
+impl<'de, T: std::borrow::Borrow<[u8]> + ?Sized> Source<'de> for PostcardBytes<'de, T> {
+    type DeserializerStorage = postcard::Deserializer<'de, Slice<'de>>;
+    type Deserializer<'storage>
+        = &'storage mut postcard::Deserializer<'de, Slice<'de>>
+    where
+        'de: 'storage;
+    type Error = postcard::Error;
+
+    fn recreate_deserializer_storage(&mut self) -> Self::DeserializerStorage {
+        postcard::Deserializer::from_bytes(self.0.borrow())
+    }
+
+    fn use_deserializer_from_storage<'storage>(
+        storage: &'storage mut Option<postcard::Deserializer<'de, Slice<'de>>>,
+    ) -> &'storage mut postcard::Deserializer<'de, Slice<'de>> {
+        storage
+            .as_mut()
+            .expect("use_deserializer_from_storage only called on Some")
+    }
+}
+
+/// Postcard as a [`SliceFormat`], using [`PostcardBytes`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PostcardFormat;
+
+impl SliceFormat for PostcardFormat {
+    type Source<'de> = PostcardBytes<'de, [u8]>;
+    type Error = postcard::Error;
+
+    fn source_from_slice(input: &[u8]) -> PostcardBytes<'_, [u8]> {
+        PostcardBytes(input)
+    }
+}
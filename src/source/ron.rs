@@ -0,0 +1,120 @@
+use super::{SliceFormat, Source};
+use crate::util::utf8_complete_prefix_len;
+
+/// Essentially [`ron::from_str`].
+///
+/// [`ron`] reads any `#![enable(...)]` attributes and leading comments before it
+/// starts deserializing. If the input stops before those are complete, then we
+/// deserialize as if the input were empty. Any other error in them is returned.
+pub struct RonStr<'de, T: std::borrow::Borrow<str> + ?Sized>(pub &'de T);
+
+/// Essentially [`ron::from_bytes`].
+pub struct RonBytes<'de, T: std::borrow::Borrow<[u8]> + ?Sized>(pub &'de T);
+
+impl<'de, T: std::borrow::Borrow<str> + ?Sized> Source<'de> for RonStr<'de, T> {
+    type DeserializerStorage = ron::Deserializer<'de>;
+    type Deserializer<'storage>
+        = &'storage mut ron::Deserializer<'de>
+    where
+        'de: 'storage;
+    type Error = ron::Error;
+
+    fn recreate_deserializer_storage(&mut self) -> Self::DeserializerStorage {
+        ron::Deserializer::from_str(self.0.borrow()).unwrap_or_else(|_| empty_deserializer())
+    }
+
+    fn use_deserializer_from_storage<'storage>(
+        storage: &'storage mut Option<ron::Deserializer<'de>>,
+    ) -> &'storage mut ron::Deserializer<'de> {
+        storage
+            .as_mut()
+            .expect("use_deserializer_from_storage only called on Some")
+    }
+
+    fn check(&mut self) -> Result<(), ron::Error> {
+        check_header(
+            ron::Deserializer::from_str(self.0.borrow()),
+            self.0.borrow().as_bytes(),
+        )
+    }
+}
+
+impl<'de, T: std::borrow::Borrow<[u8]> + ?Sized> Source<'de> for RonBytes<'de, T> {
+    type DeserializerStorage = ron::Deserializer<'de>;
+    type Deserializer<'storage>
+        = &'storage mut ron::Deserializer<'de>
+    where
+        'de: 'storage;
+    type Error = ron::Error;
+
+    fn recreate_deserializer_storage(&mut self) -> Self::DeserializerStorage {
+        ron::Deserializer::from_bytes(self.0.borrow()).unwrap_or_else(|_| empty_deserializer())
+    }
+
+    fn use_deserializer_from_storage<'storage>(
+        storage: &'storage mut Option<ron::Deserializer<'de>>,
+    ) -> &'storage mut ron::Deserializer<'de> {
+        storage
+            .as_mut()
+            .expect("use_deserializer_from_storage only called on Some")
+    }
+
+    fn check(&mut self) -> Result<(), ron::Error> {
+        check_header(
+            ron::Deserializer::from_bytes(self.0.borrow()),
+            self.0.borrow(),
+        )
+    }
+}
+
+/// For an input that stops inside the header. [`check_header`] has already returned
+/// any other error.
+fn empty_deserializer<'de>() -> ron::Deserializer<'de> {
+    ron::Deserializer::from_str("").expect("empty input has no attributes to fail on")
+}
+
+/// Return the error from reading the header, unless the input stops inside it.
+///
+/// The random trailer ends up in an attribute that was cut off, so [`ron`] fails on
+/// that instead of on the end of the input. We recognize it because nothing after
+/// the error closes the attribute or starts a new line.
+fn check_header(header: ron::Result<ron::Deserializer<'_>>, input: &[u8]) -> ron::Result<()> {
+    match header {
+        Ok(_) => Ok(()),
+        Err(error) => {
+            let rest = &input[byte_offset(input, &error.position).min(input.len())..];
+            if rest.iter().any(|&c| c == b']' || c == b'\n') {
+                Err(error)
+            } else {
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Where a [`ron::error::Position`] is in the input. [`ron`] counts columns in bytes.
+fn byte_offset(input: &[u8], position: &ron::error::Position) -> usize {
+    let line_start = input
+        .split_inclusive(|&c| c == b'\n')
+        .take(position.line.saturating_sub(1))
+        .map(<[u8]>::len)
+        .sum::<usize>();
+    line_start + position.col.saturating_sub(1)
+}
+
+/// RON as a [`SliceFormat`], using [`RonBytes`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RonFormat;
+
+impl SliceFormat for RonFormat {
+    type Source<'de> = RonBytes<'de, [u8]>;
+    type Error = ron::Error;
+
+    fn source_from_slice(input: &[u8]) -> RonBytes<'_, [u8]> {
+        RonBytes(input)
+    }
+
+    fn usable_prefix_len(input: &[u8]) -> usize {
+        utf8_complete_prefix_len(input)
+    }
+}
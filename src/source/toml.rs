@@ -0,0 +1,85 @@
+use super::{SliceFormat, Source};
+use crate::util::utf8_complete_prefix_len;
+
+/// Essentially [`toml::from_str`].
+pub struct TomlStr<'de, T: std::borrow::Borrow<str> + ?Sized>(pub &'de T);
+
+/// Like [`TomlStr`], after checking that the bytes are UTF-8. [`toml`] only reads
+/// strings.
+///
+/// A UTF-8 character that is cut off at the end is left out, like other text formats
+/// do. Any other invalid UTF-8 is an error.
+pub struct TomlBytes<'de, T: std::borrow::Borrow<[u8]> + ?Sized>(pub &'de T);
+
+impl<'de, T: std::borrow::Borrow<str> + ?Sized> Source<'de> for TomlStr<'de, T> {
+    type DeserializerStorage = toml::Deserializer<'de>;
+    type Deserializer<'storage>
+        = toml::Deserializer<'de>
+    where
+        'de: 'storage;
+    type Error = toml::de::Error;
+
+    fn recreate_deserializer_storage(&mut self) -> Self::DeserializerStorage {
+        toml::Deserializer::new(self.0.borrow())
+    }
+
+    fn use_deserializer_from_storage<'storage>(
+        storage: &mut Option<toml::Deserializer<'de>>,
+    ) -> toml::Deserializer<'de> {
+        storage
+            .take()
+            .expect("use_deserializer_from_storage only called on Some")
+    }
+}
+
+impl<'de, T: std::borrow::Borrow<[u8]> + ?Sized> TomlBytes<'de, T> {
+    fn as_str(&self) -> Result<&'de str, std::str::Utf8Error> {
+        let bytes: &'de [u8] = self.0.borrow();
+        std::str::from_utf8(&bytes[..utf8_complete_prefix_len(bytes)])
+    }
+}
+
+impl<'de, T: std::borrow::Borrow<[u8]> + ?Sized> Source<'de> for TomlBytes<'de, T> {
+    type DeserializerStorage = toml::Deserializer<'de>;
+    type Deserializer<'storage>
+        = toml::Deserializer<'de>
+    where
+        'de: 'storage;
+    type Error = toml::de::Error;
+
+    fn recreate_deserializer_storage(&mut self) -> Self::DeserializerStorage {
+        // `Self::check` has already returned the error if this is not UTF-8.
+        toml::Deserializer::new(self.as_str().unwrap_or_default())
+    }
+
+    fn use_deserializer_from_storage<'storage>(
+        storage: &mut Option<toml::Deserializer<'de>>,
+    ) -> toml::Deserializer<'de> {
+        storage
+            .take()
+            .expect("use_deserializer_from_storage only called on Some")
+    }
+
+    fn check(&mut self) -> Result<(), toml::de::Error> {
+        self.as_str()
+            .map(|_| ())
+            .map_err(<toml::de::Error as serde::de::Error>::custom)
+    }
+}
+
+/// TOML as a [`SliceFormat`], using [`TomlBytes`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TomlFormat;
+
+impl SliceFormat for TomlFormat {
+    type Source<'de> = TomlBytes<'de, [u8]>;
+    type Error = toml::de::Error;
+
+    fn source_from_slice(input: &[u8]) -> TomlBytes<'_, [u8]> {
+        TomlBytes(input)
+    }
+
+    fn usable_prefix_len(input: &[u8]) -> usize {
+        utf8_complete_prefix_len(input)
+    }
+}
@@ -1,4 +1,5 @@
-use super::Source;
+use super::{SliceFormat, Source};
+use crate::util::utf8_complete_prefix_len;
 
 /// Essentially [`serde_yaml::from_str`].
 pub struct YamlStr<'de, T: std::borrow::Borrow<str> + ?Sized>(pub &'de T);
@@ -47,3 +48,20 @@ impl<'de, T: std::borrow::Borrow<[u8]> + ?Sized> Source<'de> for YamlBytes<'de,
             .expect("use_deserializer_from_storage only called on Some")
     }
 }
+
+/// YAML as a [`SliceFormat`], using [`YamlBytes`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct YamlFormat;
+
+impl SliceFormat for YamlFormat {
+    type Source<'de> = YamlBytes<'de, [u8]>;
+    type Error = serde_yaml::Error;
+
+    fn source_from_slice(input: &[u8]) -> YamlBytes<'_, [u8]> {
+        YamlBytes(input)
+    }
+
+    fn usable_prefix_len(input: &[u8]) -> usize {
+        utf8_complete_prefix_len(input)
+    }
+}
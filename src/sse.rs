@@ -0,0 +1,197 @@
+//! JSON that arrives in fragments inside [Server-Sent Events](https://html.spec.whatwg.org/multipage/server-sent-events.html).
+
+use serde::de::DeserializeOwned;
+
+use crate::options::JsonExtraOptions;
+use crate::options_impl::ExtraOptions;
+use crate::source::JsonFormat;
+use crate::{Error, IncrementalDeserializer, Options};
+
+/// Reads a Server-Sent Events stream where every event carries a piece of one JSON
+/// document, and deserializes the document so far.
+///
+/// Every `data:` payload of an event is parsed as JSON, and the string at `pointer` (a
+/// JSON Pointer) is the next fragment. This is how LLM APIs stream the arguments of a
+/// tool call:
+///
+/// - OpenAI: `/choices/0/delta/tool_calls/0/function/arguments`
+/// - Anthropic: `/delta/partial_json`
+///
+/// ```
+/// use deser_incomplete::SseJsonFragments;
+///
+/// #[derive(Debug, PartialEq, serde::Deserialize)]
+/// struct Weather {
+///     city: String,
+///     #[serde(default)]
+///     days: u32,
+/// }
+///
+/// let mut fragments: SseJsonFragments<Weather> =
+///     SseJsonFragments::new_json("/delta/partial_json");
+///
+/// let chunk = b"event: content_block_delta\ndata: {\"delta\": {\"partial_json\": \"{\\\"city\\\": \\\"Par\"}}\n\n";
+/// let weather = fragments.push(chunk).unwrap().unwrap();
+/// assert_eq!(weather.city, "Par");
+///
+/// let chunk = b"data: {\"delta\": {\"partial_json\": \"is\\\", \\\"days\\\": 3}\"}}\n\ndata: [DONE]\n\n";
+/// let weather = fragments.push(chunk).unwrap().unwrap();
+/// assert_eq!(weather, Weather { city: "Paris".into(), days: 3 });
+/// ```
+///
+/// Events without a string at `pointer` are skipped, and so are payloads that are not
+/// JSON, such as `data: [DONE]` or an error message in plain text. An event only counts
+/// once the blank line after it arrives.
+pub struct SseJsonFragments<T, Extra: ExtraOptions = JsonExtraOptions> {
+    pointer: String,
+
+    /// The fragments so far, joined.
+    incremental: IncrementalDeserializer<T, JsonFormat, Extra>,
+
+    /// Input after the last complete line.
+    line_buffer: Vec<u8>,
+
+    /// The `data:` lines of the event that we are reading, joined by newlines.
+    data: Option<String>,
+}
+
+impl<T: DeserializeOwned> SseJsonFragments<T, JsonExtraOptions> {
+    /// Deserialize the fragments with [`Options::new_json`].
+    pub fn new_json(pointer: &str) -> Self {
+        Self::new(Options::new_json(), pointer)
+    }
+}
+
+impl<T, Extra> SseJsonFragments<T, Extra>
+where
+    T: DeserializeOwned,
+    Extra: ExtraOptions + Clone,
+{
+    /// Deserialize the fragments with custom options.
+    pub fn new(options: Options<Extra>, pointer: &str) -> Self {
+        Self::from_incremental(IncrementalDeserializer::new(options), pointer)
+    }
+
+    /// Feed the fragments into an existing incremental deserializer, for instance one
+    /// that is [`IncrementalDeserializer::monotonic`].
+    pub fn from_incremental(
+        incremental: IncrementalDeserializer<T, JsonFormat, Extra>,
+        pointer: &str,
+    ) -> Self {
+        Self {
+            pointer: pointer.to_owned(),
+            incremental,
+            line_buffer: Vec::new(),
+            data: None,
+        }
+    }
+
+    /// Append a chunk of the event stream. If it completed any events with a fragment,
+    /// then deserialize all fragments so far.
+    pub fn push(&mut self, chunk: &[u8]) -> Result<Option<T>, Error<serde_json::Error>> {
+        self.line_buffer.extend_from_slice(chunk);
+        let fragments = self.take_fragments(false);
+        if fragments.is_empty() {
+            return Ok(None);
+        }
+        self.incremental.push(fragments.as_bytes()).map(Some)
+    }
+
+    /// Deserialize all fragments, because the event stream has ended. An event that was
+    /// cut off is ignored.
+    pub fn finish(mut self) -> Result<T, Error<serde_json::Error>> {
+        let fragments = self.take_fragments(true);
+        if !fragments.is_empty() {
+            self.incremental.push(fragments.as_bytes())?;
+        }
+        self.incremental.finish()
+    }
+
+    /// The fragments so far, joined. This is the JSON that we deserialize.
+    pub fn json(&self) -> &[u8] {
+        self.incremental.input()
+    }
+
+    /// The incremental deserializer that the fragments go into.
+    pub fn incremental(&self) -> &IncrementalDeserializer<T, JsonFormat, Extra> {
+        &self.incremental
+    }
+
+    /// Read the complete lines in the buffer, and return the fragments of the events
+    /// that they completed.
+    fn take_fragments(&mut self, at_end: bool) -> String {
+        let mut fragments = String::new();
+        let mut line_start = 0;
+        while let Some((line_end, next_line)) =
+            find_line_end(&self.line_buffer[line_start..], at_end)
+        {
+            let line = &self.line_buffer[line_start..line_start + line_end];
+            line_start += next_line;
+
+            if line.is_empty() {
+                if let Some(data) = self.data.take() {
+                    fragments.push_str(&self.fragment(&data));
+                }
+                continue;
+            }
+            let line = String::from_utf8_lossy(line);
+            let (field, value) = line.split_once(':').unwrap_or((&line, ""));
+            let value = value.strip_prefix(' ').unwrap_or(value);
+            // Other fields, and comments (lines that start with a colon), don't matter.
+            if field == "data" {
+                match &mut self.data {
+                    Some(data) => {
+                        data.push('\n');
+                        data.push_str(value);
+                    }
+                    None => self.data = Some(value.to_owned()),
+                }
+            }
+        }
+        self.line_buffer.drain(..line_start);
+        fragments
+    }
+
+    /// The fragment in the payload of an event, or an empty string.
+    fn fragment(&self, data: &str) -> String {
+        let Ok(payload) = serde_json::from_str::<serde_json::Value>(data) else {
+            return String::new();
+        };
+        match payload.pointer(&self.pointer) {
+            Some(serde_json::Value::String(fragment)) => fragment.clone(),
+            _ => String::new(),
+        }
+    }
+}
+
+/// Where the first line in `input` ends, and where the next line starts.
+///
+/// Lines end in `\n`, `\r\n` or `\r`. If `input` ends in `\r`, then we can only tell
+/// how long the line ending is once the next byte arrives, or at the end.
+fn find_line_end(input: &[u8], at_end: bool) -> Option<(usize, usize)> {
+    let end = input
+        .iter()
+        .position(|byte| matches!(byte, b'\n' | b'\r'))?;
+    match input.get(end + 1) {
+        _ if input[end] == b'\n' => Some((end, end + 1)),
+        Some(b'\n') => Some((end, end + 2)),
+        Some(_) => Some((end, end + 1)),
+        None if at_end => Some((end, end + 1)),
+        None => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::find_line_end;
+
+    #[test]
+    fn test_find_line_end() {
+        assert_eq!(find_line_end(b"data: 1\nx", false), Some((7, 8)));
+        assert_eq!(find_line_end(b"data: 1\r\nx", false), Some((7, 9)));
+        assert_eq!(find_line_end(b"data: 1\rx", false), Some((7, 8)));
+        assert_eq!(find_line_end(b"data: 1\r", false), None);
+        assert_eq!(find_line_end(b"data: 1\r", true), Some((7, 8)));
+        assert_eq!(find_line_end(b"data: 1", true), None);
+    }
+}
@@ -4,7 +4,10 @@ use serde::de::SeqAccess;
 use crate::attempt::HaltingPoint;
 use crate::error::InternalError;
 use crate::options_impl::ExtraOptions;
+use crate::path::{Path, PathSegment};
 use crate::reporter::Reporter;
+use crate::stats::AttemptStats;
+use crate::util::DeserializeKind;
 use crate::Options;
 
 pub(crate) struct GlobalState<Extra: crate::options_impl::ExtraOptions> {
@@ -39,6 +42,101 @@ pub(crate) struct AttemptState<Extra: crate::options_impl::ExtraOptions> {
     /// On returning an error from an attempt, this field will remain intact as of the
     /// point of the original error.
     pub(super) halting_point_stack: Vec<HaltingPoint>,
+
+    /// Why earlier attempts failed.
+    past_reasons: Vec<InterventionReason>,
+
+    /// Whether the random trailer was removed from a string during this attempt.
+    saw_incomplete_string: bool,
+
+    /// How often during this attempt we removed the random trailer from a string, or
+    /// had a reason to intervene. (Also when an intervention was already active.)
+    n_truncation_events: usize,
+
+    /// Whether the most recently finished call to the deserializer returned a value
+    /// from a fallback.
+    pub(crate) last_deserialize_used_fallback: bool,
+
+    /// Where we are now in the data.
+    pub(crate) path: Vec<PathSegment>,
+
+    /// The most recent map key that we saw, until we know that it was a whole key.
+    pub(crate) pending_key: Option<String>,
+
+    /// Where we were at the first truncation event of the first attempt that had one.
+    truncation_path: Option<Path>,
+
+    /// Which fallbacks produced a value during this attempt.
+    fallbacks_used: Vec<&'static str>,
+
+    /// How often during this attempt the deserializer visited us with data from the
+    /// input. Structs, tuples and newtypes don't count, since formats like postcard
+    /// visit those without reading anything.
+    pub(crate) n_data_visits: usize,
+
+    /// How many bytes of strings and byte strings the deserializer gave us during this
+    /// attempt, after removing the random trailer.
+    pub(crate) n_string_bytes: usize,
+
+    /// Sequences and maps that we are inside of, innermost last.
+    open_collections: Vec<OpenCollection>,
+
+    /// Sequences and maps that the input stopped inside of, with how many of their first
+    /// elements are final. Kept across attempts, since a failed attempt may have seen
+    /// further into the input.
+    n_final_elements: Vec<(Path, usize)>,
+
+    #[cfg(feature = "erased-serde")]
+    callbacks: crate::callbacks::Callbacks,
+
+    /// Kept across attempts, so that we report every path once.
+    #[cfg(feature = "erased-serde")]
+    pub(crate) reported: crate::callbacks::Reported,
+}
+
+#[derive(Debug, Default)]
+struct OpenCollection {
+    /// How many of its first elements were followed by a separator.
+    n_final_elements: usize,
+    /// Whether the data format told us that the collection ended.
+    has_ended: bool,
+    /// The most recent element, if it looked complete and a callback wants it. We call
+    /// the callback once the element is followed by a separator or the end.
+    #[cfg(feature = "erased-serde")]
+    pending_callback: Option<(Path, crate::callbacks::Captured)>,
+}
+
+/// The result of a successful call to [`Options::deserialize_seed_impl`].
+pub(crate) struct Finished<Value, Extra: crate::options_impl::ExtraOptions> {
+    pub(crate) value: Value,
+    pub(crate) attempt: AttemptState<Extra>,
+}
+
+impl<Value, Extra: crate::options_impl::ExtraOptions> Finished<Value, Extra> {
+    pub(crate) fn into_outcome(self) -> crate::Outcome<Value> {
+        let (reasons, path, n_final_elements) = self.attempt.into_reasons_and_path();
+        crate::Outcome::new(self.value, reasons, path, n_final_elements)
+    }
+}
+
+/// What a successful attempt tells a later parse of the same input, so that it can
+/// halt at the same point straight away. See [`crate::IncrementalDeserializer`].
+#[derive(Clone, Debug)]
+pub(crate) struct Resume {
+    halting_point: HaltingPoint,
+    past_reasons: Vec<InterventionReason>,
+    truncation_path: Option<Path>,
+    n_final_elements: Vec<(Path, usize)>,
+}
+
+/// What a parse carries over from an earlier one, see [`crate::IncrementalDeserializer`].
+#[derive(Default)]
+pub(crate) struct Carryover {
+    /// Set if the earlier parse saw the same input.
+    pub(crate) resume: Option<Resume>,
+    /// Where the earlier parse already called callbacks.
+    #[cfg(feature = "erased-serde")]
+    pub(crate) reported: crate::callbacks::Reported,
 }
 
 #[derive(Clone, Debug)]
@@ -47,8 +145,10 @@ pub(crate) struct Intervention {
     candidate_halting_point_for_next_attempt: Option<HaltingPoint>,
 }
 
-#[derive(Clone, Debug)]
-pub(crate) enum InterventionReason {
+/// Why we had to step in while deserializing, see [`crate::Outcome`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InterventionReason {
     /// The deserializer has returned an error before calling the visitor.
     /// (We do not distinguish between its errors.)
     DeserializerStart,
@@ -56,16 +156,17 @@ pub(crate) enum InterventionReason {
     /// case, we can salvage the value returned by the visitor.
     DeserializerFinishSaved,
     /// We planned to halt deserialization at a certain point, and we have reached that point.
-    PlannedHalting {
-        #[allow(dead_code)]
-        at: HaltingPoint,
-    },
+    PlannedHalting { at: HaltingPoint },
     /// A visitor (data type) returned an error. We may or may not have applied a
     /// fallback.
     ///
     /// This may well be recoverable, e.g. if the error happens inside [`SeqAccess`] or
     /// a map key.
     VisitError,
+    /// The random trailer was removed from a string, so the string was cut short.
+    ///
+    /// This does not make us halt deserialization, it is only recorded.
+    IncompleteString,
 }
 
 impl<Extra: ExtraOptions> Options<Extra> {
@@ -82,16 +183,69 @@ impl<Extra: ExtraOptions> Options<Extra> {
 }
 
 impl<Extra: ExtraOptions> AttemptState<Extra> {
-    pub(crate) fn initial(global: &GlobalState<Extra>) -> Self {
+    /// The first attempt of a parse. With [`Carryover::resume`], it halts where an
+    /// earlier parse of the same input did, and remembers why the attempts before that
+    /// one failed.
+    pub(crate) fn initial(global: &GlobalState<Extra>, carryover: Carryover) -> Self {
+        let (intend_to_stop_deserializing_at, past_reasons, truncation_path, n_final_elements) =
+            match carryover.resume {
+                Some(resume) => (
+                    Some(resume.halting_point),
+                    resume.past_reasons,
+                    resume.truncation_path,
+                    resume.n_final_elements,
+                ),
+                None => (None, Vec::new(), None, Vec::new()),
+            };
         Self {
             reporter: global.reporter.clone(),
-            intend_to_stop_deserializing_at: None,
+            intend_to_stop_deserializing_at,
             intervention_active: None,
             next_halting_point: HaltingPoint::default(),
             halting_point_stack: Vec::new(),
+            past_reasons,
+            saw_incomplete_string: false,
+            n_truncation_events: 0,
+            last_deserialize_used_fallback: false,
+            path: Vec::new(),
+            pending_key: None,
+            truncation_path,
+            fallbacks_used: Vec::new(),
+            n_data_visits: 0,
+            n_string_bytes: 0,
+            open_collections: Vec::new(),
+            n_final_elements,
+            #[cfg(feature = "erased-serde")]
+            callbacks: global.config.callbacks.clone(),
+            #[cfg(feature = "erased-serde")]
+            reported: carryover.reported,
         }
     }
 
+    /// After the first attempt failed to halt where an earlier parse did: start over
+    /// without that, but keep what is kept across attempts.
+    pub(crate) fn restart(self, global: &GlobalState<Extra>) -> Self {
+        Self::initial(
+            global,
+            Carryover {
+                resume: None,
+                #[cfg(feature = "erased-serde")]
+                reported: self.reported,
+            },
+        )
+    }
+
+    /// After a successful attempt that planned where to halt: how a later parse of the
+    /// same input can do the same.
+    pub(crate) fn resume(&self) -> Option<Resume> {
+        Some(Resume {
+            halting_point: self.intend_to_stop_deserializing_at.clone()?,
+            past_reasons: self.past_reasons.clone(),
+            truncation_path: self.truncation_path.clone(),
+            n_final_elements: self.n_final_elements.clone(),
+        })
+    }
+
     /// When an attempt failed, then compute the state to start next attempt with -- if
     /// we know a way to potentially do better next attempt.
     ///
@@ -111,12 +265,29 @@ impl<Extra: ExtraOptions> AttemptState<Extra> {
                 );
 
                 self.halting_point_stack.clear();
+                self.past_reasons.push(reason);
                 Ok(Some(Self {
                     reporter: self.reporter,
                     intend_to_stop_deserializing_at: Some(next_halting_point),
                     intervention_active: None,
                     next_halting_point: HaltingPoint::default(),
                     halting_point_stack: self.halting_point_stack,
+                    past_reasons: self.past_reasons,
+                    saw_incomplete_string: false,
+                    n_truncation_events: 0,
+                    last_deserialize_used_fallback: false,
+                    path: Vec::new(),
+                    pending_key: None,
+                    truncation_path: self.truncation_path,
+                    fallbacks_used: Vec::new(),
+                    n_data_visits: 0,
+                    n_string_bytes: 0,
+                    open_collections: Vec::new(),
+                    n_final_elements: self.n_final_elements,
+                    #[cfg(feature = "erased-serde")]
+                    callbacks: self.callbacks,
+                    #[cfg(feature = "erased-serde")]
+                    reported: self.reported,
                 }))
             }
             None => {
@@ -166,6 +337,151 @@ impl<Extra: ExtraOptions> AttemptState<Extra> {
         }
     }
 
+    /// Remember that the random trailer was removed from a string.
+    pub(crate) fn note_incomplete_string(&mut self) {
+        self.saw_incomplete_string = true;
+        self.note_truncation_event();
+    }
+
+    /// Remember that a fallback produced a value.
+    pub(crate) fn note_fallback(&mut self, kind: DeserializeKind) {
+        self.fallbacks_used.push(kind.method_name());
+    }
+
+    /// Numbers about this attempt, once it is over.
+    pub(crate) fn take_stats(&mut self, succeeded: bool) -> AttemptStats {
+        AttemptStats {
+            planned_halting_point: self.intend_to_stop_deserializing_at.as_deref().copied(),
+            n_halting_points: *self.next_halting_point,
+            fallbacks: std::mem::take(&mut self.fallbacks_used),
+            succeeded,
+        }
+    }
+
+    /// Remember a map key that we see, so that we can add it to the path if it turns out
+    /// to be a whole key.
+    pub(crate) fn note_key(&mut self, key: impl std::fmt::Display) {
+        self.pending_key = Some(key.to_string());
+    }
+
+    fn note_truncation_event(&mut self) {
+        self.n_truncation_events += 1;
+        if self.truncation_path.is_none() {
+            self.truncation_path = Some(Path(self.path.clone()));
+        }
+    }
+
+    pub(crate) fn n_truncation_events(&self) -> usize {
+        self.n_truncation_events
+    }
+
+    /// Roughly how much of the input made it into the value: one for every visit with
+    /// data, plus the length of every string.
+    pub(crate) fn data_size(&self) -> usize {
+        self.n_data_visits + self.n_string_bytes
+    }
+
+    /// After a successful attempt: all reasons why the input seemed to be incomplete,
+    /// in order, where the input seemed to stop, and the collections that it stopped
+    /// inside of. No reasons if the input seemed complete.
+    pub(crate) fn into_reasons_and_path(
+        self,
+    ) -> (Vec<InterventionReason>, Path, Vec<(Path, usize)>) {
+        let mut reasons = self.past_reasons;
+        reasons.extend(self.intervention_active.map(|i| i.reason));
+        if self.saw_incomplete_string {
+            reasons.push(InterventionReason::IncompleteString);
+        }
+        let mut n_final_elements = self.n_final_elements;
+        n_final_elements.sort_by_key(|(path, _)| path.0.len());
+        (
+            reasons,
+            self.truncation_path.unwrap_or_default(),
+            n_final_elements,
+        )
+    }
+
+    /// Start deserializing the elements of a sequence or map at the current path.
+    pub(crate) fn enter_collection(&mut self) {
+        self.open_collections.push(OpenCollection::default());
+    }
+
+    /// The data format started element `index` of the innermost collection, so it must
+    /// have seen a separator after the elements before it.
+    pub(crate) fn note_element_started(&mut self, index: usize) {
+        if let Some(collection) = self.open_collections.last_mut() {
+            collection.n_final_elements = collection.n_final_elements.max(index);
+        }
+        #[cfg(feature = "erased-serde")]
+        self.fire_pending_callback();
+    }
+
+    /// The data format told us that the innermost collection ended.
+    pub(crate) fn note_collection_ended(&mut self) {
+        if let Some(collection) = self.open_collections.last_mut() {
+            collection.has_ended = true;
+        }
+        #[cfg(feature = "erased-serde")]
+        self.fire_pending_callback();
+    }
+
+    /// Whether a callback wants the value at the current path, if it turns out complete.
+    #[cfg(feature = "erased-serde")]
+    pub(crate) fn wants_callback(&self) -> bool {
+        !self.open_collections.is_empty() && self.callbacks.wants(&self.path, &self.reported)
+    }
+
+    /// We deserialized the value at the current path, and it looked complete if the
+    /// number of truncation events did not change since `n_truncation_events_before`.
+    #[cfg(feature = "erased-serde")]
+    pub(crate) fn note_value_for_callback(
+        &mut self,
+        value: crate::callbacks::Captured,
+        n_truncation_events_before: usize,
+    ) {
+        let complete = self.n_truncation_events == n_truncation_events_before
+            && !self.last_deserialize_used_fallback;
+        if let Some(collection) = self.open_collections.last_mut() {
+            collection.pending_callback = complete.then(|| (Path(self.path.clone()), value));
+        }
+    }
+
+    #[cfg(feature = "erased-serde")]
+    fn fire_pending_callback(&mut self) {
+        let pending = self
+            .open_collections
+            .last_mut()
+            .and_then(|collection| collection.pending_callback.take());
+        if let Some((path, value)) = pending {
+            self.callbacks.fire(&path, &value, &mut self.reported);
+        }
+    }
+
+    /// Done with the innermost collection, maybe because we halted or failed inside it.
+    pub(crate) fn leave_collection(&mut self) {
+        let Some(collection) = self.open_collections.pop() else {
+            error!("state: leave collection without entering it");
+            return;
+        };
+        let existing = self
+            .n_final_elements
+            .iter()
+            .position(|(path, _)| path.0 == self.path);
+        match (existing, collection.has_ended) {
+            (Some(i), true) => {
+                self.n_final_elements.remove(i);
+            }
+            (Some(i), false) => {
+                let n = &mut self.n_final_elements[i].1;
+                *n = (*n).max(collection.n_final_elements);
+            }
+            (None, true) => {}
+            (None, false) => self
+                .n_final_elements
+                .push((Path(self.path.clone()), collection.n_final_elements)),
+        }
+    }
+
     pub(crate) fn intervention_is_empty(&self) -> bool {
         self.intervention_active.is_none()
     }
@@ -173,6 +489,7 @@ impl<Extra: ExtraOptions> AttemptState<Extra> {
     /// If no intervention is active yet, then set a reason for intervention,
     /// and remember a potential better halting point for next attempt.
     pub(crate) fn activate_intervention(&mut self, reason: InterventionReason) {
+        self.note_truncation_event();
         if self.intervention_active.is_none() {
             let candidate_halting_point_for_next_attempt = self.halting_point_stack.last().cloned();
 
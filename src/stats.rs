@@ -0,0 +1,102 @@
+use std::time::Duration;
+
+/// Numbers about one call to deserialize, see [`crate::Options::deserialize_seed_with_stats`].
+///
+/// This is useful for metrics, and to tune [`crate::Options::with_max_n_backtracks`].
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct ParseStats {
+    /// Every attempt, in order. Only the last attempt can have succeeded.
+    pub attempts: Vec<AttemptStats>,
+    /// Total time spent, including all attempts.
+    pub elapsed: Duration,
+}
+
+/// Numbers about one attempt to deserialize.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct AttemptStats {
+    /// The number of the halting point where we planned to stop this attempt, counting
+    /// from 0 like [`Self::n_halting_points`]. This is `None` for the first attempt.
+    pub planned_halting_point: Option<u64>,
+    /// How many halting points this attempt reached, including the one it stopped at.
+    pub n_halting_points: u64,
+    /// Which fallbacks produced a value, in order, and for which kind of data. Each
+    /// is named after the method of [`serde::Deserializer`], such as
+    /// `"deserialize_struct"`.
+    pub fallbacks: Vec<&'static str>,
+    /// Whether this attempt produced the value.
+    pub succeeded: bool,
+}
+
+impl ParseStats {
+    pub fn n_attempts(&self) -> usize {
+        self.attempts.len()
+    }
+
+    /// Like [`crate::IncrementalDeserializer::last_n_backtracks`].
+    pub fn n_backtracks(&self) -> usize {
+        self.attempts.len().saturating_sub(1)
+    }
+
+    /// Which fallbacks produced a value during any attempt.
+    pub fn fallbacks(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.attempts
+            .iter()
+            .flat_map(|attempt| attempt.fallbacks.iter().copied())
+    }
+}
+
+#[cfg(all(test, feature = "rand", feature = "serde_json"))]
+mod test {
+    use std::borrow::Cow;
+
+    use crate::Options;
+
+    #[test]
+    fn test_stats() {
+        let options = Options::new_json();
+        let prepared = options.prepare_str_for_borrowed_deserialization(Cow::Borrowed("[1, 2"));
+        let (result, stats) = options
+            .deserialize_source_with_stats::<Vec<u32>, _>(crate::source::JsonStr(&prepared.0));
+        assert_eq!(result.unwrap(), [1, 2]);
+
+        assert_eq!(stats.n_attempts(), 2);
+        assert_eq!(stats.n_backtracks(), 1);
+        assert!(!stats.attempts[0].succeeded);
+        assert_eq!(stats.attempts[0].planned_halting_point, None);
+        assert!(stats.attempts[1].succeeded);
+        assert!(stats.attempts[1].planned_halting_point.is_some());
+    }
+
+    #[test]
+    fn test_stats_complete() {
+        let options = Options::new_json();
+        let prepared = options.prepare_str_for_borrowed_deserialization(Cow::Borrowed("[1, 2]"));
+        let (result, stats) = options
+            .deserialize_source_with_stats::<Vec<u32>, _>(crate::source::JsonStr(&prepared.0));
+        assert_eq!(result.unwrap(), [1, 2]);
+
+        assert_eq!(stats.n_attempts(), 1);
+        assert_eq!(stats.fallbacks().count(), 0);
+    }
+
+    #[test]
+    fn test_stats_fallbacks() {
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        struct Struct {
+            a: u32,
+            b: Option<u32>,
+        }
+
+        let options = Options::new_json();
+        let prepared = options.prepare_str_for_borrowed_deserialization(Cow::Borrowed("{\"a\": 1"));
+        let (result, stats) =
+            options.deserialize_source_with_stats::<Struct, _>(crate::source::JsonStr(&prepared.0));
+        assert_eq!(result.unwrap(), Struct { a: 1, b: None });
+        assert_eq!(
+            stats.fallbacks().collect::<Vec<_>>(),
+            ["deserialize_struct"]
+        );
+    }
+}
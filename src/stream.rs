@@ -0,0 +1,217 @@
+//! Asynchronous adapters that turn incoming input into a [`Stream`] of partial values.
+//!
+//! Create these with [`IncrementalDeserializer::into_stream`] or
+//! [`IncrementalDeserializer::into_stream_from_reader`].
+
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+
+use futures_core::Stream;
+use futures_io::AsyncRead;
+use serde::de::DeserializeOwned;
+
+use crate::options_impl::ExtraOptions;
+use crate::source::SliceFormat;
+use crate::{Error, IncrementalDeserializer};
+
+/// How many bytes we try to read at once in [`ReaderPartialValues`].
+const READ_CHUNK_SIZE: usize = 8192;
+
+impl<T, F, Extra> IncrementalDeserializer<T, F, Extra>
+where
+    T: DeserializeOwned,
+    F: SliceFormat,
+    Extra: ExtraOptions + Clone,
+{
+    /// Push every chunk of a stream, and yield the partial value after each chunk.
+    ///
+    /// When the chunks run out, we yield one more value from
+    /// [`IncrementalDeserializer::finish`].
+    ///
+    /// ```
+    /// use deser_incomplete::IncrementalDeserializer;
+    /// use futures::StreamExt as _;
+    ///
+    /// let chunks = futures::stream::iter(["[1, ", "2", "0, 3]"]);
+    /// let incremental: IncrementalDeserializer<Vec<u32>, _, _> =
+    ///     IncrementalDeserializer::new_json();
+    ///
+    /// let values: Vec<Vec<u32>> = futures::executor::block_on(
+    ///     incremental.into_stream(chunks).map(Result::unwrap).collect(),
+    /// );
+    /// assert_eq!(values, [vec![1], vec![1, 2], vec![1, 20, 3], vec![1, 20, 3]]);
+    /// ```
+    pub fn into_stream<S>(self, chunks: S) -> PartialValues<S, T, F, Extra>
+    where
+        S: Stream + Unpin,
+        S::Item: AsRef<[u8]>,
+    {
+        PartialValues {
+            state: Some((chunks, self)),
+        }
+    }
+
+    /// Read all input from `reader`, and yield the partial value after each read.
+    ///
+    /// When the reader is at the end, we yield one more value from
+    /// [`IncrementalDeserializer::finish`]. If reading fails, we yield the error
+    /// and then stop, except that we read again after [`std::io::ErrorKind::Interrupted`].
+    pub fn into_stream_from_reader<R>(self, reader: R) -> ReaderPartialValues<R, T, F, Extra>
+    where
+        R: AsyncRead + Unpin,
+    {
+        ReaderPartialValues {
+            state: Some((reader, self)),
+            buffer: vec![0; READ_CHUNK_SIZE].into_boxed_slice(),
+        }
+    }
+}
+
+/// Stream of partial values, see [`IncrementalDeserializer::into_stream`].
+pub struct PartialValues<S, T, F, Extra: ExtraOptions> {
+    /// `None` once we have yielded the final value.
+    state: Option<(S, IncrementalDeserializer<T, F, Extra>)>,
+}
+
+// We never pin any fields except through `S: Unpin`.
+impl<S: Unpin, T, F, Extra: ExtraOptions> Unpin for PartialValues<S, T, F, Extra> {}
+
+impl<S, T, F, Extra> Stream for PartialValues<S, T, F, Extra>
+where
+    S: Stream + Unpin,
+    S::Item: AsRef<[u8]>,
+    T: DeserializeOwned,
+    F: SliceFormat,
+    Extra: ExtraOptions + Clone,
+{
+    type Item = Result<T, Error<F::Error>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let Some((chunks, incremental)) = this.state.as_mut() else {
+            return Poll::Ready(None);
+        };
+
+        match ready!(Pin::new(chunks).poll_next(cx)) {
+            Some(chunk) => Poll::Ready(Some(incremental.push(chunk.as_ref()))),
+            None => {
+                let (_, incremental) = this.state.take().expect("state was just matched");
+                Poll::Ready(Some(incremental.finish()))
+            }
+        }
+    }
+}
+
+/// Stream of partial values, see [`IncrementalDeserializer::into_stream_from_reader`].
+pub struct ReaderPartialValues<R, T, F, Extra: ExtraOptions> {
+    /// `None` once we have yielded the final value or an error.
+    state: Option<(R, IncrementalDeserializer<T, F, Extra>)>,
+    buffer: Box<[u8]>,
+}
+
+// We never pin any fields except through `R: Unpin`.
+impl<R: Unpin, T, F, Extra: ExtraOptions> Unpin for ReaderPartialValues<R, T, F, Extra> {}
+
+impl<R, T, F, Extra> Stream for ReaderPartialValues<R, T, F, Extra>
+where
+    R: AsyncRead + Unpin,
+    T: DeserializeOwned,
+    F: SliceFormat,
+    Extra: ExtraOptions + Clone,
+{
+    type Item = Result<T, Error<F::Error>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let Some((reader, incremental)) = this.state.as_mut() else {
+            return Poll::Ready(None);
+        };
+
+        loop {
+            match ready!(Pin::new(&mut *reader).poll_read(cx, &mut this.buffer)) {
+                Ok(0) => {
+                    let (_, incremental) = this.state.take().expect("state was just matched");
+                    return Poll::Ready(Some(incremental.finish()));
+                }
+                Ok(n) => return Poll::Ready(Some(incremental.push(&this.buffer[..n]))),
+                // Nothing went wrong, the read should simply be tried again.
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(err) => {
+                    this.state = None;
+                    return Poll::Ready(Some(Err(err.into())));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "rand", feature = "serde_json"))]
+mod test {
+    use futures::StreamExt as _;
+
+    use super::*;
+
+    #[test]
+    fn test_reader() {
+        let reader = futures::io::Cursor::new(br#"{"a": [1, 2"#.to_vec());
+        let incremental: IncrementalDeserializer<serde_json::Value, _, _> =
+            IncrementalDeserializer::new_json();
+
+        let values: Vec<_> = futures::executor::block_on(
+            incremental
+                .into_stream_from_reader(reader)
+                .map(Result::unwrap)
+                .collect(),
+        );
+        assert_eq!(
+            values,
+            [
+                serde_json::json!({"a": [1, 2]}),
+                serde_json::json!({"a": [1, 2]}),
+            ]
+        );
+    }
+
+    /// Returns [`std::io::ErrorKind::Interrupted`] before every chunk.
+    struct InterruptedReader {
+        chunks: Vec<&'static [u8]>,
+        interrupt: bool,
+    }
+
+    impl AsyncRead for InterruptedReader {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+            buf: &mut [u8],
+        ) -> Poll<std::io::Result<usize>> {
+            self.interrupt = !self.interrupt;
+            if self.interrupt {
+                return Poll::Ready(Err(std::io::ErrorKind::Interrupted.into()));
+            }
+            if self.chunks.is_empty() {
+                return Poll::Ready(Ok(0));
+            }
+            let chunk = self.chunks.remove(0);
+            buf[..chunk.len()].copy_from_slice(chunk);
+            Poll::Ready(Ok(chunk.len()))
+        }
+    }
+
+    #[test]
+    fn test_reader_interrupted() {
+        let reader = InterruptedReader {
+            chunks: vec![b"[1, ", b"2"],
+            interrupt: false,
+        };
+        let incremental: IncrementalDeserializer<Vec<u32>, _, _> =
+            IncrementalDeserializer::new_json();
+
+        let values: Vec<_> = futures::executor::block_on(
+            incremental
+                .into_stream_from_reader(reader)
+                .map(Result::unwrap)
+                .collect(),
+        );
+        assert_eq!(values, [vec![1], vec![1, 2], vec![1, 2]]);
+    }
+}
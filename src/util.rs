@@ -16,11 +16,43 @@ pub(crate) fn erase_error_ref<T, E: std::error::Error>(
         .map(|x| -> &(dyn std::error::Error) { x })
 }
 
+/// Length of the longest prefix of `input` that does not stop in the middle
+/// of a UTF-8 encoded character.
+///
+/// Only the end of the input is inspected. Invalid UTF-8 elsewhere is left for the
+/// data format to complain about.
+pub(crate) fn utf8_complete_prefix_len(input: &[u8]) -> usize {
+    // A UTF-8 character is at most 4 bytes long, so we only have to look for the
+    // start of the last character in the last 4 bytes.
+    for start in (input.len().saturating_sub(4)..input.len()).rev() {
+        let byte = input[start];
+        let is_continuation_byte = byte & 0b1100_0000 == 0b1000_0000;
+        if is_continuation_byte {
+            continue;
+        }
+
+        let char_len = match byte {
+            0b1100_0000..=0b1101_1111 => 2,
+            0b1110_0000..=0b1110_1111 => 3,
+            0b1111_0000..=0b1111_0111 => 4,
+            _ => 1,
+        };
+        return if input.len() - start < char_len {
+            start
+        } else {
+            input.len()
+        };
+    }
+
+    input.len()
+}
+
 /// Correspond to methods and arguments of [`serde::Deserializer`].
 ///
-/// Not public interface in the foreseeable future.
-#[derive(Clone, Copy, Debug)]
-pub(crate) enum DeserializeKind {
+/// This is likely to change, so it is only exported from [`crate::unstable`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DeserializeKind {
     Any,
     Bool,
     I8,
@@ -68,3 +100,42 @@ pub(crate) enum DeserializeKind {
     Identifier,
     IgnoredAny,
 }
+
+impl DeserializeKind {
+    /// The name of the corresponding method of [`serde::Deserializer`].
+    pub(crate) fn method_name(&self) -> &'static str {
+        match self {
+            DeserializeKind::Any => "deserialize_any",
+            DeserializeKind::Bool => "deserialize_bool",
+            DeserializeKind::I8 => "deserialize_i8",
+            DeserializeKind::I16 => "deserialize_i16",
+            DeserializeKind::I32 => "deserialize_i32",
+            DeserializeKind::I64 => "deserialize_i64",
+            DeserializeKind::I128 => "deserialize_i128",
+            DeserializeKind::U8 => "deserialize_u8",
+            DeserializeKind::U16 => "deserialize_u16",
+            DeserializeKind::U32 => "deserialize_u32",
+            DeserializeKind::U64 => "deserialize_u64",
+            DeserializeKind::U128 => "deserialize_u128",
+            DeserializeKind::F32 => "deserialize_f32",
+            DeserializeKind::F64 => "deserialize_f64",
+            DeserializeKind::Char => "deserialize_char",
+            DeserializeKind::Str => "deserialize_str",
+            DeserializeKind::String => "deserialize_string",
+            DeserializeKind::Bytes => "deserialize_bytes",
+            DeserializeKind::ByteBuf => "deserialize_byte_buf",
+            DeserializeKind::Option => "deserialize_option",
+            DeserializeKind::Unit => "deserialize_unit",
+            DeserializeKind::UnitStruct { .. } => "deserialize_unit_struct",
+            DeserializeKind::NewtypeStruct { .. } => "deserialize_newtype_struct",
+            DeserializeKind::Seq => "deserialize_seq",
+            DeserializeKind::Tuple { .. } => "deserialize_tuple",
+            DeserializeKind::TupleStruct { .. } => "deserialize_tuple_struct",
+            DeserializeKind::Map => "deserialize_map",
+            DeserializeKind::Struct { .. } => "deserialize_struct",
+            DeserializeKind::Enum { .. } => "deserialize_enum",
+            DeserializeKind::Identifier => "deserialize_identifier",
+            DeserializeKind::IgnoredAny => "deserialize_ignored_any",
+        }
+    }
+}
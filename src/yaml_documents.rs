@@ -0,0 +1,178 @@
+//! YAML streams of `---`-separated documents, where only the last document may be
+//! cut off.
+
+use std::borrow::Cow;
+
+use crate::options_impl::ExtraOptions;
+use crate::random_trailer::InputPlusTrailer;
+use crate::{Error, Options};
+
+/// The documents in a YAML stream.
+///
+/// Get this from [`crate::from_yaml_documents_str`] or
+/// [`Options::deserialize_from_yaml_documents_str`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct YamlDocuments<T> {
+    /// All documents, in order. Only the last one may be partial.
+    pub documents: Vec<T>,
+    /// Whether the last document was cut off.
+    pub last_is_partial: bool,
+}
+
+impl<T> YamlDocuments<T> {
+    /// Whether the last document looked complete.
+    pub fn is_complete(&self) -> bool {
+        !self.last_is_partial
+    }
+
+    pub fn into_documents(self) -> Vec<T> {
+        self.documents
+    }
+}
+
+impl<Extra: ExtraOptions> Options<Extra> {
+    /// Like [`crate::from_yaml_documents_str`], but with options. The options, including
+    /// the random trailer, only apply to the last document.
+    pub fn deserialize_from_yaml_documents_str<T>(
+        self,
+        yaml: &str,
+    ) -> Result<YamlDocuments<T>, Error<serde_yaml::Error>>
+    where
+        T: for<'de> serde::de::Deserialize<'de>,
+    {
+        // Every document before the last separator is complete.
+        let (complete, last) = yaml.split_at(start_of_last_document(yaml));
+
+        let mut documents = Vec::new();
+        // On empty input, `serde_yaml` would still give us one empty document.
+        if !is_empty_document(complete) {
+            for document in serde_yaml::Deserializer::from_str(complete) {
+                documents.push(T::deserialize(document).map_err(Error::from_de)?);
+            }
+        }
+
+        let mut last_is_partial = false;
+        if !is_empty_document(last) {
+            let InputPlusTrailer(prepared) =
+                self.prepare_str_for_borrowed_deserialization(Cow::Borrowed(last));
+            let outcome = self.deserialize_source_outcome(crate::source::YamlStr(&prepared))?;
+            last_is_partial = !outcome.is_complete();
+            documents.push(outcome.into_value());
+        }
+
+        Ok(YamlDocuments {
+            documents,
+            last_is_partial,
+        })
+    }
+}
+
+/// Where the last document starts: at its `---` marker, or just after a `...` marker.
+///
+/// These markers are only recognized at the start of a line, and then they can't be
+/// part of a scalar, so we don't need to parse the YAML.
+fn start_of_last_document(yaml: &str) -> usize {
+    let mut start = 0;
+    let mut line_start = 0;
+    for line in yaml.split_inclusive('\n') {
+        if is_marker(line, "---") {
+            start = line_start;
+        } else if is_marker(line, "...") {
+            start = line_start + line.len();
+        }
+        line_start += line.len();
+    }
+    start
+}
+
+fn is_marker(line: &str, marker: &str) -> bool {
+    line.strip_prefix(marker)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with(char::is_whitespace))
+}
+
+/// Whether this has nothing but a `---` marker, for instance at the end of a stream that
+/// ends in a separator.
+fn is_empty_document(document: &str) -> bool {
+    let without_marker = document.strip_prefix("---").unwrap_or(document);
+    without_marker.trim().is_empty()
+}
+
+#[cfg(test)]
+mod test {
+    use serde::Deserialize;
+
+    use super::YamlDocuments;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Manifest {
+        kind: String,
+        #[serde(default)]
+        name: String,
+    }
+
+    fn manifest(kind: &str, name: &str) -> Manifest {
+        Manifest {
+            kind: kind.into(),
+            name: name.into(),
+        }
+    }
+
+    #[test]
+    fn test_partial_last_document() {
+        let parsed: YamlDocuments<Manifest> = crate::from_yaml_documents_str(
+            "kind: Service\nname: web\n---\nkind: Deployment\n---\nkind: ConfigMap\nname: \"sett",
+        )
+        .unwrap();
+        assert_eq!(
+            parsed.documents,
+            [
+                manifest("Service", "web"),
+                manifest("Deployment", ""),
+                manifest("ConfigMap", "sett"),
+            ]
+        );
+        assert!(parsed.last_is_partial);
+    }
+
+    #[test]
+    fn test_complete() {
+        for input in [
+            "---\nkind: Service\n---\nkind: Deployment\n",
+            "kind: Service\n---\nkind: Deployment\n---\n",
+            "kind: Service\n...\nkind: Deployment\n",
+        ] {
+            let parsed: YamlDocuments<Manifest> = crate::from_yaml_documents_str(input).unwrap();
+            assert_eq!(
+                parsed.documents,
+                [manifest("Service", ""), manifest("Deployment", "")],
+                "{input:?}"
+            );
+            assert!(parsed.is_complete(), "{input:?}");
+        }
+
+        let parsed: YamlDocuments<Manifest> = crate::from_yaml_documents_str("").unwrap();
+        assert_eq!(parsed.documents, []);
+    }
+
+    #[test]
+    fn test_string_with_dashes() {
+        let parsed: YamlDocuments<Manifest> = crate::from_yaml_documents_str(
+            "kind: Service\nname: |\n  ---\n  x\n---\nkind: \"Deploy",
+        )
+        .unwrap();
+        assert_eq!(
+            parsed.documents,
+            [manifest("Service", "---\nx\n"), manifest("Deploy", "")]
+        );
+    }
+
+    #[test]
+    fn test_malformed_document_before_the_last() {
+        let err = crate::from_yaml_documents_str::<Manifest>(
+            "kind: Service\n---\nname: web\n---\nkind: Depl",
+        )
+        .unwrap_err();
+        assert!(err.as_deserializer_error().is_some(), "{err}");
+    }
+}
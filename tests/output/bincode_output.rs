@@ -0,0 +1,97 @@
+use std::fmt::Debug;
+
+use deser_incomplete::options::DefaultExtraOptions;
+use deser_incomplete::unstable::UnstableCustomBehavior;
+use deser_incomplete::Options;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use crate::common::{hex, run_on_prefixes};
+
+mod r#enum;
+mod record;
+mod seq;
+
+type BoxSerialize = Box<dyn erased_serde::Serialize>;
+
+/// Robustly deserialize all prefixes of `value`, encoded with [`bincode`].
+///
+/// The output is deduplicated -- only inputs are shown where the output changes.
+/// Inputs are shown in hex.
+///
+/// We also check if our wrapping of [`bincode`] on the whole input matches
+/// [`bincode`] without wrapping.
+#[allow(clippy::type_complexity)]
+pub(crate) fn run_bincode_modes_on_prefixes_and_format_outputs<
+    T: for<'de> Deserialize<'de> + Serialize + Debug + PartialEq + 'static,
+>(
+    modes: &[(&'static str, Options<DefaultExtraOptions>)],
+    value: &impl Serialize,
+) -> IndexMap<&'static str, IndexMap<String, BoxSerialize>> {
+    let full_input = bincode::serialize(value).unwrap();
+
+    let reference_official: Option<T> = bincode::deserialize(&full_input).ok();
+
+    modes
+        .iter()
+        .map(|(mode_desc, options)| {
+            let inputs_outputs = run_on_prefixes(&full_input, |inp| {
+                options
+                    .clone()
+                    .deserialize_from_bincode_slice::<T>(inp)
+                    .map_err(|err| err.to_string())
+            });
+
+            let last_output_matches_bincode_friendly =
+                match reference_official.as_ref().map(|reference| {
+                    Ok(reference)
+                        == inputs_outputs
+                            .last()
+                            .expect("every slice has a prefix")
+                            .1
+                            .as_ref()
+                }) {
+                    Some(true) => None,
+                    Some(false) => Some("no"),
+                    None => Some("bincode failed"),
+                };
+            let trailing_line = (last_output_matches_bincode_friendly).map(|friendly| {
+                (
+                    "final output matches bincode?".to_string(),
+                    Box::new(friendly) as BoxSerialize,
+                )
+            });
+
+            let lines: IndexMap<_, _> = inputs_outputs
+                .into_iter()
+                .map(|(input, output)| -> (_, Box<dyn erased_serde::Serialize>) {
+                    (hex(input), Box::new(output))
+                })
+                .chain(trailing_line)
+                .collect();
+
+            (*mode_desc, lines)
+        })
+        .collect()
+}
+
+fn default_modes() -> Vec<(&'static str, Options<DefaultExtraOptions>)> {
+    vec![
+        ("default behavior", Options::new_bincode()),
+        (
+            "default behavior, 0 backtracks",
+            Options::new_bincode().with_max_n_backtracks(Some(0)),
+        ),
+        (
+            "no fallbacks, 1 backtracks",
+            Options::new_bincode()
+                .custom_behavior(UnstableCustomBehavior::default().no_fallbacks())
+                .with_max_n_backtracks(Some(1)),
+        ),
+        ("no backtracking from Some to None", Options::new_no_nonce()),
+        (
+            "strict behavior",
+            Options::new_bincode().custom_behavior(UnstableCustomBehavior::strict()),
+        ),
+    ]
+}
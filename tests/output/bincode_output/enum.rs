@@ -0,0 +1,189 @@
+use serde::{Deserialize, Serialize};
+
+use super::{default_modes, run_bincode_modes_on_prefixes_and_format_outputs};
+
+#[test]
+fn test_enum_external() {
+    #[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+    enum External {
+        Unit,
+        Newtype(String),
+        Tuple(String, #[serde(default)] String),
+        Struct {
+            a: String,
+            #[serde(default)]
+            b: String,
+        },
+    }
+
+    insta::assert_ron_snapshot!(
+        run_bincode_modes_on_prefixes_and_format_outputs::<Vec<External>>(&default_modes(), &vec![
+            External::Unit,
+            External::Newtype("az".into()),
+            External::Tuple("az".into(), "by".into()),
+            External::Struct { a: "az".into(), b: "by".into() },
+        ]),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok([]),
+        "04 00 00 00 00 00 00 00 00 00 00 00": Ok([
+          Unit,
+        ]),
+        "04 00 00 00 00 00 00 00 00 00 00 00 01 00 00 00 02 00 00 00 00 00 00 00 61 7a": Ok([
+          Unit,
+          Newtype("az"),
+        ]),
+        "04 00 00 00 00 00 00 00 00 00 00 00 01 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 02 00 00 00 00 00 00 00 61 7a": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", ""),
+        ]),
+        "04 00 00 00 00 00 00 00 00 00 00 00 01 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 00 00 00 00 62 79": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+        ]),
+        "04 00 00 00 00 00 00 00 00 00 00 00 01 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 00 00 00 00 62 79 03 00 00 00 02 00 00 00 00 00 00 00 61 7a": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "",
+          ),
+        ]),
+        "04 00 00 00 00 00 00 00 00 00 00 00 01 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 00 00 00 00 62 79 03 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 00 00 00 00 62 79": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "by",
+          ),
+        ]),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok([]),
+        "04 00 00 00 00 00 00 00": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "04 00 00 00 00 00 00 00 00 00 00 00 01 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 00 00 00 00 62 79 03 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 00 00 00 00 62 79": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "by",
+          ),
+        ]),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "04 00 00 00 00 00 00 00": Ok([]),
+        "04 00 00 00 00 00 00 00 00 00 00 00": Ok([
+          Unit,
+        ]),
+        "04 00 00 00 00 00 00 00 00 00 00 00 01 00 00 00 02 00 00 00 00 00 00 00 61 7a": Ok([
+          Unit,
+          Newtype("az"),
+        ]),
+        "04 00 00 00 00 00 00 00 00 00 00 00 01 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "04 00 00 00 00 00 00 00 00 00 00 00 01 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 02 00 00 00 00 00 00 00 61 7a": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", ""),
+        ]),
+        "04 00 00 00 00 00 00 00 00 00 00 00 01 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 00 00 00 00 62 79": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+        ]),
+        "04 00 00 00 00 00 00 00 00 00 00 00 01 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 00 00 00 00 62 79 03 00 00 00": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "04 00 00 00 00 00 00 00 00 00 00 00 01 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 00 00 00 00 62 79 03 00 00 00 02 00 00 00 00 00 00 00 61 7a": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "",
+          ),
+        ]),
+        "04 00 00 00 00 00 00 00 00 00 00 00 01 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 00 00 00 00 62 79 03 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 00 00 00 00 62 79": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "by",
+          ),
+        ]),
+      },
+      "no backtracking from Some to None": {
+        "": Ok([]),
+        "04 00 00 00 00 00 00 00 00 00 00 00": Ok([
+          Unit,
+        ]),
+        "04 00 00 00 00 00 00 00 00 00 00 00 01 00 00 00 02 00 00 00 00 00 00 00 61 7a": Ok([
+          Unit,
+          Newtype("az"),
+        ]),
+        "04 00 00 00 00 00 00 00 00 00 00 00 01 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 02 00 00 00 00 00 00 00 61 7a": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", ""),
+        ]),
+        "04 00 00 00 00 00 00 00 00 00 00 00 01 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 00 00 00 00 62 79": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+        ]),
+        "04 00 00 00 00 00 00 00 00 00 00 00 01 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 00 00 00 00 62 79 03 00 00 00 02 00 00 00 00 00 00 00 61 7a": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "",
+          ),
+        ]),
+        "04 00 00 00 00 00 00 00 00 00 00 00 01 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 00 00 00 00 62 79 03 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 00 00 00 00 62 79": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "by",
+          ),
+        ]),
+      },
+      "strict behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "04 00 00 00 00 00 00 00 00 00 00 00 01 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 1 backtracks)"),
+        "04 00 00 00 00 00 00 00 00 00 00 00 01 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 02 00 00 00 00 00 00 00 61 7a": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", ""),
+        ]),
+        "04 00 00 00 00 00 00 00 00 00 00 00 01 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 00 00 00 00 62 79": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "04 00 00 00 00 00 00 00 00 00 00 00 01 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 00 00 00 00 62 79 03 00 00 00": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 1 backtracks)"),
+        "04 00 00 00 00 00 00 00 00 00 00 00 01 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 00 00 00 00 62 79 03 00 00 00 02 00 00 00 00 00 00 00 61 7a": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "",
+          ),
+        ]),
+        "04 00 00 00 00 00 00 00 00 00 00 00 01 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 00 00 00 00 62 79 03 00 00 00 02 00 00 00 00 00 00 00 61 7a 02 00 00 00 00 00 00 00 62 79": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "by",
+          ),
+        ]),
+      },
+    }
+    "###)
+}
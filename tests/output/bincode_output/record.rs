@@ -0,0 +1,277 @@
+use serde::{Deserialize, Serialize};
+
+use super::{default_modes, run_bincode_modes_on_prefixes_and_format_outputs};
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+enum Level {
+    Info,
+    Warn(u8),
+}
+
+/// Without `#[serde(default)]`, a record only shows up once it is complete.
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+struct Record {
+    timestamp: u32,
+    level: Level,
+    message: String,
+    battery: Option<u8>,
+}
+
+/// With `#[serde(default)]`, the last record shows up with the fields that are complete.
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+struct DefaultRecord {
+    timestamp: u32,
+    message: String,
+    samples: Vec<i16>,
+    battery: Option<u8>,
+}
+
+#[test]
+fn test_records() {
+    insta::assert_ron_snapshot!(
+        run_bincode_modes_on_prefixes_and_format_outputs::<Vec<Record>>(&default_modes(), &vec![
+            Record { timestamp: 1, level: Level::Info, message: "boot".into(), battery: Some(90) },
+            Record { timestamp: 2, level: Level::Warn(3), message: "low".into(), battery: None },
+        ]),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok([]),
+        "02 00 00 00 00 00 00 00 01 00 00 00 00 00 00 00 04 00 00 00 00 00 00 00 62 6f 6f 74": Ok([
+          Record(
+            timestamp: 1,
+            level: Info,
+            message: "boot",
+            battery: None,
+          ),
+        ]),
+        "02 00 00 00 00 00 00 00 01 00 00 00 00 00 00 00 04 00 00 00 00 00 00 00 62 6f 6f 74 01 5a": Ok([
+          Record(
+            timestamp: 1,
+            level: Info,
+            message: "boot",
+            battery: Some(90),
+          ),
+        ]),
+        "02 00 00 00 00 00 00 00 01 00 00 00 00 00 00 00 04 00 00 00 00 00 00 00 62 6f 6f 74 01 5a 02 00 00 00 01 00 00 00 03 03 00 00 00 00 00 00 00 6c 6f 77": Ok([
+          Record(
+            timestamp: 1,
+            level: Info,
+            message: "boot",
+            battery: Some(90),
+          ),
+          Record(
+            timestamp: 2,
+            level: Warn(3),
+            message: "low",
+            battery: None,
+          ),
+        ]),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok([]),
+        "02 00 00 00 00 00 00 00": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "02 00 00 00 00 00 00 00 01 00 00 00 00 00 00 00 04 00 00 00 00 00 00 00 62 6f 6f 74": Ok([
+          Record(
+            timestamp: 1,
+            level: Info,
+            message: "boot",
+            battery: None,
+          ),
+        ]),
+        "02 00 00 00 00 00 00 00 01 00 00 00 00 00 00 00 04 00 00 00 00 00 00 00 62 6f 6f 74 01": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "02 00 00 00 00 00 00 00 01 00 00 00 00 00 00 00 04 00 00 00 00 00 00 00 62 6f 6f 74 01 5a 02 00 00 00 01 00 00 00 03 03 00 00 00 00 00 00 00 6c 6f 77": Ok([
+          Record(
+            timestamp: 1,
+            level: Info,
+            message: "boot",
+            battery: Some(90),
+          ),
+          Record(
+            timestamp: 2,
+            level: Warn(3),
+            message: "low",
+            battery: None,
+          ),
+        ]),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "02 00 00 00 00 00 00 00": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "02 00 00 00 00 00 00 00 01 00 00 00 00 00 00 00 04 00 00 00 00 00 00 00 62 6f 6f 74 01 5a 02 00 00 00 01 00 00 00 03 03 00 00 00 00 00 00 00 6c 6f 77 00": Ok([
+          Record(
+            timestamp: 1,
+            level: Info,
+            message: "boot",
+            battery: Some(90),
+          ),
+          Record(
+            timestamp: 2,
+            level: Warn(3),
+            message: "low",
+            battery: None,
+          ),
+        ]),
+      },
+      "no backtracking from Some to None": {
+        "": Ok([]),
+        "02 00 00 00 00 00 00 00 01 00 00 00 00 00 00 00 04 00 00 00 00 00 00 00 62 6f 6f 74": Ok([
+          Record(
+            timestamp: 1,
+            level: Info,
+            message: "boot",
+            battery: None,
+          ),
+        ]),
+        "02 00 00 00 00 00 00 00 01 00 00 00 00 00 00 00 04 00 00 00 00 00 00 00 62 6f 6f 74 01": Ok([]),
+        "02 00 00 00 00 00 00 00 01 00 00 00 00 00 00 00 04 00 00 00 00 00 00 00 62 6f 6f 74 01 5a": Ok([
+          Record(
+            timestamp: 1,
+            level: Info,
+            message: "boot",
+            battery: Some(90),
+          ),
+        ]),
+        "02 00 00 00 00 00 00 00 01 00 00 00 00 00 00 00 04 00 00 00 00 00 00 00 62 6f 6f 74 01 5a 02 00 00 00 01 00 00 00 03 03 00 00 00 00 00 00 00 6c 6f 77": Ok([
+          Record(
+            timestamp: 1,
+            level: Info,
+            message: "boot",
+            battery: Some(90),
+          ),
+          Record(
+            timestamp: 2,
+            level: Warn(3),
+            message: "low",
+            battery: None,
+          ),
+        ]),
+      },
+      "strict behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "02 00 00 00 00 00 00 00 01 00 00 00 00 00 00 00 04 00 00 00 00 00 00 00 62 6f 6f 74 01 5a 02 00 00 00 01 00 00 00 03 03 00 00 00 00 00 00 00 6c 6f 77 00": Ok([
+          Record(
+            timestamp: 1,
+            level: Info,
+            message: "boot",
+            battery: Some(90),
+          ),
+          Record(
+            timestamp: 2,
+            level: Warn(3),
+            message: "low",
+            battery: None,
+          ),
+        ]),
+      },
+    }
+    "###)
+}
+
+#[test]
+fn test_records_with_default() {
+    insta::assert_ron_snapshot!(
+        run_bincode_modes_on_prefixes_and_format_outputs::<Vec<DefaultRecord>>(&default_modes()[..1], &vec![
+            DefaultRecord { timestamp: 1, message: "boot".into(), samples: vec![1, -2], battery: Some(90) },
+            DefaultRecord { timestamp: 2, message: "low".into(), samples: vec![7], battery: None },
+        ]),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok([]),
+        "02 00 00 00 00 00 00 00 01 00 00 00": Ok([
+          DefaultRecord(
+            timestamp: 1,
+            message: "",
+            samples: [],
+            battery: None,
+          ),
+        ]),
+        "02 00 00 00 00 00 00 00 01 00 00 00 04 00 00 00 00 00 00 00 62 6f 6f 74": Ok([
+          DefaultRecord(
+            timestamp: 1,
+            message: "boot",
+            samples: [],
+            battery: None,
+          ),
+        ]),
+        "02 00 00 00 00 00 00 00 01 00 00 00 04 00 00 00 00 00 00 00 62 6f 6f 74 02 00 00 00 00 00 00 00 01 00 fe ff": Ok([
+          DefaultRecord(
+            timestamp: 1,
+            message: "boot",
+            samples: [
+              1,
+              -2,
+            ],
+            battery: None,
+          ),
+        ]),
+        "02 00 00 00 00 00 00 00 01 00 00 00 04 00 00 00 00 00 00 00 62 6f 6f 74 02 00 00 00 00 00 00 00 01 00 fe ff 01 5a": Ok([
+          DefaultRecord(
+            timestamp: 1,
+            message: "boot",
+            samples: [
+              1,
+              -2,
+            ],
+            battery: Some(90),
+          ),
+        ]),
+        "02 00 00 00 00 00 00 00 01 00 00 00 04 00 00 00 00 00 00 00 62 6f 6f 74 02 00 00 00 00 00 00 00 01 00 fe ff 01 5a 02 00 00 00": Ok([
+          DefaultRecord(
+            timestamp: 1,
+            message: "boot",
+            samples: [
+              1,
+              -2,
+            ],
+            battery: Some(90),
+          ),
+          DefaultRecord(
+            timestamp: 2,
+            message: "",
+            samples: [],
+            battery: None,
+          ),
+        ]),
+        "02 00 00 00 00 00 00 00 01 00 00 00 04 00 00 00 00 00 00 00 62 6f 6f 74 02 00 00 00 00 00 00 00 01 00 fe ff 01 5a 02 00 00 00 03 00 00 00 00 00 00 00 6c 6f 77": Ok([
+          DefaultRecord(
+            timestamp: 1,
+            message: "boot",
+            samples: [
+              1,
+              -2,
+            ],
+            battery: Some(90),
+          ),
+          DefaultRecord(
+            timestamp: 2,
+            message: "low",
+            samples: [],
+            battery: None,
+          ),
+        ]),
+        "02 00 00 00 00 00 00 00 01 00 00 00 04 00 00 00 00 00 00 00 62 6f 6f 74 02 00 00 00 00 00 00 00 01 00 fe ff 01 5a 02 00 00 00 03 00 00 00 00 00 00 00 6c 6f 77 01 00 00 00 00 00 00 00 07 00": Ok([
+          DefaultRecord(
+            timestamp: 1,
+            message: "boot",
+            samples: [
+              1,
+              -2,
+            ],
+            battery: Some(90),
+          ),
+          DefaultRecord(
+            timestamp: 2,
+            message: "low",
+            samples: [
+              7,
+            ],
+            battery: None,
+          ),
+        ]),
+      },
+    }
+    "###)
+}
@@ -0,0 +1,330 @@
+use super::{default_modes, run_bincode_modes_on_prefixes_and_format_outputs};
+
+#[test]
+fn test_seq() {
+    insta::assert_ron_snapshot!(
+        run_bincode_modes_on_prefixes_and_format_outputs::<Vec<Vec<u16>>>(&default_modes(), &vec![vec![1u16, 300], vec![], vec![7]]),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok([]),
+        "03 00 00 00 00 00 00 00 02 00 00 00 00 00 00 00": Ok([
+          [],
+        ]),
+        "03 00 00 00 00 00 00 00 02 00 00 00 00 00 00 00 01 00": Ok([
+          [
+            1,
+          ],
+        ]),
+        "03 00 00 00 00 00 00 00 02 00 00 00 00 00 00 00 01 00 2c 01": Ok([
+          [
+            1,
+            300,
+          ],
+        ]),
+        "03 00 00 00 00 00 00 00 02 00 00 00 00 00 00 00 01 00 2c 01 00 00 00 00 00 00 00 00": Ok([
+          [
+            1,
+            300,
+          ],
+          [],
+        ]),
+        "03 00 00 00 00 00 00 00 02 00 00 00 00 00 00 00 01 00 2c 01 00 00 00 00 00 00 00 00 01 00 00 00 00 00 00 00": Ok([
+          [
+            1,
+            300,
+          ],
+          [],
+          [],
+        ]),
+        "03 00 00 00 00 00 00 00 02 00 00 00 00 00 00 00 01 00 2c 01 00 00 00 00 00 00 00 00 01 00 00 00 00 00 00 00 07 00": Ok([
+          [
+            1,
+            300,
+          ],
+          [],
+          [
+            7,
+          ],
+        ]),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok([]),
+        "03 00 00 00 00 00 00 00": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "03 00 00 00 00 00 00 00 02 00 00 00 00 00 00 00 01 00 2c 01 00 00 00 00 00 00 00 00 01 00 00 00 00 00 00 00 07 00": Ok([
+          [
+            1,
+            300,
+          ],
+          [],
+          [
+            7,
+          ],
+        ]),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "03 00 00 00 00 00 00 00": Ok([]),
+        "03 00 00 00 00 00 00 00 02 00 00 00 00 00 00 00": Ok([
+          [],
+        ]),
+        "03 00 00 00 00 00 00 00 02 00 00 00 00 00 00 00 01 00": Ok([
+          [
+            1,
+          ],
+        ]),
+        "03 00 00 00 00 00 00 00 02 00 00 00 00 00 00 00 01 00 2c 01": Ok([
+          [
+            1,
+            300,
+          ],
+        ]),
+        "03 00 00 00 00 00 00 00 02 00 00 00 00 00 00 00 01 00 2c 01 00 00 00 00 00 00 00 00": Ok([
+          [
+            1,
+            300,
+          ],
+          [],
+        ]),
+        "03 00 00 00 00 00 00 00 02 00 00 00 00 00 00 00 01 00 2c 01 00 00 00 00 00 00 00 00 01 00 00 00 00 00 00 00": Ok([
+          [
+            1,
+            300,
+          ],
+          [],
+          [],
+        ]),
+        "03 00 00 00 00 00 00 00 02 00 00 00 00 00 00 00 01 00 2c 01 00 00 00 00 00 00 00 00 01 00 00 00 00 00 00 00 07 00": Ok([
+          [
+            1,
+            300,
+          ],
+          [],
+          [
+            7,
+          ],
+        ]),
+      },
+      "no backtracking from Some to None": {
+        "": Ok([]),
+        "03 00 00 00 00 00 00 00 02 00 00 00 00 00 00 00": Ok([
+          [],
+        ]),
+        "03 00 00 00 00 00 00 00 02 00 00 00 00 00 00 00 01 00": Ok([
+          [
+            1,
+          ],
+        ]),
+        "03 00 00 00 00 00 00 00 02 00 00 00 00 00 00 00 01 00 2c 01": Ok([
+          [
+            1,
+            300,
+          ],
+        ]),
+        "03 00 00 00 00 00 00 00 02 00 00 00 00 00 00 00 01 00 2c 01 00 00 00 00 00 00 00 00": Ok([
+          [
+            1,
+            300,
+          ],
+          [],
+        ]),
+        "03 00 00 00 00 00 00 00 02 00 00 00 00 00 00 00 01 00 2c 01 00 00 00 00 00 00 00 00 01 00 00 00 00 00 00 00": Ok([
+          [
+            1,
+            300,
+          ],
+          [],
+          [],
+        ]),
+        "03 00 00 00 00 00 00 00 02 00 00 00 00 00 00 00 01 00 2c 01 00 00 00 00 00 00 00 00 01 00 00 00 00 00 00 00 07 00": Ok([
+          [
+            1,
+            300,
+          ],
+          [],
+          [
+            7,
+          ],
+        ]),
+      },
+      "strict behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "03 00 00 00 00 00 00 00 02 00 00 00 00 00 00 00 01 00 2c 01 00 00 00 00 00 00 00 00 01 00 00 00 00 00 00 00 07 00": Ok([
+          [
+            1,
+            300,
+          ],
+          [],
+          [
+            7,
+          ],
+        ]),
+      },
+    }
+    "###)
+}
+
+#[test]
+fn test_option() {
+    insta::assert_ron_snapshot!(
+        run_bincode_modes_on_prefixes_and_format_outputs::<Vec<Option<Vec<bool>>>>(&default_modes(), &vec![None, Some(vec![true, false]), Some(vec![])]),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok([]),
+        "03 00 00 00 00 00 00 00 00": Ok([
+          None,
+        ]),
+        "03 00 00 00 00 00 00 00 00 01": Ok([
+          None,
+          None,
+        ]),
+        "03 00 00 00 00 00 00 00 00 01 02 00 00 00 00 00 00 00": Ok([
+          None,
+          Some([]),
+        ]),
+        "03 00 00 00 00 00 00 00 00 01 02 00 00 00 00 00 00 00 01": Ok([
+          None,
+          Some([
+            true,
+          ]),
+        ]),
+        "03 00 00 00 00 00 00 00 00 01 02 00 00 00 00 00 00 00 01 00": Ok([
+          None,
+          Some([
+            true,
+            false,
+          ]),
+        ]),
+        "03 00 00 00 00 00 00 00 00 01 02 00 00 00 00 00 00 00 01 00 01": Ok([
+          None,
+          Some([
+            true,
+            false,
+          ]),
+          None,
+        ]),
+        "03 00 00 00 00 00 00 00 00 01 02 00 00 00 00 00 00 00 01 00 01 00 00 00 00 00 00 00 00": Ok([
+          None,
+          Some([
+            true,
+            false,
+          ]),
+          Some([]),
+        ]),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok([]),
+        "03 00 00 00 00 00 00 00 00": Ok([
+          None,
+        ]),
+        "03 00 00 00 00 00 00 00 00 01": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "03 00 00 00 00 00 00 00 00 01 02 00 00 00 00 00 00 00 01 00": Ok([
+          None,
+          Some([
+            true,
+            false,
+          ]),
+        ]),
+        "03 00 00 00 00 00 00 00 00 01 02 00 00 00 00 00 00 00 01 00 01": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "03 00 00 00 00 00 00 00 00 01 02 00 00 00 00 00 00 00 01 00 01 00 00 00 00 00 00 00 00": Ok([
+          None,
+          Some([
+            true,
+            false,
+          ]),
+          Some([]),
+        ]),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "03 00 00 00 00 00 00 00": Ok([]),
+        "03 00 00 00 00 00 00 00 00": Ok([
+          None,
+        ]),
+        "03 00 00 00 00 00 00 00 00 01 02 00 00 00 00 00 00 00": Ok([
+          None,
+          Some([]),
+        ]),
+        "03 00 00 00 00 00 00 00 00 01 02 00 00 00 00 00 00 00 01": Ok([
+          None,
+          Some([
+            true,
+          ]),
+        ]),
+        "03 00 00 00 00 00 00 00 00 01 02 00 00 00 00 00 00 00 01 00": Ok([
+          None,
+          Some([
+            true,
+            false,
+          ]),
+        ]),
+        "03 00 00 00 00 00 00 00 00 01 02 00 00 00 00 00 00 00 01 00 01 00 00 00 00 00 00 00 00": Ok([
+          None,
+          Some([
+            true,
+            false,
+          ]),
+          Some([]),
+        ]),
+      },
+      "no backtracking from Some to None": {
+        "": Ok([]),
+        "03 00 00 00 00 00 00 00": Ok([
+          None,
+        ]),
+        "03 00 00 00 00 00 00 00 00": Ok([
+          None,
+          None,
+        ]),
+        "03 00 00 00 00 00 00 00 00 01": Ok([
+          None,
+        ]),
+        "03 00 00 00 00 00 00 00 00 01 02 00 00 00 00 00 00 00": Ok([
+          None,
+          Some([]),
+        ]),
+        "03 00 00 00 00 00 00 00 00 01 02 00 00 00 00 00 00 00 01": Ok([
+          None,
+          Some([
+            true,
+          ]),
+        ]),
+        "03 00 00 00 00 00 00 00 00 01 02 00 00 00 00 00 00 00 01 00": Ok([
+          None,
+          Some([
+            true,
+            false,
+          ]),
+          None,
+        ]),
+        "03 00 00 00 00 00 00 00 00 01 02 00 00 00 00 00 00 00 01 00 01": Ok([
+          None,
+          Some([
+            true,
+            false,
+          ]),
+        ]),
+        "03 00 00 00 00 00 00 00 00 01 02 00 00 00 00 00 00 00 01 00 01 00 00 00 00 00 00 00 00": Ok([
+          None,
+          Some([
+            true,
+            false,
+          ]),
+          Some([]),
+        ]),
+      },
+      "strict behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "03 00 00 00 00 00 00 00 00 01 02 00 00 00 00 00 00 00 01 00 01 00 00 00 00 00 00 00 00": Ok([
+          None,
+          Some([
+            true,
+            false,
+          ]),
+          Some([]),
+        ]),
+      },
+    }
+    "###)
+}
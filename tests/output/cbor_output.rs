@@ -0,0 +1,158 @@
+use std::fmt::Debug;
+
+use deser_incomplete::options::DefaultExtraOptions;
+use deser_incomplete::unstable::UnstableCustomBehavior;
+use deser_incomplete::Options;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_cbor::Value;
+
+use crate::common::{hex, run_on_prefixes};
+
+mod r#enum;
+mod map;
+mod seq;
+mod string;
+mod r#struct;
+
+type BoxSerialize = Box<dyn erased_serde::Serialize>;
+
+/// Robustly deserialize all prefixes of `value`, encoded as CBOR.
+///
+/// The output is deduplicated -- only inputs are shown where the output changes.
+/// Inputs are shown in hex.
+///
+/// We also check if our wrapping of [`serde_cbor`] on the whole input matches
+/// [`serde_cbor`] without wrapping, and we check the soundness and monotonicity
+/// criteria with [`check_sound_and_monotone`].
+#[allow(clippy::type_complexity)]
+pub(crate) fn run_cbor_modes_on_prefixes_and_format_outputs<
+    T: for<'de> Deserialize<'de> + Serialize + Debug + PartialEq + 'static,
+>(
+    modes: &[(&'static str, Options<DefaultExtraOptions>)],
+    value: &impl Serialize,
+) -> IndexMap<&'static str, IndexMap<String, BoxSerialize>> {
+    let full_input = serde_cbor::to_vec(value).unwrap();
+
+    check_sound_and_monotone(&full_input);
+
+    let reference_official: Option<T> = serde_cbor::from_slice(&full_input).ok();
+
+    modes
+        .iter()
+        .map(|(mode_desc, options)| {
+            let inputs_outputs = run_on_prefixes(&full_input, |inp| {
+                options
+                    .clone()
+                    .deserialize_from_cbor_slice::<T>(inp)
+                    .map_err(|err| err.to_string())
+            });
+
+            let last_output_matches_serde_cbor_friendly =
+                match reference_official.as_ref().map(|reference| {
+                    Ok(reference)
+                        == inputs_outputs
+                            .last()
+                            .expect("every slice has a prefix")
+                            .1
+                            .as_ref()
+                }) {
+                    Some(true) => None,
+                    Some(false) => Some("no"),
+                    None => Some("serde_cbor failed"),
+                };
+            let trailing_line = (last_output_matches_serde_cbor_friendly).map(|friendly| {
+                (
+                    "final output matches serde_cbor?".to_string(),
+                    Box::new(friendly) as BoxSerialize,
+                )
+            });
+
+            let lines: IndexMap<_, _> = inputs_outputs
+                .into_iter()
+                .map(|(input, output)| -> (_, Box<dyn erased_serde::Serialize>) {
+                    (hex(input), Box::new(output))
+                })
+                .chain(trailing_line)
+                .collect();
+
+            (*mode_desc, lines)
+        })
+        .collect()
+}
+
+/// Check the guiding principles from the README on every prefix, by deserializing
+/// into a schemaless [`Value`]:
+///
+/// 1. ("soundness") The output for a prefix contains no data that isn't in the full input.
+/// 2. ("monotone") The output for a longer prefix contains the output for a shorter one.
+fn check_sound_and_monotone(full_input: &[u8]) {
+    let full: Value = serde_cbor::from_slice(full_input).unwrap();
+
+    let mut previous: Option<Value> = None;
+    for end in 0..=full_input.len() {
+        let output = deser_incomplete::from_cbor_slice::<Value>(&full_input[..end]);
+        match (&previous, output) {
+            (_, Ok(output)) => {
+                assert!(
+                    is_part_of(&output, &full),
+                    "not sound at {}: {output:?} is not part of {full:?}",
+                    hex(&full_input[..end])
+                );
+                if let Some(previous) = &previous {
+                    assert!(
+                        is_part_of(previous, &output),
+                        "not monotone at {}: {previous:?} is not part of {output:?}",
+                        hex(&full_input[..end])
+                    );
+                }
+                previous = Some(output);
+            }
+            (None, Err(_)) => {}
+            (Some(previous), Err(err)) => panic!(
+                "not monotone at {}: {previous:?} became {err}",
+                hex(&full_input[..end])
+            ),
+        }
+    }
+
+    assert_eq!(previous, Some(full));
+}
+
+/// Whether `part` could be what we get from a prefix of `whole`.
+fn is_part_of(part: &Value, whole: &Value) -> bool {
+    match (part, whole) {
+        (Value::Array(part), Value::Array(whole)) => {
+            part.len() <= whole.len() && part.iter().zip(whole).all(|(p, w)| is_part_of(p, w))
+        }
+        (Value::Map(part), Value::Map(whole)) => part
+            .iter()
+            .all(|(key, p)| whole.get(key).is_some_and(|w| is_part_of(p, w))),
+        (Value::Text(part), Value::Text(whole)) => whole.starts_with(part.as_str()),
+        (Value::Bytes(part), Value::Bytes(whole)) => whole.starts_with(part),
+        (Value::Tag(part_tag, part), Value::Tag(whole_tag, whole)) => {
+            part_tag == whole_tag && is_part_of(part, whole)
+        }
+        (part, whole) => part == whole,
+    }
+}
+
+fn default_modes() -> Vec<(&'static str, Options<DefaultExtraOptions>)> {
+    vec![
+        ("default behavior", Options::new_no_nonce()),
+        (
+            "default behavior, 0 backtracks",
+            Options::new_no_nonce().with_max_n_backtracks(Some(0)),
+        ),
+        (
+            "no fallbacks, 1 backtracks",
+            Options::new_no_nonce()
+                .custom_behavior(UnstableCustomBehavior::default().no_fallbacks())
+                .with_max_n_backtracks(Some(1)),
+        ),
+        (
+            "strict behavior",
+            Options::new_no_nonce().custom_behavior(UnstableCustomBehavior::strict()),
+        ),
+    ]
+}
@@ -0,0 +1,136 @@
+use serde::{Deserialize, Serialize};
+
+use super::{default_modes, run_cbor_modes_on_prefixes_and_format_outputs};
+
+#[test]
+fn test_enum_external() {
+    #[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+    enum External {
+        Unit,
+        Newtype(String),
+        Tuple(String, #[serde(default)] String),
+        Struct {
+            a: String,
+            #[serde(default)]
+            b: String,
+        },
+    }
+
+    insta::assert_ron_snapshot!(
+        run_cbor_modes_on_prefixes_and_format_outputs::<Vec<External>>(&default_modes(), &vec![
+            External::Unit,
+            External::Newtype("az".into()),
+            External::Tuple("az".into(), "by".into()),
+            External::Struct { a: "az".into(), b: "by".into() },
+        ]),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok([]),
+        "84 64 55 6e 69 74": Ok([
+          Unit,
+        ]),
+        "84 64 55 6e 69 74 a1 67 4e 65 77 74 79 70 65 62 61 7a": Ok([
+          Unit,
+          Newtype("az"),
+        ]),
+        "84 64 55 6e 69 74 a1 67 4e 65 77 74 79 70 65 62 61 7a a1 65 54 75 70 6c 65 82 62 61 7a 62 62 79": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+        ]),
+        "84 64 55 6e 69 74 a1 67 4e 65 77 74 79 70 65 62 61 7a a1 65 54 75 70 6c 65 82 62 61 7a 62 62 79 a1 66 53 74 72 75 63 74 a2 61 61 62 61 7a": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "",
+          ),
+        ]),
+        "84 64 55 6e 69 74 a1 67 4e 65 77 74 79 70 65 62 61 7a a1 65 54 75 70 6c 65 82 62 61 7a 62 62 79 a1 66 53 74 72 75 63 74 a2 61 61 62 61 7a 61 62 62 62 79": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "by",
+          ),
+        ]),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok([]),
+        "84": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "84 64 55 6e 69 74 a1 67 4e 65 77 74 79 70 65 62 61 7a a1 65 54 75 70 6c 65 82 62 61 7a 62 62 79 a1 66 53 74 72 75 63 74 a2 61 61 62 61 7a 61 62 62 62 79": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "by",
+          ),
+        ]),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "84": Ok([]),
+        "84 64 55 6e 69 74": Ok([
+          Unit,
+        ]),
+        "84 64 55 6e 69 74 a1 67 4e 65 77 74 79 70 65 62 61 7a": Ok([
+          Unit,
+          Newtype("az"),
+        ]),
+        "84 64 55 6e 69 74 a1 67 4e 65 77 74 79 70 65 62 61 7a a1 65 54 75 70 6c 65 82": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "84 64 55 6e 69 74 a1 67 4e 65 77 74 79 70 65 62 61 7a a1 65 54 75 70 6c 65 82 62 61 7a 62 62 79": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+        ]),
+        "84 64 55 6e 69 74 a1 67 4e 65 77 74 79 70 65 62 61 7a a1 65 54 75 70 6c 65 82 62 61 7a 62 62 79 a1 66 53 74 72 75 63 74 a2": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "84 64 55 6e 69 74 a1 67 4e 65 77 74 79 70 65 62 61 7a a1 65 54 75 70 6c 65 82 62 61 7a 62 62 79 a1 66 53 74 72 75 63 74 a2 61 61 62 61 7a": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "",
+          ),
+        ]),
+        "84 64 55 6e 69 74 a1 67 4e 65 77 74 79 70 65 62 61 7a a1 65 54 75 70 6c 65 82 62 61 7a 62 62 79 a1 66 53 74 72 75 63 74 a2 61 61 62 61 7a 61 62 62 62 79": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "by",
+          ),
+        ]),
+      },
+      "strict behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "84 64 55 6e 69 74 a1 67 4e 65 77 74 79 70 65 62 61 7a a1 65 54 75 70 6c 65 82": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 1 backtracks)"),
+        "84 64 55 6e 69 74 a1 67 4e 65 77 74 79 70 65 62 61 7a a1 65 54 75 70 6c 65 82 62 61 7a 62 62 79": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "84 64 55 6e 69 74 a1 67 4e 65 77 74 79 70 65 62 61 7a a1 65 54 75 70 6c 65 82 62 61 7a 62 62 79 a1 66 53 74 72 75 63 74 a2": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 1 backtracks)"),
+        "84 64 55 6e 69 74 a1 67 4e 65 77 74 79 70 65 62 61 7a a1 65 54 75 70 6c 65 82 62 61 7a 62 62 79 a1 66 53 74 72 75 63 74 a2 61 61 62 61 7a": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "",
+          ),
+        ]),
+        "84 64 55 6e 69 74 a1 67 4e 65 77 74 79 70 65 62 61 7a a1 65 54 75 70 6c 65 82 62 61 7a 62 62 79 a1 66 53 74 72 75 63 74 a2 61 61 62 61 7a 61 62 62 62 79": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "by",
+          ),
+        ]),
+      },
+    }
+    "###)
+}
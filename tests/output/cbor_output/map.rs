@@ -0,0 +1,155 @@
+use indexmap::IndexMap;
+
+use super::{default_modes, run_cbor_modes_on_prefixes_and_format_outputs};
+
+#[test]
+fn test_map() {
+    let map: IndexMap<&str, Vec<bool>> = [("ab", vec![true]), ("cd", vec![false, true])].into();
+    insta::assert_ron_snapshot!(
+        run_cbor_modes_on_prefixes_and_format_outputs::<IndexMap<String, Vec<bool>>>(&default_modes(), &map),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok({}),
+        "a2 62 61 62": Ok({
+          "ab": [],
+        }),
+        "a2 62 61 62 81 f5": Ok({
+          "ab": [
+            true,
+          ],
+        }),
+        "a2 62 61 62 81 f5 62 63 64": Ok({
+          "ab": [
+            true,
+          ],
+          "cd": [],
+        }),
+        "a2 62 61 62 81 f5 62 63 64 82 f4": Ok({
+          "ab": [
+            true,
+          ],
+          "cd": [
+            false,
+          ],
+        }),
+        "a2 62 61 62 81 f5 62 63 64 82 f4 f5": Ok({
+          "ab": [
+            true,
+          ],
+          "cd": [
+            false,
+            true,
+          ],
+        }),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok({}),
+        "a2": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "a2 62 61 62 81 f5 62 63 64 82 f4 f5": Ok({
+          "ab": [
+            true,
+          ],
+          "cd": [
+            false,
+            true,
+          ],
+        }),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "a2": Ok({}),
+        "a2 62 61 62": Ok({
+          "ab": [],
+        }),
+        "a2 62 61 62 81 f5": Ok({
+          "ab": [
+            true,
+          ],
+        }),
+        "a2 62 61 62 81 f5 62 63 64": Ok({
+          "ab": [
+            true,
+          ],
+          "cd": [],
+        }),
+        "a2 62 61 62 81 f5 62 63 64 82 f4": Ok({
+          "ab": [
+            true,
+          ],
+          "cd": [
+            false,
+          ],
+        }),
+        "a2 62 61 62 81 f5 62 63 64 82 f4 f5": Ok({
+          "ab": [
+            true,
+          ],
+          "cd": [
+            false,
+            true,
+          ],
+        }),
+      },
+      "strict behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "a2 62 61 62 81 f5 62 63 64 82 f4 f5": Ok({
+          "ab": [
+            true,
+          ],
+          "cd": [
+            false,
+            true,
+          ],
+        }),
+      },
+    }
+    "###)
+}
+
+#[test]
+fn test_map_with_integer_keys() {
+    let map: IndexMap<u8, &str> = [(1, "a"), (2, "b")].into();
+    insta::assert_ron_snapshot!(
+        run_cbor_modes_on_prefixes_and_format_outputs::<IndexMap<u8, String>>(&default_modes(), &map),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok({}),
+        "a2 01 61 61": Ok({
+          1: "a",
+        }),
+        "a2 01 61 61 02 61 62": Ok({
+          1: "a",
+          2: "b",
+        }),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok({}),
+        "a2": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "a2 01 61 61 02 61 62": Ok({
+          1: "a",
+          2: "b",
+        }),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "a2": Ok({}),
+        "a2 01 61 61": Ok({
+          1: "a",
+        }),
+        "a2 01 61 61 02 61 62": Ok({
+          1: "a",
+          2: "b",
+        }),
+      },
+      "strict behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "a2 01 61 61 02 61 62": Ok({
+          1: "a",
+          2: "b",
+        }),
+      },
+    }
+    "###)
+}
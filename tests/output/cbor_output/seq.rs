@@ -0,0 +1,149 @@
+use super::{default_modes, run_cbor_modes_on_prefixes_and_format_outputs};
+
+#[test]
+fn test_seq() {
+    insta::assert_ron_snapshot!(
+        run_cbor_modes_on_prefixes_and_format_outputs::<Vec<Vec<u32>>>(&default_modes(), &vec![vec![1, 300], vec![], vec![70000]]),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok([]),
+        "83 82": Ok([
+          [],
+        ]),
+        "83 82 01": Ok([
+          [
+            1,
+          ],
+        ]),
+        "83 82 01 19 01 2c": Ok([
+          [
+            1,
+            300,
+          ],
+        ]),
+        "83 82 01 19 01 2c 80": Ok([
+          [
+            1,
+            300,
+          ],
+          [],
+        ]),
+        "83 82 01 19 01 2c 80 81": Ok([
+          [
+            1,
+            300,
+          ],
+          [],
+          [],
+        ]),
+        "83 82 01 19 01 2c 80 81 1a 00 01 11 70": Ok([
+          [
+            1,
+            300,
+          ],
+          [],
+          [
+            70000,
+          ],
+        ]),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok([]),
+        "83": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "83 82 01 19 01 2c 80 81 1a 00 01 11 70": Ok([
+          [
+            1,
+            300,
+          ],
+          [],
+          [
+            70000,
+          ],
+        ]),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "83": Ok([]),
+        "83 82": Ok([
+          [],
+        ]),
+        "83 82 01": Ok([
+          [
+            1,
+          ],
+        ]),
+        "83 82 01 19 01 2c": Ok([
+          [
+            1,
+            300,
+          ],
+        ]),
+        "83 82 01 19 01 2c 80": Ok([
+          [
+            1,
+            300,
+          ],
+          [],
+        ]),
+        "83 82 01 19 01 2c 80 81": Ok([
+          [
+            1,
+            300,
+          ],
+          [],
+          [],
+        ]),
+        "83 82 01 19 01 2c 80 81 1a 00 01 11 70": Ok([
+          [
+            1,
+            300,
+          ],
+          [],
+          [
+            70000,
+          ],
+        ]),
+      },
+      "strict behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "83 82 01 19 01 2c 80 81 1a 00 01 11 70": Ok([
+          [
+            1,
+            300,
+          ],
+          [],
+          [
+            70000,
+          ],
+        ]),
+      },
+    }
+    "###)
+}
+
+#[test]
+fn test_tuple() {
+    insta::assert_ron_snapshot!(
+        run_cbor_modes_on_prefixes_and_format_outputs::<(bool, Option<i8>, f32)>(&default_modes(), &(true, Some(-5i8), 1.5f32)),
+        @r###"
+    {
+      "default behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "83 f5 24 f9 3e 00": Ok((true, Some(-5), 1.5)),
+      },
+      "default behavior, 0 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "83 f5 24 f9 3e 00": Ok((true, Some(-5), 1.5)),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "83 f5 24 f9 3e 00": Ok((true, Some(-5), 1.5)),
+      },
+      "strict behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "83 f5 24 f9 3e 00": Ok((true, Some(-5), 1.5)),
+      },
+    }
+    "###)
+}
@@ -0,0 +1,59 @@
+use super::{default_modes, run_cbor_modes_on_prefixes_and_format_outputs};
+
+#[test]
+fn test_string() {
+    insta::assert_ron_snapshot!(
+        run_cbor_modes_on_prefixes_and_format_outputs::<Vec<String>>(&default_modes(), &vec!["ab", "", "é"]),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok([]),
+        "83 62 61 62": Ok([
+          "ab",
+        ]),
+        "83 62 61 62 60": Ok([
+          "ab",
+          "",
+        ]),
+        "83 62 61 62 60 62 c3 a9": Ok([
+          "ab",
+          "",
+          "é",
+        ]),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok([]),
+        "83": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "83 62 61 62 60 62 c3 a9": Ok([
+          "ab",
+          "",
+          "é",
+        ]),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "83": Ok([]),
+        "83 62 61 62": Ok([
+          "ab",
+        ]),
+        "83 62 61 62 60": Ok([
+          "ab",
+          "",
+        ]),
+        "83 62 61 62 60 62 c3 a9": Ok([
+          "ab",
+          "",
+          "é",
+        ]),
+      },
+      "strict behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "83 62 61 62 60 62 c3 a9": Ok([
+          "ab",
+          "",
+          "é",
+        ]),
+      },
+    }
+    "###)
+}
@@ -0,0 +1,264 @@
+use serde::{Deserialize, Serialize};
+
+use super::{default_modes, run_cbor_modes_on_prefixes_and_format_outputs};
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+struct Reading {
+    device: String,
+    #[serde(default)]
+    samples: Vec<u16>,
+    #[serde(default)]
+    battery: Option<u8>,
+}
+
+#[test]
+fn test_struct() {
+    insta::assert_ron_snapshot!(
+        run_cbor_modes_on_prefixes_and_format_outputs::<Vec<Reading>>(&default_modes(), &vec![
+            Reading { device: "a".into(), samples: vec![1, 2], battery: Some(90) },
+            Reading { device: "b".into(), samples: vec![3], battery: None },
+        ]),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok([]),
+        "82 a3 66 64 65 76 69 63 65 61 61": Ok([
+          Reading(
+            device: "a",
+            samples: [],
+            battery: None,
+          ),
+        ]),
+        "82 a3 66 64 65 76 69 63 65 61 61 67 73 61 6d 70 6c 65 73 82 01": Ok([
+          Reading(
+            device: "a",
+            samples: [
+              1,
+            ],
+            battery: None,
+          ),
+        ]),
+        "82 a3 66 64 65 76 69 63 65 61 61 67 73 61 6d 70 6c 65 73 82 01 02": Ok([
+          Reading(
+            device: "a",
+            samples: [
+              1,
+              2,
+            ],
+            battery: None,
+          ),
+        ]),
+        "82 a3 66 64 65 76 69 63 65 61 61 67 73 61 6d 70 6c 65 73 82 01 02 67 62 61 74 74 65 72 79 18 5a": Ok([
+          Reading(
+            device: "a",
+            samples: [
+              1,
+              2,
+            ],
+            battery: Some(90),
+          ),
+        ]),
+        "82 a3 66 64 65 76 69 63 65 61 61 67 73 61 6d 70 6c 65 73 82 01 02 67 62 61 74 74 65 72 79 18 5a a3 66 64 65 76 69 63 65 61 62": Ok([
+          Reading(
+            device: "a",
+            samples: [
+              1,
+              2,
+            ],
+            battery: Some(90),
+          ),
+          Reading(
+            device: "b",
+            samples: [],
+            battery: None,
+          ),
+        ]),
+        "82 a3 66 64 65 76 69 63 65 61 61 67 73 61 6d 70 6c 65 73 82 01 02 67 62 61 74 74 65 72 79 18 5a a3 66 64 65 76 69 63 65 61 62 67 73 61 6d 70 6c 65 73 81 03": Ok([
+          Reading(
+            device: "a",
+            samples: [
+              1,
+              2,
+            ],
+            battery: Some(90),
+          ),
+          Reading(
+            device: "b",
+            samples: [
+              3,
+            ],
+            battery: None,
+          ),
+        ]),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok([]),
+        "82": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "82 a3 66 64 65 76 69 63 65 61 61": Ok([
+          Reading(
+            device: "a",
+            samples: [],
+            battery: None,
+          ),
+        ]),
+        "82 a3 66 64 65 76 69 63 65 61 61 67 73 61 6d 70 6c 65 73": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "82 a3 66 64 65 76 69 63 65 61 61 67 73 61 6d 70 6c 65 73 82 01 02": Ok([
+          Reading(
+            device: "a",
+            samples: [
+              1,
+              2,
+            ],
+            battery: None,
+          ),
+        ]),
+        "82 a3 66 64 65 76 69 63 65 61 61 67 73 61 6d 70 6c 65 73 82 01 02 67 62 61 74 74 65 72 79": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "82 a3 66 64 65 76 69 63 65 61 61 67 73 61 6d 70 6c 65 73 82 01 02 67 62 61 74 74 65 72 79 18 5a a3 66 64 65 76 69 63 65 61 62": Ok([
+          Reading(
+            device: "a",
+            samples: [
+              1,
+              2,
+            ],
+            battery: Some(90),
+          ),
+          Reading(
+            device: "b",
+            samples: [],
+            battery: None,
+          ),
+        ]),
+        "82 a3 66 64 65 76 69 63 65 61 61 67 73 61 6d 70 6c 65 73 82 01 02 67 62 61 74 74 65 72 79 18 5a a3 66 64 65 76 69 63 65 61 62 67 73 61 6d 70 6c 65 73": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "82 a3 66 64 65 76 69 63 65 61 61 67 73 61 6d 70 6c 65 73 82 01 02 67 62 61 74 74 65 72 79 18 5a a3 66 64 65 76 69 63 65 61 62 67 73 61 6d 70 6c 65 73 81 03": Ok([
+          Reading(
+            device: "a",
+            samples: [
+              1,
+              2,
+            ],
+            battery: Some(90),
+          ),
+          Reading(
+            device: "b",
+            samples: [
+              3,
+            ],
+            battery: None,
+          ),
+        ]),
+        "82 a3 66 64 65 76 69 63 65 61 61 67 73 61 6d 70 6c 65 73 82 01 02 67 62 61 74 74 65 72 79 18 5a a3 66 64 65 76 69 63 65 61 62 67 73 61 6d 70 6c 65 73 81 03 67 62 61 74 74 65 72 79": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "82 a3 66 64 65 76 69 63 65 61 61 67 73 61 6d 70 6c 65 73 82 01 02 67 62 61 74 74 65 72 79 18 5a a3 66 64 65 76 69 63 65 61 62 67 73 61 6d 70 6c 65 73 81 03 67 62 61 74 74 65 72 79 f6": Ok([
+          Reading(
+            device: "a",
+            samples: [
+              1,
+              2,
+            ],
+            battery: Some(90),
+          ),
+          Reading(
+            device: "b",
+            samples: [
+              3,
+            ],
+            battery: None,
+          ),
+        ]),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "82": Ok([]),
+        "82 a3": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "82 a3 66 64 65 76 69 63 65 61 61": Ok([
+          Reading(
+            device: "a",
+            samples: [],
+            battery: None,
+          ),
+        ]),
+        "82 a3 66 64 65 76 69 63 65 61 61 67 73 61 6d 70 6c 65 73 82 01": Ok([
+          Reading(
+            device: "a",
+            samples: [
+              1,
+            ],
+            battery: None,
+          ),
+        ]),
+        "82 a3 66 64 65 76 69 63 65 61 61 67 73 61 6d 70 6c 65 73 82 01 02": Ok([
+          Reading(
+            device: "a",
+            samples: [
+              1,
+              2,
+            ],
+            battery: None,
+          ),
+        ]),
+        "82 a3 66 64 65 76 69 63 65 61 61 67 73 61 6d 70 6c 65 73 82 01 02 67 62 61 74 74 65 72 79 18 5a": Ok([
+          Reading(
+            device: "a",
+            samples: [
+              1,
+              2,
+            ],
+            battery: Some(90),
+          ),
+        ]),
+        "82 a3 66 64 65 76 69 63 65 61 61 67 73 61 6d 70 6c 65 73 82 01 02 67 62 61 74 74 65 72 79 18 5a a3": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "82 a3 66 64 65 76 69 63 65 61 61 67 73 61 6d 70 6c 65 73 82 01 02 67 62 61 74 74 65 72 79 18 5a a3 66 64 65 76 69 63 65 61 62": Ok([
+          Reading(
+            device: "a",
+            samples: [
+              1,
+              2,
+            ],
+            battery: Some(90),
+          ),
+          Reading(
+            device: "b",
+            samples: [],
+            battery: None,
+          ),
+        ]),
+        "82 a3 66 64 65 76 69 63 65 61 61 67 73 61 6d 70 6c 65 73 82 01 02 67 62 61 74 74 65 72 79 18 5a a3 66 64 65 76 69 63 65 61 62 67 73 61 6d 70 6c 65 73 81 03": Ok([
+          Reading(
+            device: "a",
+            samples: [
+              1,
+              2,
+            ],
+            battery: Some(90),
+          ),
+          Reading(
+            device: "b",
+            samples: [
+              3,
+            ],
+            battery: None,
+          ),
+        ]),
+      },
+      "strict behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "82 a3 66 64 65 76 69 63 65 61 61 67 73 61 6d 70 6c 65 73 82 01 02 67 62 61 74 74 65 72 79 18 5a a3 66 64 65 76 69 63 65 61 62 67 73 61 6d 70 6c 65 73 81 03 67 62 61 74 74 65 72 79 f6": Ok([
+          Reading(
+            device: "a",
+            samples: [
+              1,
+              2,
+            ],
+            battery: Some(90),
+          ),
+          Reading(
+            device: "b",
+            samples: [
+              3,
+            ],
+            battery: None,
+          ),
+        ]),
+      },
+    }
+    "###)
+}
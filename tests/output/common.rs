@@ -1,5 +1,5 @@
 use std::borrow::Cow;
-use std::fmt::Debug;
+use std::fmt::{Debug, Write as _};
 
 use indexmap::IndexMap;
 
@@ -13,7 +13,18 @@ use indexmap::IndexMap;
 pub(crate) fn run_on_prefixes_and_format_outputs<Output: Debug + PartialEq>(
     full_input: &[u8],
     f: impl Fn(&[u8]) -> Output,
-) -> IndexMap<Cow<str>, Output> {
+) -> IndexMap<Cow<'_, str>, Output> {
+    run_on_prefixes(full_input, f)
+        .into_iter()
+        .map(|(input, output)| (String::from_utf8_lossy(input), output))
+        .collect()
+}
+
+/// Like [`run_on_prefixes_and_format_outputs`], but leaves the inputs as bytes.
+pub(crate) fn run_on_prefixes<Output: Debug + PartialEq>(
+    full_input: &[u8],
+    f: impl Fn(&[u8]) -> Output,
+) -> Vec<(&[u8], Output)> {
     let mut outputs: Vec<(&[u8], Output)> = Vec::new();
 
     for end in 0..=full_input.len() {
@@ -31,7 +42,16 @@ pub(crate) fn run_on_prefixes_and_format_outputs<Output: Debug + PartialEq>(
     }
 
     outputs
-        .into_iter()
-        .map(|(input, output)| (String::from_utf8_lossy(input), output))
-        .collect()
+}
+
+/// Format bytes as hex, like `a2 61 78`.
+pub(crate) fn hex(bytes: &[u8]) -> String {
+    let mut hex = String::new();
+    for (i, byte) in bytes.iter().enumerate() {
+        if i > 0 {
+            hex.push(' ');
+        }
+        write!(hex, "{byte:02x}").unwrap();
+    }
+    hex
 }
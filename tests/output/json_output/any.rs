@@ -27,11 +27,6 @@ fn test_any() {
           3,
           4,
         ]),
-        "[true, false, 3, 4.": Ok([
-          true,
-          false,
-          3,
-        ]),
         "[true, false, 3, 4.5": Ok([
           true,
           false,
@@ -150,11 +145,6 @@ fn test_any() {
           3,
           4,
         ]),
-        "[true, false, 3, 4.": Ok([
-          true,
-          false,
-          3,
-        ]),
         "[true, false, 3, 4.5": Ok([
           true,
           false,
@@ -211,11 +201,6 @@ fn test_any() {
           3,
           4,
         ]),
-        "[true, false, 3, 4.": Ok([
-          true,
-          false,
-          3,
-        ]),
         "[true, false, 3, 4.5": Ok([
           true,
           false,
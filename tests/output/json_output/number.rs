@@ -1,3 +1,5 @@
+use deser_incomplete::Options;
+
 use super::{default_modes, run_json_modes_on_prefixes_and_format_outputs};
 
 #[test]
@@ -683,12 +685,6 @@ fn test_f32() {
           123456790.0,
           3.0,
         ]),
-        "[0, 1, 12345, 123456789, 3.": Ok([
-          0.0,
-          1.0,
-          12345.0,
-          123456790.0,
-        ]),
         "[0, 1, 12345, 123456789, 3.1": Ok([
           0.0,
           1.0,
@@ -711,13 +707,6 @@ fn test_f32() {
           3.14,
           3.0,
         ]),
-        "[0, 1, 12345, 123456789, 3.14, 3.": Ok([
-          0.0,
-          1.0,
-          12345.0,
-          123456790.0,
-          3.14,
-        ]),
         "[0, 1, 12345, 123456789, 3.14, 3.1": Ok([
           0.0,
           1.0,
@@ -854,29 +843,6 @@ fn test_f32() {
           -0.0,
           0.0,
         ]),
-        "[0, 1, 12345, 123456789, 3.14, 3.14159265358979323846264338327950288419716939937510, -1, -1000, -0, 0.": Ok([
-          0.0,
-          1.0,
-          12345.0,
-          123456790.0,
-          3.14,
-          3.1415927,
-          -1.0,
-          -1000.0,
-          -0.0,
-        ]),
-        "[0, 1, 12345, 123456789, 3.14, 3.14159265358979323846264338327950288419716939937510, -1, -1000, -0, 0.0": Ok([
-          0.0,
-          1.0,
-          12345.0,
-          123456790.0,
-          3.14,
-          3.1415927,
-          -1.0,
-          -1000.0,
-          -0.0,
-          0.0,
-        ]),
         "[0, 1, 12345, 123456789, 3.14, 3.14159265358979323846264338327950288419716939937510, -1, -1000, -0, 0.000, 4": Ok([
           0.0,
           1.0,
@@ -1350,12 +1316,6 @@ fn test_f32() {
           123456790.0,
           3.0,
         ]),
-        "[0, 1, 12345, 123456789, 3.": Ok([
-          0.0,
-          1.0,
-          12345.0,
-          123456790.0,
-        ]),
         "[0, 1, 12345, 123456789, 3.1": Ok([
           0.0,
           1.0,
@@ -1378,13 +1338,6 @@ fn test_f32() {
           3.14,
           3.0,
         ]),
-        "[0, 1, 12345, 123456789, 3.14, 3.": Ok([
-          0.0,
-          1.0,
-          12345.0,
-          123456790.0,
-          3.14,
-        ]),
         "[0, 1, 12345, 123456789, 3.14, 3.1": Ok([
           0.0,
           1.0,
@@ -1521,29 +1474,6 @@ fn test_f32() {
           -0.0,
           0.0,
         ]),
-        "[0, 1, 12345, 123456789, 3.14, 3.14159265358979323846264338327950288419716939937510, -1, -1000, -0, 0.": Ok([
-          0.0,
-          1.0,
-          12345.0,
-          123456790.0,
-          3.14,
-          3.1415927,
-          -1.0,
-          -1000.0,
-          -0.0,
-        ]),
-        "[0, 1, 12345, 123456789, 3.14, 3.14159265358979323846264338327950288419716939937510, -1, -1000, -0, 0.0": Ok([
-          0.0,
-          1.0,
-          12345.0,
-          123456790.0,
-          3.14,
-          3.1415927,
-          -1.0,
-          -1000.0,
-          -0.0,
-          0.0,
-        ]),
         "[0, 1, 12345, 123456789, 3.14, 3.14159265358979323846264338327950288419716939937510, -1, -1000, -0, 0.000, 4": Ok([
           0.0,
           1.0,
@@ -1666,12 +1596,6 @@ fn test_f32() {
           123456790.0,
           3.0,
         ]),
-        "[0, 1, 12345, 123456789, 3.": Ok([
-          0.0,
-          1.0,
-          12345.0,
-          123456790.0,
-        ]),
         "[0, 1, 12345, 123456789, 3.1": Ok([
           0.0,
           1.0,
@@ -1694,13 +1618,6 @@ fn test_f32() {
           3.14,
           3.0,
         ]),
-        "[0, 1, 12345, 123456789, 3.14, 3.": Ok([
-          0.0,
-          1.0,
-          12345.0,
-          123456790.0,
-          3.14,
-        ]),
         "[0, 1, 12345, 123456789, 3.14, 3.1": Ok([
           0.0,
           1.0,
@@ -1837,29 +1754,6 @@ fn test_f32() {
           -0.0,
           0.0,
         ]),
-        "[0, 1, 12345, 123456789, 3.14, 3.14159265358979323846264338327950288419716939937510, -1, -1000, -0, 0.": Ok([
-          0.0,
-          1.0,
-          12345.0,
-          123456790.0,
-          3.14,
-          3.1415927,
-          -1.0,
-          -1000.0,
-          -0.0,
-        ]),
-        "[0, 1, 12345, 123456789, 3.14, 3.14159265358979323846264338327950288419716939937510, -1, -1000, -0, 0.0": Ok([
-          0.0,
-          1.0,
-          12345.0,
-          123456790.0,
-          3.14,
-          3.1415927,
-          -1.0,
-          -1000.0,
-          -0.0,
-          0.0,
-        ]),
         "[0, 1, 12345, 123456789, 3.14, 3.14159265358979323846264338327950288419716939937510, -1, -1000, -0, 0.000, 4": Ok([
           0.0,
           1.0,
@@ -1907,3 +1801,250 @@ fn test_f32() {
     "###
     );
 }
+
+#[test]
+fn test_withhold_trailing_numbers() {
+    insta::assert_ron_snapshot!(
+        run_json_modes_on_prefixes_and_format_outputs::<Vec<f64>>(
+            &[(
+                "withhold trailing numbers",
+                Options::new_json().withhold_trailing_numbers()
+            )],
+            &"[12, -3.5e2]"
+        ),
+        @r###"
+    {
+      "withhold trailing numbers": {
+        "": Ok([]),
+        "[12,": Ok([
+          12.0,
+        ]),
+        "[12, -3.5e2]": Ok([
+          12.0,
+          -350.0,
+        ]),
+      },
+    }
+    "###);
+}
+
+#[test]
+fn test_f64_exponent() {
+    let mut modes = default_modes();
+    modes.push((
+        "withhold trailing numbers",
+        Options::new_json().withhold_trailing_numbers(),
+    ));
+    insta::assert_ron_snapshot!(
+        run_json_modes_on_prefixes_and_format_outputs::<Vec<f64>>(&modes, &"[-1.5e3, 2.25E-2, 4e+1]"),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok([]),
+        "[-1": Ok([
+          -1.0,
+        ]),
+        "[-1.5": Ok([
+          -1.5,
+        ]),
+        "[-1.5e3": Ok([
+          -1500.0,
+        ]),
+        "[-1.5e3, 2": Ok([
+          -1500.0,
+          2.0,
+        ]),
+        "[-1.5e3, 2.2": Ok([
+          -1500.0,
+          2.2,
+        ]),
+        "[-1.5e3, 2.25": Ok([
+          -1500.0,
+          2.25,
+        ]),
+        "[-1.5e3, 2.25E-2": Ok([
+          -1500.0,
+          0.0225,
+        ]),
+        "[-1.5e3, 2.25E-2, 4": Ok([
+          -1500.0,
+          0.0225,
+          4.0,
+        ]),
+        "[-1.5e3, 2.25E-2, 4e+1": Ok([
+          -1500.0,
+          0.0225,
+          40.0,
+        ]),
+      },
+      "default behavior except no randomized trailer": {
+        "": Ok([]),
+        "[-1": Ok([
+          -1.0,
+        ]),
+        "[-1.": Ok([]),
+        "[-1.5": Ok([
+          -1.5,
+        ]),
+        "[-1.5e": Ok([]),
+        "[-1.5e3": Ok([
+          -1500.0,
+        ]),
+        "[-1.5e3, 2": Ok([
+          -1500.0,
+          2.0,
+        ]),
+        "[-1.5e3, 2.": Ok([
+          -1500.0,
+        ]),
+        "[-1.5e3, 2.2": Ok([
+          -1500.0,
+          2.2,
+        ]),
+        "[-1.5e3, 2.25": Ok([
+          -1500.0,
+          2.25,
+        ]),
+        "[-1.5e3, 2.25E": Ok([
+          -1500.0,
+        ]),
+        "[-1.5e3, 2.25E-2": Ok([
+          -1500.0,
+          0.0225,
+        ]),
+        "[-1.5e3, 2.25E-2, 4": Ok([
+          -1500.0,
+          0.0225,
+          4.0,
+        ]),
+        "[-1.5e3, 2.25E-2, 4e": Ok([
+          -1500.0,
+          0.0225,
+        ]),
+        "[-1.5e3, 2.25E-2, 4e+1": Ok([
+          -1500.0,
+          0.0225,
+          40.0,
+        ]),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok([]),
+        "[": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[-1.5e3, 2.25E-2, 4e+1]": Ok([
+          -1500.0,
+          0.0225,
+          40.0,
+        ]),
+      },
+      "no fallbacks, 0 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "[": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[-1.5e3, 2.25E-2, 4e+1]": Ok([
+          -1500.0,
+          0.0225,
+          40.0,
+        ]),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "[": Ok([]),
+        "[-1": Ok([
+          -1.0,
+        ]),
+        "[-1.5": Ok([
+          -1.5,
+        ]),
+        "[-1.5e3": Ok([
+          -1500.0,
+        ]),
+        "[-1.5e3, 2": Ok([
+          -1500.0,
+          2.0,
+        ]),
+        "[-1.5e3, 2.2": Ok([
+          -1500.0,
+          2.2,
+        ]),
+        "[-1.5e3, 2.25": Ok([
+          -1500.0,
+          2.25,
+        ]),
+        "[-1.5e3, 2.25E-2": Ok([
+          -1500.0,
+          0.0225,
+        ]),
+        "[-1.5e3, 2.25E-2, 4": Ok([
+          -1500.0,
+          0.0225,
+          4.0,
+        ]),
+        "[-1.5e3, 2.25E-2, 4e+1": Ok([
+          -1500.0,
+          0.0225,
+          40.0,
+        ]),
+      },
+      "default behavior, 1 backtracks": {
+        "": Ok([]),
+        "[-1": Ok([
+          -1.0,
+        ]),
+        "[-1.5": Ok([
+          -1.5,
+        ]),
+        "[-1.5e3": Ok([
+          -1500.0,
+        ]),
+        "[-1.5e3, 2": Ok([
+          -1500.0,
+          2.0,
+        ]),
+        "[-1.5e3, 2.2": Ok([
+          -1500.0,
+          2.2,
+        ]),
+        "[-1.5e3, 2.25": Ok([
+          -1500.0,
+          2.25,
+        ]),
+        "[-1.5e3, 2.25E-2": Ok([
+          -1500.0,
+          0.0225,
+        ]),
+        "[-1.5e3, 2.25E-2, 4": Ok([
+          -1500.0,
+          0.0225,
+          4.0,
+        ]),
+        "[-1.5e3, 2.25E-2, 4e+1": Ok([
+          -1500.0,
+          0.0225,
+          40.0,
+        ]),
+      },
+      "strict behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "[-1.5e3, 2.25E-2, 4e+1]": Ok([
+          -1500.0,
+          0.0225,
+          40.0,
+        ]),
+      },
+      "withhold trailing numbers": {
+        "": Ok([]),
+        "[-1.5e3,": Ok([
+          -1500.0,
+        ]),
+        "[-1.5e3, 2.25E-2,": Ok([
+          -1500.0,
+          0.0225,
+        ]),
+        "[-1.5e3, 2.25E-2, 4e+1]": Ok([
+          -1500.0,
+          0.0225,
+          40.0,
+        ]),
+      },
+    }
+    "###);
+}
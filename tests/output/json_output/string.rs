@@ -149,3 +149,122 @@ fn test_string() {
     }
     "###)
 }
+
+#[test]
+fn test_string_unicode_escape() {
+    insta::assert_ron_snapshot!(
+        run_json_modes_on_prefixes_and_format_outputs::<Vec<String>>(&
+            default_modes(), &r#"["caf\u00e9 \ud83d\ude00!"]"#),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok([]),
+        "[\"": Ok([
+          "",
+        ]),
+        "[\"c": Ok([
+          "c",
+        ]),
+        "[\"ca": Ok([
+          "ca",
+        ]),
+        "[\"caf": Ok([
+          "caf",
+        ]),
+        "[\"caf\\u00e9": Ok([
+          "café",
+        ]),
+        "[\"caf\\u00e9 ": Ok([
+          "café ",
+        ]),
+        "[\"caf\\u00e9 \\ud83d\\ude00": Ok([
+          "café 😀",
+        ]),
+        "[\"caf\\u00e9 \\ud83d\\ude00!": Ok([
+          "café 😀!",
+        ]),
+      },
+      "default behavior except no randomized trailer": {
+        "": Ok([]),
+        "[\"caf\\u00e9 \\ud83d\\ude00!\"": Ok([
+          "café 😀!",
+        ]),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok([]),
+        "[": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[\"caf\\u00e9 \\ud83d\\ude00!\"]": Ok([
+          "café 😀!",
+        ]),
+      },
+      "no fallbacks, 0 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "[": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[\"caf\\u00e9 \\ud83d\\ude00!\"]": Ok([
+          "café 😀!",
+        ]),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "[": Ok([]),
+        "[\"": Ok([
+          "",
+        ]),
+        "[\"c": Ok([
+          "c",
+        ]),
+        "[\"ca": Ok([
+          "ca",
+        ]),
+        "[\"caf": Ok([
+          "caf",
+        ]),
+        "[\"caf\\u00e9": Ok([
+          "café",
+        ]),
+        "[\"caf\\u00e9 ": Ok([
+          "café ",
+        ]),
+        "[\"caf\\u00e9 \\ud83d\\ude00": Ok([
+          "café 😀",
+        ]),
+        "[\"caf\\u00e9 \\ud83d\\ude00!": Ok([
+          "café 😀!",
+        ]),
+      },
+      "default behavior, 1 backtracks": {
+        "": Ok([]),
+        "[\"": Ok([
+          "",
+        ]),
+        "[\"c": Ok([
+          "c",
+        ]),
+        "[\"ca": Ok([
+          "ca",
+        ]),
+        "[\"caf": Ok([
+          "caf",
+        ]),
+        "[\"caf\\u00e9": Ok([
+          "café",
+        ]),
+        "[\"caf\\u00e9 ": Ok([
+          "café ",
+        ]),
+        "[\"caf\\u00e9 \\ud83d\\ude00": Ok([
+          "café 😀",
+        ]),
+        "[\"caf\\u00e9 \\ud83d\\ude00!": Ok([
+          "café 😀!",
+        ]),
+      },
+      "strict behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "[\"caf\\u00e9 \\ud83d\\ude00!\"]": Ok([
+          "café 😀!",
+        ]),
+      },
+    }
+    "###);
+}
@@ -3,8 +3,20 @@
     allow(unused_variables, unused_imports, dead_code)
 )]
 
+#[cfg(feature = "bincode")]
+mod bincode_output;
+#[cfg(feature = "serde_cbor")]
+mod cbor_output;
 mod common;
 #[cfg(feature = "serde_json")]
 mod json_output;
+#[cfg(feature = "rmp-serde")]
+mod msgpack_output;
+#[cfg(feature = "postcard")]
+mod postcard_output;
+#[cfg(feature = "ron")]
+mod ron_output;
+#[cfg(feature = "toml")]
+mod toml_output;
 #[cfg(feature = "serde_yaml")]
 mod yaml_output;
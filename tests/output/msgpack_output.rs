@@ -0,0 +1,166 @@
+use std::borrow::Cow;
+use std::fmt::Debug;
+
+use deser_incomplete::options::MsgPackExtraOptions;
+use deser_incomplete::unstable::UnstableCustomBehavior;
+use deser_incomplete::Options;
+use indexmap::IndexMap;
+use rmpv::Value;
+use serde::{Deserialize, Serialize};
+
+use crate::common::{hex, run_on_prefixes};
+
+mod bytes;
+mod r#enum;
+mod map;
+mod seq;
+mod string;
+mod r#struct;
+
+type BoxSerialize = Box<dyn erased_serde::Serialize>;
+
+/// Robustly deserialize all prefixes of the input as MessagePack.
+///
+/// The output is deduplicated -- only inputs are shown where the output changes.
+/// Inputs are shown in hex.
+///
+/// We also check if our wrapping of [`rmp_serde`] on the whole input matches
+/// [`rmp_serde`] without wrapping, and we check the soundness and monotonicity
+/// criteria with [`check_sound_and_monotone`].
+#[allow(clippy::type_complexity)]
+pub(crate) fn run_msgpack_modes_on_prefixes_and_format_outputs<
+    T: for<'de> Deserialize<'de> + Serialize + Debug + PartialEq + 'static,
+>(
+    modes: &[(&'static str, Options<MsgPackExtraOptions>)],
+    full_input: &[u8],
+) -> IndexMap<&'static str, IndexMap<String, BoxSerialize>> {
+    check_sound_and_monotone(full_input);
+
+    let reference_official: Option<T> = rmp_serde::from_slice(full_input).ok();
+
+    modes
+        .iter()
+        .map(|(mode_desc, options)| {
+            let inputs_outputs = run_on_prefixes(full_input, |inp| {
+                options
+                    .clone()
+                    .deserialize_from_msgpack_slice::<T>(Cow::Borrowed(inp))
+                    .map_err(|err| err.to_string())
+            });
+
+            let last_output_matches_rmp_serde_friendly =
+                match reference_official.as_ref().map(|reference| {
+                    Ok(reference)
+                        == inputs_outputs
+                            .last()
+                            .expect("every slice has a prefix")
+                            .1
+                            .as_ref()
+                }) {
+                    Some(true) => None,
+                    Some(false) => Some("no"),
+                    None => Some("rmp_serde failed"),
+                };
+            let trailing_line = (last_output_matches_rmp_serde_friendly).map(|friendly| {
+                (
+                    "final output matches rmp_serde?".to_string(),
+                    Box::new(friendly) as BoxSerialize,
+                )
+            });
+
+            let lines: IndexMap<_, _> = inputs_outputs
+                .into_iter()
+                .map(|(input, output)| -> (_, Box<dyn erased_serde::Serialize>) {
+                    (hex(input), Box::new(output))
+                })
+                .chain(trailing_line)
+                .collect();
+
+            (*mode_desc, lines)
+        })
+        .collect()
+}
+
+/// Check the guiding principles from the README on every prefix, by deserializing
+/// into a schemaless [`Value`]:
+///
+/// 1. ("soundness") The output for a prefix contains no data that isn't in the full input.
+/// 2. ("monotone") The output for a longer prefix contains the output for a shorter one.
+fn check_sound_and_monotone(full_input: &[u8]) {
+    let full: Value = rmp_serde::from_slice(full_input).unwrap();
+
+    let mut previous: Option<Value> = None;
+    for end in 0..=full_input.len() {
+        let output = deser_incomplete::from_msgpack_slice::<Value>(&full_input[..end]);
+        match (&previous, output) {
+            (_, Ok(output)) => {
+                assert!(
+                    is_part_of(&output, &full),
+                    "not sound at {}: {output:?} is not part of {full:?}",
+                    hex(&full_input[..end])
+                );
+                if let Some(previous) = &previous {
+                    assert!(
+                        is_part_of(previous, &output),
+                        "not monotone at {}: {previous:?} is not part of {output:?}",
+                        hex(&full_input[..end])
+                    );
+                }
+                previous = Some(output);
+            }
+            (None, Err(_)) => {}
+            (Some(previous), Err(err)) => panic!(
+                "not monotone at {}: {previous:?} became {err}",
+                hex(&full_input[..end])
+            ),
+        }
+    }
+
+    assert_eq!(previous, Some(full));
+}
+
+/// Whether `part` could be what we get from a prefix of `whole`.
+fn is_part_of(part: &Value, whole: &Value) -> bool {
+    match (part, whole) {
+        (Value::Array(part), Value::Array(whole)) => {
+            part.len() <= whole.len() && part.iter().zip(whole).all(|(p, w)| is_part_of(p, w))
+        }
+        // Map entries come in order, and only the last key may be partial.
+        (Value::Map(part), Value::Map(whole)) => {
+            part.len() <= whole.len()
+                && part
+                    .iter()
+                    .zip(whole)
+                    .all(|((pk, pv), (wk, wv))| is_part_of(pk, wk) && is_part_of(pv, wv))
+        }
+        (Value::String(part), Value::String(whole)) => {
+            whole.as_bytes().starts_with(part.as_bytes())
+        }
+        (Value::Binary(part), Value::Binary(whole)) => whole.starts_with(part),
+        (part, whole) => part == whole,
+    }
+}
+
+fn default_modes() -> Vec<(&'static str, Options<MsgPackExtraOptions>)> {
+    vec![
+        ("default behavior", Options::new_msgpack()),
+        (
+            "default behavior except no randomized trailer",
+            Options::new_msgpack().disable_random_tag(),
+        ),
+        (
+            "default behavior, 0 backtracks",
+            Options::new_msgpack().with_max_n_backtracks(Some(0)),
+        ),
+        (
+            "no fallbacks, 1 backtracks",
+            Options::new_msgpack()
+                .custom_behavior(UnstableCustomBehavior::default().no_fallbacks())
+                .with_max_n_backtracks(Some(1)),
+        ),
+        (
+            "strict behavior",
+            Options::new_msgpack().custom_behavior(UnstableCustomBehavior::strict()),
+        ),
+    ]
+}
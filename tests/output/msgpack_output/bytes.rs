@@ -0,0 +1,123 @@
+use serde_bytes::ByteBuf;
+
+use super::{default_modes, run_msgpack_modes_on_prefixes_and_format_outputs};
+
+#[test]
+fn test_bytes() {
+    let value = vec![
+        ByteBuf::from(b"ab".to_vec()),
+        ByteBuf::from(vec![0xff, 0x00, 0x10]),
+    ];
+    insta::assert_ron_snapshot!(
+        run_msgpack_modes_on_prefixes_and_format_outputs::<Vec<ByteBuf>>(&default_modes(), &rmp_serde::to_vec(&value).unwrap()),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok([]),
+        "92 c4": Ok([
+          "",
+        ]),
+        "92 c4 02 61": Ok([
+          "YQ==",
+        ]),
+        "92 c4 02 61 62": Ok([
+          "YWI=",
+        ]),
+        "92 c4 02 61 62 c4": Ok([
+          "YWI=",
+          "",
+        ]),
+        "92 c4 02 61 62 c4 03 ff": Ok([
+          "YWI=",
+          "/w==",
+        ]),
+        "92 c4 02 61 62 c4 03 ff 00": Ok([
+          "YWI=",
+          "/wA=",
+        ]),
+        "92 c4 02 61 62 c4 03 ff 00 10": Ok([
+          "YWI=",
+          "/wAQ",
+        ]),
+      },
+      "default behavior except no randomized trailer": {
+        "": Ok([]),
+        "92 c4 02 61 62": Ok([
+          "YWI=",
+        ]),
+        "92 c4 02 61 62 c4 03 ff 00 10": Ok([
+          "YWI=",
+          "/wAQ",
+        ]),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok([]),
+        "92": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "92 c4 02 61 62 c4": Ok([
+          "YWI=",
+          "",
+        ]),
+        "92 c4 02 61 62 c4 03 ff": Ok([
+          "YWI=",
+          "/w==",
+        ]),
+        "92 c4 02 61 62 c4 03 ff 00": Ok([
+          "YWI=",
+          "/wA=",
+        ]),
+        "92 c4 02 61 62 c4 03 ff 00 10": Ok([
+          "YWI=",
+          "/wAQ",
+        ]),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "92": Ok([]),
+        "92 c4": Ok([
+          "",
+        ]),
+        "92 c4 02 61": Ok([
+          "YQ==",
+        ]),
+        "92 c4 02 61 62": Ok([
+          "YWI=",
+        ]),
+        "92 c4 02 61 62 c4": Ok([
+          "YWI=",
+          "",
+        ]),
+        "92 c4 02 61 62 c4 03 ff": Ok([
+          "YWI=",
+          "/w==",
+        ]),
+        "92 c4 02 61 62 c4 03 ff 00": Ok([
+          "YWI=",
+          "/wA=",
+        ]),
+        "92 c4 02 61 62 c4 03 ff 00 10": Ok([
+          "YWI=",
+          "/wAQ",
+        ]),
+      },
+      "strict behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "92 c4 02 61 62 c4": Ok([
+          "YWI=",
+          "",
+        ]),
+        "92 c4 02 61 62 c4 03 ff": Ok([
+          "YWI=",
+          "/w==",
+        ]),
+        "92 c4 02 61 62 c4 03 ff 00": Ok([
+          "YWI=",
+          "/wA=",
+        ]),
+        "92 c4 02 61 62 c4 03 ff 00 10": Ok([
+          "YWI=",
+          "/wAQ",
+        ]),
+      },
+    }
+    "###)
+}
@@ -0,0 +1,308 @@
+use serde::{Deserialize, Serialize};
+
+use super::{default_modes, run_msgpack_modes_on_prefixes_and_format_outputs};
+
+#[test]
+fn test_enum_external() {
+    #[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+    enum External {
+        Unit,
+        Newtype(String),
+        Tuple(String, #[serde(default)] String),
+        Struct {
+            a: String,
+            #[serde(default)]
+            b: String,
+        },
+    }
+
+    let value = vec![
+        External::Unit,
+        External::Newtype("az".into()),
+        External::Tuple("az".into(), "by".into()),
+        External::Struct {
+            a: "az".into(),
+            b: "by".into(),
+        },
+    ];
+    insta::assert_ron_snapshot!(
+        run_msgpack_modes_on_prefixes_and_format_outputs::<Vec<External>>(&default_modes(), &rmp_serde::to_vec_named(&value).unwrap()),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok([]),
+        "94 a4 55 6e 69 74": Ok([
+          Unit,
+        ]),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2": Ok([
+          Unit,
+          Newtype(""),
+        ]),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61": Ok([
+          Unit,
+          Newtype("a"),
+        ]),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a": Ok([
+          Unit,
+          Newtype("az"),
+        ]),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a 81 a5 54 75 70 6c 65 92 a2 61 7a a2": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", ""),
+        ]),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a 81 a5 54 75 70 6c 65 92 a2 61 7a a2 62": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "b"),
+        ]),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a 81 a5 54 75 70 6c 65 92 a2 61 7a a2 62 79": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+        ]),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a 81 a5 54 75 70 6c 65 92 a2 61 7a a2 62 79 81 a6 53 74 72 75 63 74 82 a1 61 a2": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "",
+            b: "",
+          ),
+        ]),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a 81 a5 54 75 70 6c 65 92 a2 61 7a a2 62 79 81 a6 53 74 72 75 63 74 82 a1 61 a2 61": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "a",
+            b: "",
+          ),
+        ]),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a 81 a5 54 75 70 6c 65 92 a2 61 7a a2 62 79 81 a6 53 74 72 75 63 74 82 a1 61 a2 61 7a": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "",
+          ),
+        ]),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a 81 a5 54 75 70 6c 65 92 a2 61 7a a2 62 79 81 a6 53 74 72 75 63 74 82 a1 61 a2 61 7a a1 62 a2 62": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "b",
+          ),
+        ]),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a 81 a5 54 75 70 6c 65 92 a2 61 7a a2 62 79 81 a6 53 74 72 75 63 74 82 a1 61 a2 61 7a a1 62 a2 62 79": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "by",
+          ),
+        ]),
+      },
+      "default behavior except no randomized trailer": {
+        "": Ok([]),
+        "94 a4 55 6e 69 74": Ok([
+          Unit,
+        ]),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a": Ok([
+          Unit,
+          Newtype("az"),
+        ]),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a 81 a5 54 75 70 6c 65 92 a2 61 7a a2 62 79": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+        ]),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a 81 a5 54 75 70 6c 65 92 a2 61 7a a2 62 79 81 a6 53 74 72 75 63 74 82 a1 61 a2 61 7a": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "",
+          ),
+        ]),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a 81 a5 54 75 70 6c 65 92 a2 61 7a a2 62 79 81 a6 53 74 72 75 63 74 82 a1 61 a2 61 7a a1 62 a2 62 79": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "by",
+          ),
+        ]),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok([]),
+        "94": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a 81 a5 54 75 70 6c 65 92 a2 61 7a a2 62 79 81 a6 53 74 72 75 63 74 82 a1 61 a2 61 7a a1 62 a2": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "",
+          ),
+        ]),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a 81 a5 54 75 70 6c 65 92 a2 61 7a a2 62 79 81 a6 53 74 72 75 63 74 82 a1 61 a2 61 7a a1 62 a2 62": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "b",
+          ),
+        ]),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a 81 a5 54 75 70 6c 65 92 a2 61 7a a2 62 79 81 a6 53 74 72 75 63 74 82 a1 61 a2 61 7a a1 62 a2 62 79": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "by",
+          ),
+        ]),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "94": Ok([]),
+        "94 a4 55 6e 69 74": Ok([
+          Unit,
+        ]),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2": Ok([
+          Unit,
+          Newtype(""),
+        ]),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61": Ok([
+          Unit,
+          Newtype("a"),
+        ]),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a": Ok([
+          Unit,
+          Newtype("az"),
+        ]),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a 81 a5 54 75 70 6c 65 92": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a 81 a5 54 75 70 6c 65 92 a2 61 7a a2": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", ""),
+        ]),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a 81 a5 54 75 70 6c 65 92 a2 61 7a a2 62": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "b"),
+        ]),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a 81 a5 54 75 70 6c 65 92 a2 61 7a a2 62 79": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+        ]),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a 81 a5 54 75 70 6c 65 92 a2 61 7a a2 62 79 81 a6 53 74 72 75 63 74 82": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a 81 a5 54 75 70 6c 65 92 a2 61 7a a2 62 79 81 a6 53 74 72 75 63 74 82 a1 61 a2": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "",
+            b: "",
+          ),
+        ]),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a 81 a5 54 75 70 6c 65 92 a2 61 7a a2 62 79 81 a6 53 74 72 75 63 74 82 a1 61 a2 61": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "a",
+            b: "",
+          ),
+        ]),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a 81 a5 54 75 70 6c 65 92 a2 61 7a a2 62 79 81 a6 53 74 72 75 63 74 82 a1 61 a2 61 7a": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "",
+          ),
+        ]),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a 81 a5 54 75 70 6c 65 92 a2 61 7a a2 62 79 81 a6 53 74 72 75 63 74 82 a1 61 a2 61 7a a1 62 a2 62": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "b",
+          ),
+        ]),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a 81 a5 54 75 70 6c 65 92 a2 61 7a a2 62 79 81 a6 53 74 72 75 63 74 82 a1 61 a2 61 7a a1 62 a2 62 79": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "by",
+          ),
+        ]),
+      },
+      "strict behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a 81 a5 54 75 70 6c 65 92": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 1 backtracks)"),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a 81 a5 54 75 70 6c 65 92 a2 61 7a a2": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a 81 a5 54 75 70 6c 65 92 a2 61 7a a2 62 79 81 a6 53 74 72 75 63 74 82": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 1 backtracks)"),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a 81 a5 54 75 70 6c 65 92 a2 61 7a a2 62 79 81 a6 53 74 72 75 63 74 82 a1 61 a2": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "",
+            b: "",
+          ),
+        ]),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a 81 a5 54 75 70 6c 65 92 a2 61 7a a2 62 79 81 a6 53 74 72 75 63 74 82 a1 61 a2 61": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "a",
+            b: "",
+          ),
+        ]),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a 81 a5 54 75 70 6c 65 92 a2 61 7a a2 62 79 81 a6 53 74 72 75 63 74 82 a1 61 a2 61 7a": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "",
+          ),
+        ]),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a 81 a5 54 75 70 6c 65 92 a2 61 7a a2 62 79 81 a6 53 74 72 75 63 74 82 a1 61 a2 61 7a a1 62 a2 62": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "b",
+          ),
+        ]),
+        "94 a4 55 6e 69 74 81 a7 4e 65 77 74 79 70 65 a2 61 7a 81 a5 54 75 70 6c 65 92 a2 61 7a a2 62 79 81 a6 53 74 72 75 63 74 82 a1 61 a2 61 7a a1 62 a2 62 79": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "by",
+          ),
+        ]),
+      },
+    }
+    "###)
+}
@@ -0,0 +1,142 @@
+use indexmap::IndexMap;
+
+use super::{default_modes, run_msgpack_modes_on_prefixes_and_format_outputs};
+
+#[test]
+fn test_map() {
+    let map: IndexMap<&str, Vec<bool>> = [("ab", vec![true]), ("cd", vec![false, true])].into();
+    insta::assert_ron_snapshot!(
+        run_msgpack_modes_on_prefixes_and_format_outputs::<IndexMap<String, Vec<bool>>>(&default_modes(), &rmp_serde::to_vec(&map).unwrap()),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok({}),
+        "82 a2 61 62": Ok({
+          "ab": [],
+        }),
+        "82 a2 61 62 91 c3": Ok({
+          "ab": [
+            true,
+          ],
+        }),
+        "82 a2 61 62 91 c3 a2 63 64": Ok({
+          "ab": [
+            true,
+          ],
+          "cd": [],
+        }),
+        "82 a2 61 62 91 c3 a2 63 64 92 c2": Ok({
+          "ab": [
+            true,
+          ],
+          "cd": [
+            false,
+          ],
+        }),
+        "82 a2 61 62 91 c3 a2 63 64 92 c2 c3": Ok({
+          "ab": [
+            true,
+          ],
+          "cd": [
+            false,
+            true,
+          ],
+        }),
+      },
+      "default behavior except no randomized trailer": {
+        "": Ok({}),
+        "82 a2 61 62": Ok({
+          "ab": [],
+        }),
+        "82 a2 61 62 91 c3": Ok({
+          "ab": [
+            true,
+          ],
+        }),
+        "82 a2 61 62 91 c3 a2 63 64": Ok({
+          "ab": [
+            true,
+          ],
+          "cd": [],
+        }),
+        "82 a2 61 62 91 c3 a2 63 64 92 c2": Ok({
+          "ab": [
+            true,
+          ],
+          "cd": [
+            false,
+          ],
+        }),
+        "82 a2 61 62 91 c3 a2 63 64 92 c2 c3": Ok({
+          "ab": [
+            true,
+          ],
+          "cd": [
+            false,
+            true,
+          ],
+        }),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok({}),
+        "82": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "82 a2 61 62 91 c3 a2 63 64 92 c2 c3": Ok({
+          "ab": [
+            true,
+          ],
+          "cd": [
+            false,
+            true,
+          ],
+        }),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "82": Ok({}),
+        "82 a2 61 62": Ok({
+          "ab": [],
+        }),
+        "82 a2 61 62 91 c3": Ok({
+          "ab": [
+            true,
+          ],
+        }),
+        "82 a2 61 62 91 c3 a2 63 64": Ok({
+          "ab": [
+            true,
+          ],
+          "cd": [],
+        }),
+        "82 a2 61 62 91 c3 a2 63 64 92 c2": Ok({
+          "ab": [
+            true,
+          ],
+          "cd": [
+            false,
+          ],
+        }),
+        "82 a2 61 62 91 c3 a2 63 64 92 c2 c3": Ok({
+          "ab": [
+            true,
+          ],
+          "cd": [
+            false,
+            true,
+          ],
+        }),
+      },
+      "strict behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "82 a2 61 62 91 c3 a2 63 64 92 c2 c3": Ok({
+          "ab": [
+            true,
+          ],
+          "cd": [
+            false,
+            true,
+          ],
+        }),
+      },
+    }
+    "###)
+}
@@ -0,0 +1,165 @@
+use super::{default_modes, run_msgpack_modes_on_prefixes_and_format_outputs};
+
+#[test]
+fn test_seq() {
+    insta::assert_ron_snapshot!(
+        run_msgpack_modes_on_prefixes_and_format_outputs::<Vec<Vec<u32>>>(&default_modes(), &rmp_serde::to_vec(&vec![vec![1, 300], vec![], vec![70000]]).unwrap()),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok([]),
+        "93 92": Ok([
+          [],
+        ]),
+        "93 92 01": Ok([
+          [
+            1,
+          ],
+        ]),
+        "93 92 01 cd 01 2c": Ok([
+          [
+            1,
+            300,
+          ],
+        ]),
+        "93 92 01 cd 01 2c 90": Ok([
+          [
+            1,
+            300,
+          ],
+          [],
+        ]),
+        "93 92 01 cd 01 2c 90 91": Ok([
+          [
+            1,
+            300,
+          ],
+          [],
+          [],
+        ]),
+        "93 92 01 cd 01 2c 90 91 ce 00 01 11 70": Ok([
+          [
+            1,
+            300,
+          ],
+          [],
+          [
+            70000,
+          ],
+        ]),
+      },
+      "default behavior except no randomized trailer": {
+        "": Ok([]),
+        "93 92": Ok([
+          [],
+        ]),
+        "93 92 01": Ok([
+          [
+            1,
+          ],
+        ]),
+        "93 92 01 cd 01 2c": Ok([
+          [
+            1,
+            300,
+          ],
+        ]),
+        "93 92 01 cd 01 2c 90": Ok([
+          [
+            1,
+            300,
+          ],
+          [],
+        ]),
+        "93 92 01 cd 01 2c 90 91": Ok([
+          [
+            1,
+            300,
+          ],
+          [],
+          [],
+        ]),
+        "93 92 01 cd 01 2c 90 91 ce 00 01 11 70": Ok([
+          [
+            1,
+            300,
+          ],
+          [],
+          [
+            70000,
+          ],
+        ]),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok([]),
+        "93": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "93 92 01 cd 01 2c 90 91 ce 00 01 11 70": Ok([
+          [
+            1,
+            300,
+          ],
+          [],
+          [
+            70000,
+          ],
+        ]),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "93": Ok([]),
+        "93 92": Ok([
+          [],
+        ]),
+        "93 92 01": Ok([
+          [
+            1,
+          ],
+        ]),
+        "93 92 01 cd 01 2c": Ok([
+          [
+            1,
+            300,
+          ],
+        ]),
+        "93 92 01 cd 01 2c 90": Ok([
+          [
+            1,
+            300,
+          ],
+          [],
+        ]),
+        "93 92 01 cd 01 2c 90 91": Ok([
+          [
+            1,
+            300,
+          ],
+          [],
+          [],
+        ]),
+        "93 92 01 cd 01 2c 90 91 ce 00 01 11 70": Ok([
+          [
+            1,
+            300,
+          ],
+          [],
+          [
+            70000,
+          ],
+        ]),
+      },
+      "strict behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "93 92 01 cd 01 2c 90 91 ce 00 01 11 70": Ok([
+          [
+            1,
+            300,
+          ],
+          [],
+          [
+            70000,
+          ],
+        ]),
+      },
+    }
+    "###)
+}
@@ -0,0 +1,249 @@
+use super::{default_modes, run_msgpack_modes_on_prefixes_and_format_outputs};
+
+#[test]
+fn test_string() {
+    insta::assert_ron_snapshot!(
+        run_msgpack_modes_on_prefixes_and_format_outputs::<Vec<String>>(&default_modes(), &rmp_serde::to_vec(&vec!["ab", "", "é"]).unwrap()),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok([]),
+        "93 a2": Ok([
+          "",
+        ]),
+        "93 a2 61": Ok([
+          "a",
+        ]),
+        "93 a2 61 62": Ok([
+          "ab",
+        ]),
+        "93 a2 61 62 a0": Ok([
+          "ab",
+          "",
+        ]),
+        "93 a2 61 62 a0 a2": Ok([
+          "ab",
+          "",
+          "",
+        ]),
+        "93 a2 61 62 a0 a2 c3 a9": Ok([
+          "ab",
+          "",
+          "é",
+        ]),
+      },
+      "default behavior except no randomized trailer": {
+        "": Ok([]),
+        "93 a2 61 62": Ok([
+          "ab",
+        ]),
+        "93 a2 61 62 a0": Ok([
+          "ab",
+          "",
+        ]),
+        "93 a2 61 62 a0 a2 c3 a9": Ok([
+          "ab",
+          "",
+          "é",
+        ]),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok([]),
+        "93": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "93 a2 61 62 a0 a2": Ok([
+          "ab",
+          "",
+          "",
+        ]),
+        "93 a2 61 62 a0 a2 c3 a9": Ok([
+          "ab",
+          "",
+          "é",
+        ]),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "93": Ok([]),
+        "93 a2": Ok([
+          "",
+        ]),
+        "93 a2 61": Ok([
+          "a",
+        ]),
+        "93 a2 61 62": Ok([
+          "ab",
+        ]),
+        "93 a2 61 62 a0": Ok([
+          "ab",
+          "",
+        ]),
+        "93 a2 61 62 a0 a2": Ok([
+          "ab",
+          "",
+          "",
+        ]),
+        "93 a2 61 62 a0 a2 c3 a9": Ok([
+          "ab",
+          "",
+          "é",
+        ]),
+      },
+      "strict behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "93 a2 61 62 a0 a2": Ok([
+          "ab",
+          "",
+          "",
+        ]),
+        "93 a2 61 62 a0 a2 c3 a9": Ok([
+          "ab",
+          "",
+          "é",
+        ]),
+      },
+    }
+    "###)
+}
+
+#[test]
+fn test_long_string() {
+    // A str 8: the length is in a separate byte.
+    insta::assert_ron_snapshot!(
+        run_msgpack_modes_on_prefixes_and_format_outputs::<Vec<String>>(&default_modes()[..2], &rmp_serde::to_vec(&vec!["0123456789".repeat(4)]).unwrap()),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok([]),
+        "91 d9": Ok([
+          "",
+        ]),
+        "91 d9 28 30": Ok([
+          "0",
+        ]),
+        "91 d9 28 30 31": Ok([
+          "01",
+        ]),
+        "91 d9 28 30 31 32": Ok([
+          "012",
+        ]),
+        "91 d9 28 30 31 32 33": Ok([
+          "0123",
+        ]),
+        "91 d9 28 30 31 32 33 34": Ok([
+          "01234",
+        ]),
+        "91 d9 28 30 31 32 33 34 35": Ok([
+          "012345",
+        ]),
+        "91 d9 28 30 31 32 33 34 35 36": Ok([
+          "0123456",
+        ]),
+        "91 d9 28 30 31 32 33 34 35 36 37": Ok([
+          "01234567",
+        ]),
+        "91 d9 28 30 31 32 33 34 35 36 37 38": Ok([
+          "012345678",
+        ]),
+        "91 d9 28 30 31 32 33 34 35 36 37 38 39": Ok([
+          "0123456789",
+        ]),
+        "91 d9 28 30 31 32 33 34 35 36 37 38 39 30": Ok([
+          "01234567890",
+        ]),
+        "91 d9 28 30 31 32 33 34 35 36 37 38 39 30 31": Ok([
+          "012345678901",
+        ]),
+        "91 d9 28 30 31 32 33 34 35 36 37 38 39 30 31 32": Ok([
+          "0123456789012",
+        ]),
+        "91 d9 28 30 31 32 33 34 35 36 37 38 39 30 31 32 33": Ok([
+          "01234567890123",
+        ]),
+        "91 d9 28 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34": Ok([
+          "012345678901234",
+        ]),
+        "91 d9 28 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35": Ok([
+          "0123456789012345",
+        ]),
+        "91 d9 28 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36": Ok([
+          "01234567890123456",
+        ]),
+        "91 d9 28 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37": Ok([
+          "012345678901234567",
+        ]),
+        "91 d9 28 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38": Ok([
+          "0123456789012345678",
+        ]),
+        "91 d9 28 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39": Ok([
+          "01234567890123456789",
+        ]),
+        "91 d9 28 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39 30": Ok([
+          "012345678901234567890",
+        ]),
+        "91 d9 28 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39 30 31": Ok([
+          "0123456789012345678901",
+        ]),
+        "91 d9 28 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39 30 31 32": Ok([
+          "01234567890123456789012",
+        ]),
+        "91 d9 28 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39 30 31 32 33": Ok([
+          "012345678901234567890123",
+        ]),
+        "91 d9 28 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34": Ok([
+          "0123456789012345678901234",
+        ]),
+        "91 d9 28 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35": Ok([
+          "01234567890123456789012345",
+        ]),
+        "91 d9 28 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36": Ok([
+          "012345678901234567890123456",
+        ]),
+        "91 d9 28 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37": Ok([
+          "0123456789012345678901234567",
+        ]),
+        "91 d9 28 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38": Ok([
+          "01234567890123456789012345678",
+        ]),
+        "91 d9 28 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39": Ok([
+          "012345678901234567890123456789",
+        ]),
+        "91 d9 28 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39 30": Ok([
+          "0123456789012345678901234567890",
+        ]),
+        "91 d9 28 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39 30 31": Ok([
+          "01234567890123456789012345678901",
+        ]),
+        "91 d9 28 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39 30 31 32": Ok([
+          "012345678901234567890123456789012",
+        ]),
+        "91 d9 28 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39 30 31 32 33": Ok([
+          "0123456789012345678901234567890123",
+        ]),
+        "91 d9 28 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34": Ok([
+          "01234567890123456789012345678901234",
+        ]),
+        "91 d9 28 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35": Ok([
+          "012345678901234567890123456789012345",
+        ]),
+        "91 d9 28 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36": Ok([
+          "0123456789012345678901234567890123456",
+        ]),
+        "91 d9 28 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37": Ok([
+          "01234567890123456789012345678901234567",
+        ]),
+        "91 d9 28 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38": Ok([
+          "012345678901234567890123456789012345678",
+        ]),
+        "91 d9 28 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39": Ok([
+          "0123456789012345678901234567890123456789",
+        ]),
+      },
+      "default behavior except no randomized trailer": {
+        "": Ok([]),
+        "91 d9 28 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39 30 31 32 33 34 35 36 37 38 39": Ok([
+          "0123456789012345678901234567890123456789",
+        ]),
+      },
+    }
+    "###)
+}
@@ -0,0 +1,413 @@
+use serde::{Deserialize, Serialize};
+
+use super::{default_modes, run_msgpack_modes_on_prefixes_and_format_outputs};
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+struct Reply {
+    id: u32,
+    #[serde(default)]
+    message: String,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+#[test]
+fn test_struct_as_map() {
+    let value = Reply {
+        id: 7,
+        message: "hello".into(),
+        tags: vec!["a".into()],
+    };
+    insta::assert_ron_snapshot!(
+        run_msgpack_modes_on_prefixes_and_format_outputs::<Reply>(&default_modes(), &rmp_serde::to_vec_named(&value).unwrap()),
+        @r###"
+    {
+      "default behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "83": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 1 backtracks)"),
+        "83 a2 69 64 07": Ok(Reply(
+          id: 7,
+          message: "",
+          tags: [],
+        )),
+        "83 a2 69 64 07 a7 6d 65 73 73 61 67 65 a5 68": Ok(Reply(
+          id: 7,
+          message: "h",
+          tags: [],
+        )),
+        "83 a2 69 64 07 a7 6d 65 73 73 61 67 65 a5 68 65": Ok(Reply(
+          id: 7,
+          message: "he",
+          tags: [],
+        )),
+        "83 a2 69 64 07 a7 6d 65 73 73 61 67 65 a5 68 65 6c": Ok(Reply(
+          id: 7,
+          message: "hel",
+          tags: [],
+        )),
+        "83 a2 69 64 07 a7 6d 65 73 73 61 67 65 a5 68 65 6c 6c": Ok(Reply(
+          id: 7,
+          message: "hell",
+          tags: [],
+        )),
+        "83 a2 69 64 07 a7 6d 65 73 73 61 67 65 a5 68 65 6c 6c 6f": Ok(Reply(
+          id: 7,
+          message: "hello",
+          tags: [],
+        )),
+        "83 a2 69 64 07 a7 6d 65 73 73 61 67 65 a5 68 65 6c 6c 6f a4 74 61 67 73 91 a1": Ok(Reply(
+          id: 7,
+          message: "hello",
+          tags: [
+            "",
+          ],
+        )),
+        "83 a2 69 64 07 a7 6d 65 73 73 61 67 65 a5 68 65 6c 6c 6f a4 74 61 67 73 91 a1 61": Ok(Reply(
+          id: 7,
+          message: "hello",
+          tags: [
+            "a",
+          ],
+        )),
+      },
+      "default behavior except no randomized trailer": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "83": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 1 backtracks)"),
+        "83 a2 69 64 07": Ok(Reply(
+          id: 7,
+          message: "",
+          tags: [],
+        )),
+        "83 a2 69 64 07 a7 6d 65 73 73 61 67 65 a5 68 65 6c 6c 6f": Ok(Reply(
+          id: 7,
+          message: "hello",
+          tags: [],
+        )),
+        "83 a2 69 64 07 a7 6d 65 73 73 61 67 65 a5 68 65 6c 6c 6f a4 74 61 67 73 91 a1 61": Ok(Reply(
+          id: 7,
+          message: "hello",
+          tags: [
+            "a",
+          ],
+        )),
+      },
+      "default behavior, 0 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "83": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "83 a2 69 64 07": Ok(Reply(
+          id: 7,
+          message: "",
+          tags: [],
+        )),
+        "83 a2 69 64 07 a7 6d 65 73 73 61 67 65": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "83 a2 69 64 07 a7 6d 65 73 73 61 67 65 a5": Ok(Reply(
+          id: 7,
+          message: "",
+          tags: [],
+        )),
+        "83 a2 69 64 07 a7 6d 65 73 73 61 67 65 a5 68": Ok(Reply(
+          id: 7,
+          message: "h",
+          tags: [],
+        )),
+        "83 a2 69 64 07 a7 6d 65 73 73 61 67 65 a5 68 65": Ok(Reply(
+          id: 7,
+          message: "he",
+          tags: [],
+        )),
+        "83 a2 69 64 07 a7 6d 65 73 73 61 67 65 a5 68 65 6c": Ok(Reply(
+          id: 7,
+          message: "hel",
+          tags: [],
+        )),
+        "83 a2 69 64 07 a7 6d 65 73 73 61 67 65 a5 68 65 6c 6c": Ok(Reply(
+          id: 7,
+          message: "hell",
+          tags: [],
+        )),
+        "83 a2 69 64 07 a7 6d 65 73 73 61 67 65 a5 68 65 6c 6c 6f": Ok(Reply(
+          id: 7,
+          message: "hello",
+          tags: [],
+        )),
+        "83 a2 69 64 07 a7 6d 65 73 73 61 67 65 a5 68 65 6c 6c 6f a4 74 61 67 73": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "83 a2 69 64 07 a7 6d 65 73 73 61 67 65 a5 68 65 6c 6c 6f a4 74 61 67 73 91 a1": Ok(Reply(
+          id: 7,
+          message: "hello",
+          tags: [
+            "",
+          ],
+        )),
+        "83 a2 69 64 07 a7 6d 65 73 73 61 67 65 a5 68 65 6c 6c 6f a4 74 61 67 73 91 a1 61": Ok(Reply(
+          id: 7,
+          message: "hello",
+          tags: [
+            "a",
+          ],
+        )),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "83": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 1 backtracks)"),
+        "83 a2 69 64 07": Ok(Reply(
+          id: 7,
+          message: "",
+          tags: [],
+        )),
+        "83 a2 69 64 07 a7 6d 65 73 73 61 67 65 a5 68": Ok(Reply(
+          id: 7,
+          message: "h",
+          tags: [],
+        )),
+        "83 a2 69 64 07 a7 6d 65 73 73 61 67 65 a5 68 65": Ok(Reply(
+          id: 7,
+          message: "he",
+          tags: [],
+        )),
+        "83 a2 69 64 07 a7 6d 65 73 73 61 67 65 a5 68 65 6c": Ok(Reply(
+          id: 7,
+          message: "hel",
+          tags: [],
+        )),
+        "83 a2 69 64 07 a7 6d 65 73 73 61 67 65 a5 68 65 6c 6c": Ok(Reply(
+          id: 7,
+          message: "hell",
+          tags: [],
+        )),
+        "83 a2 69 64 07 a7 6d 65 73 73 61 67 65 a5 68 65 6c 6c 6f": Ok(Reply(
+          id: 7,
+          message: "hello",
+          tags: [],
+        )),
+        "83 a2 69 64 07 a7 6d 65 73 73 61 67 65 a5 68 65 6c 6c 6f a4 74 61 67 73 91 a1": Ok(Reply(
+          id: 7,
+          message: "hello",
+          tags: [
+            "",
+          ],
+        )),
+        "83 a2 69 64 07 a7 6d 65 73 73 61 67 65 a5 68 65 6c 6c 6f a4 74 61 67 73 91 a1 61": Ok(Reply(
+          id: 7,
+          message: "hello",
+          tags: [
+            "a",
+          ],
+        )),
+      },
+      "strict behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "83 a2 69 64 07 a7 6d 65 73 73 61 67 65 a5 68 65 6c 6c 6f a4 74 61 67 73 91 a1": Ok(Reply(
+          id: 7,
+          message: "hello",
+          tags: [
+            "",
+          ],
+        )),
+        "83 a2 69 64 07 a7 6d 65 73 73 61 67 65 a5 68 65 6c 6c 6f a4 74 61 67 73 91 a1 61": Ok(Reply(
+          id: 7,
+          message: "hello",
+          tags: [
+            "a",
+          ],
+        )),
+      },
+    }
+    "###)
+}
+
+#[test]
+fn test_struct_as_array() {
+    let value = Reply {
+        id: 7,
+        message: "hello".into(),
+        tags: vec!["a".into()],
+    };
+    insta::assert_ron_snapshot!(
+        run_msgpack_modes_on_prefixes_and_format_outputs::<Reply>(&default_modes(), &rmp_serde::to_vec(&value).unwrap()),
+        @r###"
+    {
+      "default behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "93": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 1 backtracks)"),
+        "93 07": Ok(Reply(
+          id: 7,
+          message: "",
+          tags: [],
+        )),
+        "93 07 a5 68": Ok(Reply(
+          id: 7,
+          message: "h",
+          tags: [],
+        )),
+        "93 07 a5 68 65": Ok(Reply(
+          id: 7,
+          message: "he",
+          tags: [],
+        )),
+        "93 07 a5 68 65 6c": Ok(Reply(
+          id: 7,
+          message: "hel",
+          tags: [],
+        )),
+        "93 07 a5 68 65 6c 6c": Ok(Reply(
+          id: 7,
+          message: "hell",
+          tags: [],
+        )),
+        "93 07 a5 68 65 6c 6c 6f": Ok(Reply(
+          id: 7,
+          message: "hello",
+          tags: [],
+        )),
+        "93 07 a5 68 65 6c 6c 6f 91 a1": Ok(Reply(
+          id: 7,
+          message: "hello",
+          tags: [
+            "",
+          ],
+        )),
+        "93 07 a5 68 65 6c 6c 6f 91 a1 61": Ok(Reply(
+          id: 7,
+          message: "hello",
+          tags: [
+            "a",
+          ],
+        )),
+      },
+      "default behavior except no randomized trailer": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "93": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 1 backtracks)"),
+        "93 07": Ok(Reply(
+          id: 7,
+          message: "",
+          tags: [],
+        )),
+        "93 07 a5 68 65 6c 6c 6f": Ok(Reply(
+          id: 7,
+          message: "hello",
+          tags: [],
+        )),
+        "93 07 a5 68 65 6c 6c 6f 91 a1 61": Ok(Reply(
+          id: 7,
+          message: "hello",
+          tags: [
+            "a",
+          ],
+        )),
+      },
+      "default behavior, 0 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "93": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "93 07": Ok(Reply(
+          id: 7,
+          message: "",
+          tags: [],
+        )),
+        "93 07 a5 68": Ok(Reply(
+          id: 7,
+          message: "h",
+          tags: [],
+        )),
+        "93 07 a5 68 65": Ok(Reply(
+          id: 7,
+          message: "he",
+          tags: [],
+        )),
+        "93 07 a5 68 65 6c": Ok(Reply(
+          id: 7,
+          message: "hel",
+          tags: [],
+        )),
+        "93 07 a5 68 65 6c 6c": Ok(Reply(
+          id: 7,
+          message: "hell",
+          tags: [],
+        )),
+        "93 07 a5 68 65 6c 6c 6f": Ok(Reply(
+          id: 7,
+          message: "hello",
+          tags: [],
+        )),
+        "93 07 a5 68 65 6c 6c 6f 91 a1": Ok(Reply(
+          id: 7,
+          message: "hello",
+          tags: [
+            "",
+          ],
+        )),
+        "93 07 a5 68 65 6c 6c 6f 91 a1 61": Ok(Reply(
+          id: 7,
+          message: "hello",
+          tags: [
+            "a",
+          ],
+        )),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "93": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 1 backtracks)"),
+        "93 07": Ok(Reply(
+          id: 7,
+          message: "",
+          tags: [],
+        )),
+        "93 07 a5 68": Ok(Reply(
+          id: 7,
+          message: "h",
+          tags: [],
+        )),
+        "93 07 a5 68 65": Ok(Reply(
+          id: 7,
+          message: "he",
+          tags: [],
+        )),
+        "93 07 a5 68 65 6c": Ok(Reply(
+          id: 7,
+          message: "hel",
+          tags: [],
+        )),
+        "93 07 a5 68 65 6c 6c": Ok(Reply(
+          id: 7,
+          message: "hell",
+          tags: [],
+        )),
+        "93 07 a5 68 65 6c 6c 6f": Ok(Reply(
+          id: 7,
+          message: "hello",
+          tags: [],
+        )),
+        "93 07 a5 68 65 6c 6c 6f 91 a1": Ok(Reply(
+          id: 7,
+          message: "hello",
+          tags: [
+            "",
+          ],
+        )),
+        "93 07 a5 68 65 6c 6c 6f 91 a1 61": Ok(Reply(
+          id: 7,
+          message: "hello",
+          tags: [
+            "a",
+          ],
+        )),
+      },
+      "strict behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "93 07 a5 68 65 6c 6c 6f 91 a1": Ok(Reply(
+          id: 7,
+          message: "hello",
+          tags: [
+            "",
+          ],
+        )),
+        "93 07 a5 68 65 6c 6c 6f 91 a1 61": Ok(Reply(
+          id: 7,
+          message: "hello",
+          tags: [
+            "a",
+          ],
+        )),
+      },
+    }
+    "###)
+}
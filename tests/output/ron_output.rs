@@ -0,0 +1,119 @@
+use std::borrow::Cow;
+use std::fmt::Debug;
+
+use deser_incomplete::options::RonExtraOptions;
+use deser_incomplete::unstable::UnstableCustomBehavior;
+use deser_incomplete::Options;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use crate::common::run_on_prefixes_and_format_outputs;
+
+mod r#enum;
+mod map;
+mod seq;
+mod string;
+mod r#struct;
+
+type BoxSerialize = Box<dyn erased_serde::Serialize>;
+
+/// Robustly deserialize all prefixes of the input as RON.
+///
+/// The output is deduplicated -- only inputs are shown where the output changes.
+///
+/// We also check if our wrapping of [`ron`] on the whole string matches
+/// [`ron`] without wrapping.
+#[allow(clippy::type_complexity)]
+pub(crate) fn run_ron_modes_on_prefixes_and_format_outputs<
+    'input,
+    T: for<'de> Deserialize<'de> + Serialize + Debug + PartialEq + 'static,
+>(
+    modes: &[(&'static str, Options<RonExtraOptions>)],
+    full_input: &'input str,
+) -> IndexMap<&'input str, IndexMap<Cow<'input, str>, BoxSerialize>> {
+    let reference_official: Option<T> = ron::from_str(full_input).ok();
+
+    modes
+        .iter()
+        .map(|(mode_desc, options)| {
+            let inputs_outputs = run_on_prefixes_and_format_outputs(full_input.as_bytes(), |inp| {
+                // Leave out a partial UTF-8 character.
+                let inp = match std::str::from_utf8(inp) {
+                    Ok(inp) => inp,
+                    Err(err) => std::str::from_utf8(&inp[..err.valid_up_to()]).unwrap(),
+                };
+                options
+                    .clone()
+                    .deserialize_from_ron_str::<T>(Cow::Borrowed(inp))
+                    .map_err(|err| err.to_string())
+            });
+
+            let last_output_matches_ron_friendly =
+                match reference_official.as_ref().map(|reference| {
+                    Ok(reference)
+                        == inputs_outputs
+                            .last()
+                            .expect("every slice has a prefix")
+                            .1
+                            .as_ref()
+                }) {
+                    Some(true) => None,
+                    Some(false) => Some("no"),
+                    None => Some("ron failed"),
+                };
+            let trailing_line = (last_output_matches_ron_friendly).map(|friendly| {
+                (
+                    Cow::Borrowed("final output matches ron?"),
+                    Box::new(friendly) as BoxSerialize,
+                )
+            });
+
+            let lines: IndexMap<_, _> = inputs_outputs
+                .into_iter()
+                .map(|(input, output)| -> (_, Box<dyn erased_serde::Serialize>) {
+                    (input, Box::new(output))
+                })
+                .chain(trailing_line)
+                .collect();
+
+            (*mode_desc, lines)
+        })
+        .collect()
+}
+
+fn default_modes() -> Vec<(
+    &'static str,
+    Options<deser_incomplete::options::RonExtraOptions>,
+)> {
+    vec![
+        ("default behavior", Options::new_ron()),
+        (
+            "default behavior except no randomized trailer",
+            deser_incomplete::Options::new_ron().disable_random_tag(),
+        ),
+        (
+            "default behavior, 0 backtracks",
+            Options::new_ron().with_max_n_backtracks(Some(0)),
+        ),
+        (
+            "no fallbacks, 0 backtracks",
+            Options::new_ron()
+                .custom_behavior(UnstableCustomBehavior::default().no_fallbacks())
+                .with_max_n_backtracks(Some(0)),
+        ),
+        (
+            "no fallbacks, 1 backtracks",
+            Options::new_ron()
+                .custom_behavior(UnstableCustomBehavior::default().no_fallbacks())
+                .with_max_n_backtracks(Some(1)),
+        ),
+        (
+            "default behavior, 1 backtracks",
+            Options::new_ron().with_max_n_backtracks(Some(1)),
+        ),
+        (
+            "strict behavior",
+            Options::new_ron().custom_behavior(UnstableCustomBehavior::strict()),
+        ),
+    ]
+}
@@ -0,0 +1,369 @@
+use serde::{Deserialize, Serialize};
+
+use super::{default_modes, run_ron_modes_on_prefixes_and_format_outputs};
+
+#[test]
+fn test_enum() {
+    #[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+    enum External {
+        Unit,
+        Newtype(String),
+        Tuple(String, #[serde(default)] String),
+        Struct {
+            a: String,
+            #[serde(default)]
+            b: String,
+        },
+    }
+
+    insta::assert_ron_snapshot!(
+        run_ron_modes_on_prefixes_and_format_outputs::<Vec<External>>(&default_modes(), r#"[Unit, Newtype("az"), Tuple("az", "by"), Struct(a: "az", b: "by")]"#),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok([]),
+        "[Unit,": Ok([
+          Unit,
+        ]),
+        "[Unit, Newtype(\"": Ok([
+          Unit,
+          Newtype(""),
+        ]),
+        "[Unit, Newtype(\"a": Ok([
+          Unit,
+          Newtype("a"),
+        ]),
+        "[Unit, Newtype(\"az": Ok([
+          Unit,
+          Newtype("az"),
+        ]),
+        "[Unit, Newtype(\"az\"), Tuple(\"": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("", ""),
+        ]),
+        "[Unit, Newtype(\"az\"), Tuple(\"a": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("a", ""),
+        ]),
+        "[Unit, Newtype(\"az\"), Tuple(\"az": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", ""),
+        ]),
+        "[Unit, Newtype(\"az\"), Tuple(\"az\", \"b": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "b"),
+        ]),
+        "[Unit, Newtype(\"az\"), Tuple(\"az\", \"by": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+        ]),
+        "[Unit, Newtype(\"az\"), Tuple(\"az\", \"by\"), Struct(a: \"": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "",
+            b: "",
+          ),
+        ]),
+        "[Unit, Newtype(\"az\"), Tuple(\"az\", \"by\"), Struct(a: \"a": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "a",
+            b: "",
+          ),
+        ]),
+        "[Unit, Newtype(\"az\"), Tuple(\"az\", \"by\"), Struct(a: \"az": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "",
+          ),
+        ]),
+        "[Unit, Newtype(\"az\"), Tuple(\"az\", \"by\"), Struct(a: \"az\", b: \"b": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "b",
+          ),
+        ]),
+        "[Unit, Newtype(\"az\"), Tuple(\"az\", \"by\"), Struct(a: \"az\", b: \"by": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "by",
+          ),
+        ]),
+      },
+      "default behavior except no randomized trailer": {
+        "": Ok([]),
+        "[Unit": Ok([
+          Unit,
+        ]),
+        "[Unit, Newtype(\"az\")": Ok([
+          Unit,
+          Newtype("az"),
+        ]),
+        "[Unit, Newtype(\"az\"), Tuple(\"az\", \"by\")": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+        ]),
+        "[Unit, Newtype(\"az\"), Tuple(\"az\", \"by\"), Struct(a: \"az\"": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "",
+          ),
+        ]),
+        "[Unit, Newtype(\"az\"), Tuple(\"az\", \"by\"), Struct(a: \"az\", b: \"by\"": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "by",
+          ),
+        ]),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok([]),
+        "[": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[Unit, Newtype(\"az\"), Tuple(\"az\", \"by\"), Struct(a: \"az\", b: \"by\")]": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "by",
+          ),
+        ]),
+      },
+      "no fallbacks, 0 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "[": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[Unit, Newtype(\"az\"), Tuple(\"az\", \"by\"), Struct(a: \"az\", b: \"by\")]": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "by",
+          ),
+        ]),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "[": Ok([]),
+        "[Unit,": Ok([
+          Unit,
+        ]),
+        "[Unit, Newtype(\"": Ok([
+          Unit,
+          Newtype(""),
+        ]),
+        "[Unit, Newtype(\"a": Ok([
+          Unit,
+          Newtype("a"),
+        ]),
+        "[Unit, Newtype(\"az": Ok([
+          Unit,
+          Newtype("az"),
+        ]),
+        "[Unit, Newtype(\"az\"), Tuple(": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[Unit, Newtype(\"az\"), Tuple(\"": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("", ""),
+        ]),
+        "[Unit, Newtype(\"az\"), Tuple(\"a": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("a", ""),
+        ]),
+        "[Unit, Newtype(\"az\"), Tuple(\"az": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", ""),
+        ]),
+        "[Unit, Newtype(\"az\"), Tuple(\"az\", \"b": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "b"),
+        ]),
+        "[Unit, Newtype(\"az\"), Tuple(\"az\", \"by": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+        ]),
+        "[Unit, Newtype(\"az\"), Tuple(\"az\", \"by\"), Struct(": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[Unit, Newtype(\"az\"), Tuple(\"az\", \"by\"), Struct(a: \"": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "",
+            b: "",
+          ),
+        ]),
+        "[Unit, Newtype(\"az\"), Tuple(\"az\", \"by\"), Struct(a: \"a": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "a",
+            b: "",
+          ),
+        ]),
+        "[Unit, Newtype(\"az\"), Tuple(\"az\", \"by\"), Struct(a: \"az": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "",
+          ),
+        ]),
+        "[Unit, Newtype(\"az\"), Tuple(\"az\", \"by\"), Struct(a: \"az\", b: \"b": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "b",
+          ),
+        ]),
+        "[Unit, Newtype(\"az\"), Tuple(\"az\", \"by\"), Struct(a: \"az\", b: \"by": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "by",
+          ),
+        ]),
+      },
+      "default behavior, 1 backtracks": {
+        "": Ok([]),
+        "[Unit,": Ok([
+          Unit,
+        ]),
+        "[Unit, Newtype(\"": Ok([
+          Unit,
+          Newtype(""),
+        ]),
+        "[Unit, Newtype(\"a": Ok([
+          Unit,
+          Newtype("a"),
+        ]),
+        "[Unit, Newtype(\"az": Ok([
+          Unit,
+          Newtype("az"),
+        ]),
+        "[Unit, Newtype(\"az\"), Tuple(": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[Unit, Newtype(\"az\"), Tuple(\"": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("", ""),
+        ]),
+        "[Unit, Newtype(\"az\"), Tuple(\"a": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("a", ""),
+        ]),
+        "[Unit, Newtype(\"az\"), Tuple(\"az": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", ""),
+        ]),
+        "[Unit, Newtype(\"az\"), Tuple(\"az\", \"b": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "b"),
+        ]),
+        "[Unit, Newtype(\"az\"), Tuple(\"az\", \"by": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+        ]),
+        "[Unit, Newtype(\"az\"), Tuple(\"az\", \"by\"), Struct(": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[Unit, Newtype(\"az\"), Tuple(\"az\", \"by\"), Struct(a: \"": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "",
+            b: "",
+          ),
+        ]),
+        "[Unit, Newtype(\"az\"), Tuple(\"az\", \"by\"), Struct(a: \"a": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "a",
+            b: "",
+          ),
+        ]),
+        "[Unit, Newtype(\"az\"), Tuple(\"az\", \"by\"), Struct(a: \"az": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "",
+          ),
+        ]),
+        "[Unit, Newtype(\"az\"), Tuple(\"az\", \"by\"), Struct(a: \"az\", b: \"b": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "b",
+          ),
+        ]),
+        "[Unit, Newtype(\"az\"), Tuple(\"az\", \"by\"), Struct(a: \"az\", b: \"by": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "by",
+          ),
+        ]),
+      },
+      "strict behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "[Unit, Newtype(\"az\"), Tuple(": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 1 backtracks)"),
+        "[Unit, Newtype(\"az\"), Tuple(\"": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "[Unit, Newtype(\"az\"), Tuple(\"az\", \"by\"), Struct(": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 1 backtracks)"),
+        "[Unit, Newtype(\"az\"), Tuple(\"az\", \"by\"), Struct(a: \"az\", b: \"by\")": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "[Unit, Newtype(\"az\"), Tuple(\"az\", \"by\"), Struct(a: \"az\", b: \"by\")]": Ok([
+          Unit,
+          Newtype("az"),
+          Tuple("az", "by"),
+          Struct(
+            a: "az",
+            b: "by",
+          ),
+        ]),
+      },
+    }
+    "###)
+}
@@ -0,0 +1,149 @@
+use std::collections::BTreeMap;
+
+use super::{default_modes, run_ron_modes_on_prefixes_and_format_outputs};
+
+#[test]
+fn test_map() {
+    insta::assert_ron_snapshot!(
+        run_ron_modes_on_prefixes_and_format_outputs::<BTreeMap<String, Vec<bool>>>(&default_modes(), r#"{"ab": [true], "cd": [false]}"#),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok({}),
+        "{\"ab\":": Ok({
+          "ab": [],
+        }),
+        "{\"ab\": [true": Ok({
+          "ab": [
+            true,
+          ],
+        }),
+        "{\"ab\": [true], \"cd\":": Ok({
+          "ab": [
+            true,
+          ],
+          "cd": [],
+        }),
+        "{\"ab\": [true], \"cd\": [false": Ok({
+          "ab": [
+            true,
+          ],
+          "cd": [
+            false,
+          ],
+        }),
+      },
+      "default behavior except no randomized trailer": {
+        "": Ok({}),
+        "{\"ab\":": Ok({
+          "ab": [],
+        }),
+        "{\"ab\": [true": Ok({
+          "ab": [
+            true,
+          ],
+        }),
+        "{\"ab\": [true], \"cd\":": Ok({
+          "ab": [
+            true,
+          ],
+          "cd": [],
+        }),
+        "{\"ab\": [true], \"cd\": [false": Ok({
+          "ab": [
+            true,
+          ],
+          "cd": [
+            false,
+          ],
+        }),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok({}),
+        "{": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "{\"ab\": [true], \"cd\": [false]}": Ok({
+          "ab": [
+            true,
+          ],
+          "cd": [
+            false,
+          ],
+        }),
+      },
+      "no fallbacks, 0 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "{": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "{\"ab\": [true], \"cd\": [false]}": Ok({
+          "ab": [
+            true,
+          ],
+          "cd": [
+            false,
+          ],
+        }),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "{": Ok({}),
+        "{\"ab\":": Ok({
+          "ab": [],
+        }),
+        "{\"ab\": [true": Ok({
+          "ab": [
+            true,
+          ],
+        }),
+        "{\"ab\": [true], \"cd\":": Ok({
+          "ab": [
+            true,
+          ],
+          "cd": [],
+        }),
+        "{\"ab\": [true], \"cd\": [false": Ok({
+          "ab": [
+            true,
+          ],
+          "cd": [
+            false,
+          ],
+        }),
+      },
+      "default behavior, 1 backtracks": {
+        "": Ok({}),
+        "{\"ab\":": Ok({
+          "ab": [],
+        }),
+        "{\"ab\": [true": Ok({
+          "ab": [
+            true,
+          ],
+        }),
+        "{\"ab\": [true], \"cd\":": Ok({
+          "ab": [
+            true,
+          ],
+          "cd": [],
+        }),
+        "{\"ab\": [true], \"cd\": [false": Ok({
+          "ab": [
+            true,
+          ],
+          "cd": [
+            false,
+          ],
+        }),
+      },
+      "strict behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "{\"ab\": [true], \"cd\": [false]}": Ok({
+          "ab": [
+            true,
+          ],
+          "cd": [
+            false,
+          ],
+        }),
+      },
+    }
+    "###)
+}
@@ -0,0 +1,291 @@
+use super::{default_modes, run_ron_modes_on_prefixes_and_format_outputs};
+
+#[test]
+fn test_seq() {
+    insta::assert_ron_snapshot!(
+        run_ron_modes_on_prefixes_and_format_outputs::<Vec<Vec<u32>>>(&default_modes(), "[[1, 23], [], [4,],]"),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok([]),
+        "[[": Ok([
+          [],
+        ]),
+        "[[1": Ok([
+          [
+            1,
+          ],
+        ]),
+        "[[1, 2": Ok([
+          [
+            1,
+            2,
+          ],
+        ]),
+        "[[1, 23": Ok([
+          [
+            1,
+            23,
+          ],
+        ]),
+        "[[1, 23], [": Ok([
+          [
+            1,
+            23,
+          ],
+          [],
+        ]),
+        "[[1, 23], [], [": Ok([
+          [
+            1,
+            23,
+          ],
+          [],
+          [],
+        ]),
+        "[[1, 23], [], [4": Ok([
+          [
+            1,
+            23,
+          ],
+          [],
+          [
+            4,
+          ],
+        ]),
+      },
+      "default behavior except no randomized trailer": {
+        "": Ok([]),
+        "[[": Ok([
+          [],
+        ]),
+        "[[1": Ok([
+          [
+            1,
+          ],
+        ]),
+        "[[1, 2": Ok([
+          [
+            1,
+            2,
+          ],
+        ]),
+        "[[1, 23": Ok([
+          [
+            1,
+            23,
+          ],
+        ]),
+        "[[1, 23], [": Ok([
+          [
+            1,
+            23,
+          ],
+          [],
+        ]),
+        "[[1, 23], [], [": Ok([
+          [
+            1,
+            23,
+          ],
+          [],
+          [],
+        ]),
+        "[[1, 23], [], [4": Ok([
+          [
+            1,
+            23,
+          ],
+          [],
+          [
+            4,
+          ],
+        ]),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok([]),
+        "[": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[[1, 23], [], [4,],]": Ok([
+          [
+            1,
+            23,
+          ],
+          [],
+          [
+            4,
+          ],
+        ]),
+      },
+      "no fallbacks, 0 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "[": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[[1, 23], [], [4,],]": Ok([
+          [
+            1,
+            23,
+          ],
+          [],
+          [
+            4,
+          ],
+        ]),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "[": Ok([]),
+        "[[": Ok([
+          [],
+        ]),
+        "[[1": Ok([
+          [
+            1,
+          ],
+        ]),
+        "[[1, 2": Ok([
+          [
+            1,
+            2,
+          ],
+        ]),
+        "[[1, 23": Ok([
+          [
+            1,
+            23,
+          ],
+        ]),
+        "[[1, 23], [": Ok([
+          [
+            1,
+            23,
+          ],
+          [],
+        ]),
+        "[[1, 23], [], [": Ok([
+          [
+            1,
+            23,
+          ],
+          [],
+          [],
+        ]),
+        "[[1, 23], [], [4": Ok([
+          [
+            1,
+            23,
+          ],
+          [],
+          [
+            4,
+          ],
+        ]),
+      },
+      "default behavior, 1 backtracks": {
+        "": Ok([]),
+        "[[": Ok([
+          [],
+        ]),
+        "[[1": Ok([
+          [
+            1,
+          ],
+        ]),
+        "[[1, 2": Ok([
+          [
+            1,
+            2,
+          ],
+        ]),
+        "[[1, 23": Ok([
+          [
+            1,
+            23,
+          ],
+        ]),
+        "[[1, 23], [": Ok([
+          [
+            1,
+            23,
+          ],
+          [],
+        ]),
+        "[[1, 23], [], [": Ok([
+          [
+            1,
+            23,
+          ],
+          [],
+          [],
+        ]),
+        "[[1, 23], [], [4": Ok([
+          [
+            1,
+            23,
+          ],
+          [],
+          [
+            4,
+          ],
+        ]),
+      },
+      "strict behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "[[1, 23], [], [4,],]": Ok([
+          [
+            1,
+            23,
+          ],
+          [],
+          [
+            4,
+          ],
+        ]),
+      },
+    }
+    "###)
+}
+
+#[test]
+fn test_tuple() {
+    insta::assert_ron_snapshot!(
+        run_ron_modes_on_prefixes_and_format_outputs::<(bool, char, Option<u8>)>(&default_modes(), "(true, 'x', Some(5))"),
+        @r###"
+    {
+      "default behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "(true, \'x\',": Ok((true, 'x', None)),
+        "(true, \'x\', Some(": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "(true, \'x\', Some(5": Ok((true, 'x', Some(5))),
+      },
+      "default behavior except no randomized trailer": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "(true, \'x\', S": Ok((true, 'x', None)),
+        "(true, \'x\', Some(": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "(true, \'x\', Some(5": Ok((true, 'x', Some(5))),
+      },
+      "default behavior, 0 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "(true, \'x\',": Ok((true, 'x', None)),
+        "(true, \'x\', Some(": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "(true, \'x\', Some(5": Ok((true, 'x', Some(5))),
+      },
+      "no fallbacks, 0 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "(true, \'x\', Some(5": Ok((true, 'x', Some(5))),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "(true, \'x\', Some(5": Ok((true, 'x', Some(5))),
+      },
+      "default behavior, 1 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "(true, \'x\',": Ok((true, 'x', None)),
+        "(true, \'x\', Some(": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "(true, \'x\', Some(5": Ok((true, 'x', Some(5))),
+      },
+      "strict behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "(true, \'x\', Some(5))": Ok((true, 'x', Some(5))),
+      },
+    }
+    "###)
+}
@@ -0,0 +1,408 @@
+use super::{default_modes, run_ron_modes_on_prefixes_and_format_outputs};
+
+#[test]
+fn test_string() {
+    insta::assert_ron_snapshot!(
+        run_ron_modes_on_prefixes_and_format_outputs::<Vec<String>>(&default_modes(), r#"["abc", "d\"\u{e9}f"]"#),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok([]),
+        "[\"": Ok([
+          "",
+        ]),
+        "[\"a": Ok([
+          "a",
+        ]),
+        "[\"ab": Ok([
+          "ab",
+        ]),
+        "[\"abc": Ok([
+          "abc",
+        ]),
+        "[\"abc\", \"": Ok([
+          "abc",
+          "",
+        ]),
+        "[\"abc\", \"d": Ok([
+          "abc",
+          "d",
+        ]),
+        "[\"abc\", \"d\\\"": Ok([
+          "abc",
+          "d\"",
+        ]),
+        "[\"abc\", \"d\\\"\\u": Ok([
+          "abc",
+        ]),
+        "[\"abc\", \"d\\\"\\u{e9}": Ok([
+          "abc",
+          "d\"é",
+        ]),
+        "[\"abc\", \"d\\\"\\u{e9}f": Ok([
+          "abc",
+          "d\"éf",
+        ]),
+      },
+      "default behavior except no randomized trailer": {
+        "": Ok([]),
+        "[\"abc\"": Ok([
+          "abc",
+        ]),
+        "[\"abc\", \"d\\\"\\u{e9}f\"": Ok([
+          "abc",
+          "d\"éf",
+        ]),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok([]),
+        "[": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[\"abc\", \"d\\\"\\u{e9}f\"]": Ok([
+          "abc",
+          "d\"éf",
+        ]),
+      },
+      "no fallbacks, 0 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "[": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[\"abc\", \"d\\\"\\u{e9}f\"]": Ok([
+          "abc",
+          "d\"éf",
+        ]),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "[": Ok([]),
+        "[\"": Ok([
+          "",
+        ]),
+        "[\"a": Ok([
+          "a",
+        ]),
+        "[\"ab": Ok([
+          "ab",
+        ]),
+        "[\"abc": Ok([
+          "abc",
+        ]),
+        "[\"abc\", \"": Ok([
+          "abc",
+          "",
+        ]),
+        "[\"abc\", \"d": Ok([
+          "abc",
+          "d",
+        ]),
+        "[\"abc\", \"d\\\"": Ok([
+          "abc",
+          "d\"",
+        ]),
+        "[\"abc\", \"d\\\"\\u": Ok([
+          "abc",
+        ]),
+        "[\"abc\", \"d\\\"\\u{e9}": Ok([
+          "abc",
+          "d\"é",
+        ]),
+        "[\"abc\", \"d\\\"\\u{e9}f": Ok([
+          "abc",
+          "d\"éf",
+        ]),
+      },
+      "default behavior, 1 backtracks": {
+        "": Ok([]),
+        "[\"": Ok([
+          "",
+        ]),
+        "[\"a": Ok([
+          "a",
+        ]),
+        "[\"ab": Ok([
+          "ab",
+        ]),
+        "[\"abc": Ok([
+          "abc",
+        ]),
+        "[\"abc\", \"": Ok([
+          "abc",
+          "",
+        ]),
+        "[\"abc\", \"d": Ok([
+          "abc",
+          "d",
+        ]),
+        "[\"abc\", \"d\\\"": Ok([
+          "abc",
+          "d\"",
+        ]),
+        "[\"abc\", \"d\\\"\\u": Ok([
+          "abc",
+        ]),
+        "[\"abc\", \"d\\\"\\u{e9}": Ok([
+          "abc",
+          "d\"é",
+        ]),
+        "[\"abc\", \"d\\\"\\u{e9}f": Ok([
+          "abc",
+          "d\"éf",
+        ]),
+      },
+      "strict behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "[\"abc\", \"d\\\"\\u{e9}f\"]": Ok([
+          "abc",
+          "d\"éf",
+        ]),
+      },
+    }
+    "###)
+}
+
+#[test]
+fn test_raw_string() {
+    insta::assert_ron_snapshot!(
+        run_ron_modes_on_prefixes_and_format_outputs::<Vec<String>>(&default_modes(), r###"[r"a\b", r##"c"#d"##]"###),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok([]),
+        "[r\"": Ok([
+          "",
+        ]),
+        "[r\"a": Ok([
+          "a",
+        ]),
+        "[r\"a\\": Ok([
+          "a\\",
+        ]),
+        "[r\"a\\b": Ok([
+          "a\\b",
+        ]),
+        "[r\"a\\b\", r##\"": Ok([
+          "a\\b",
+          "",
+        ]),
+        "[r\"a\\b\", r##\"c": Ok([
+          "a\\b",
+          "c",
+        ]),
+        "[r\"a\\b\", r##\"c\"": Ok([
+          "a\\b",
+          "c\"",
+        ]),
+        "[r\"a\\b\", r##\"c\"#": Ok([
+          "a\\b",
+          "c\"#",
+        ]),
+        "[r\"a\\b\", r##\"c\"#d": Ok([
+          "a\\b",
+          "c\"#d",
+        ]),
+        "[r\"a\\b\", r##\"c\"#d\"": Ok([
+          "a\\b",
+          "c\"#d\"",
+        ]),
+        "[r\"a\\b\", r##\"c\"#d\"#": Ok([
+          "a\\b",
+          "c\"#d\"#",
+        ]),
+        "[r\"a\\b\", r##\"c\"#d\"##": Ok([
+          "a\\b",
+          "c\"#d",
+        ]),
+      },
+      "default behavior except no randomized trailer": {
+        "": Ok([]),
+        "[r\"a\\b\"": Ok([
+          "a\\b",
+        ]),
+        "[r\"a\\b\", r##\"c\"#d\"##": Ok([
+          "a\\b",
+          "c\"#d",
+        ]),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok([]),
+        "[": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[r\"a\\b\", r##\"c\"#d\"##]": Ok([
+          "a\\b",
+          "c\"#d",
+        ]),
+      },
+      "no fallbacks, 0 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "[": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[r\"a\\b\", r##\"c\"#d\"##]": Ok([
+          "a\\b",
+          "c\"#d",
+        ]),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "[": Ok([]),
+        "[r\"": Ok([
+          "",
+        ]),
+        "[r\"a": Ok([
+          "a",
+        ]),
+        "[r\"a\\": Ok([
+          "a\\",
+        ]),
+        "[r\"a\\b": Ok([
+          "a\\b",
+        ]),
+        "[r\"a\\b\", r##\"": Ok([
+          "a\\b",
+          "",
+        ]),
+        "[r\"a\\b\", r##\"c": Ok([
+          "a\\b",
+          "c",
+        ]),
+        "[r\"a\\b\", r##\"c\"": Ok([
+          "a\\b",
+          "c\"",
+        ]),
+        "[r\"a\\b\", r##\"c\"#": Ok([
+          "a\\b",
+          "c\"#",
+        ]),
+        "[r\"a\\b\", r##\"c\"#d": Ok([
+          "a\\b",
+          "c\"#d",
+        ]),
+        "[r\"a\\b\", r##\"c\"#d\"": Ok([
+          "a\\b",
+          "c\"#d\"",
+        ]),
+        "[r\"a\\b\", r##\"c\"#d\"#": Ok([
+          "a\\b",
+          "c\"#d\"#",
+        ]),
+        "[r\"a\\b\", r##\"c\"#d\"##": Ok([
+          "a\\b",
+          "c\"#d",
+        ]),
+      },
+      "default behavior, 1 backtracks": {
+        "": Ok([]),
+        "[r\"": Ok([
+          "",
+        ]),
+        "[r\"a": Ok([
+          "a",
+        ]),
+        "[r\"a\\": Ok([
+          "a\\",
+        ]),
+        "[r\"a\\b": Ok([
+          "a\\b",
+        ]),
+        "[r\"a\\b\", r##\"": Ok([
+          "a\\b",
+          "",
+        ]),
+        "[r\"a\\b\", r##\"c": Ok([
+          "a\\b",
+          "c",
+        ]),
+        "[r\"a\\b\", r##\"c\"": Ok([
+          "a\\b",
+          "c\"",
+        ]),
+        "[r\"a\\b\", r##\"c\"#": Ok([
+          "a\\b",
+          "c\"#",
+        ]),
+        "[r\"a\\b\", r##\"c\"#d": Ok([
+          "a\\b",
+          "c\"#d",
+        ]),
+        "[r\"a\\b\", r##\"c\"#d\"": Ok([
+          "a\\b",
+          "c\"#d\"",
+        ]),
+        "[r\"a\\b\", r##\"c\"#d\"#": Ok([
+          "a\\b",
+          "c\"#d\"#",
+        ]),
+        "[r\"a\\b\", r##\"c\"#d\"##": Ok([
+          "a\\b",
+          "c\"#d",
+        ]),
+      },
+      "strict behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "[r\"a\\b\", r##\"c\"#d\"##]": Ok([
+          "a\\b",
+          "c\"#d",
+        ]),
+      },
+    }
+    "###)
+}
+
+#[test]
+fn test_string_after_comments() {
+    insta::assert_ron_snapshot!(
+        run_ron_modes_on_prefixes_and_format_outputs::<Vec<String>>(&default_modes(), "/* a */ [ // b\n\"c\"]"),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok([]),
+        "/* a */ [ // b\n\"": Ok([
+          "",
+        ]),
+        "/* a */ [ // b\n\"c": Ok([
+          "c",
+        ]),
+      },
+      "default behavior except no randomized trailer": {
+        "": Ok([]),
+        "/* a */ [ // b\n\"c\"": Ok([
+          "c",
+        ]),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok([]),
+        "/* a */ [": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "/* a */ [ // b\n\"c\"]": Ok([
+          "c",
+        ]),
+      },
+      "no fallbacks, 0 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "/* a */ [": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "/* a */ [ // b\n\"c\"]": Ok([
+          "c",
+        ]),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "/* a */ [": Ok([]),
+        "/* a */ [ // b\n\"": Ok([
+          "",
+        ]),
+        "/* a */ [ // b\n\"c": Ok([
+          "c",
+        ]),
+      },
+      "default behavior, 1 backtracks": {
+        "": Ok([]),
+        "/* a */ [ // b\n\"": Ok([
+          "",
+        ]),
+        "/* a */ [ // b\n\"c": Ok([
+          "c",
+        ]),
+      },
+      "strict behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "/* a */ [ // b\n\"c\"]": Ok([
+          "c",
+        ]),
+      },
+    }
+    "###)
+}
@@ -0,0 +1,440 @@
+use serde::{Deserialize, Serialize};
+
+use super::{default_modes, run_ron_modes_on_prefixes_and_format_outputs};
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+struct Point {
+    x: i32,
+    #[serde(default)]
+    label: String,
+}
+
+#[test]
+fn test_named_struct() {
+    insta::assert_ron_snapshot!(
+        run_ron_modes_on_prefixes_and_format_outputs::<Vec<Point>>(&default_modes(), r#"[Point(x: 1, label: "a"), Point(x: -2)]"#),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok([]),
+        "[Point(x: 1": Ok([
+          Point(
+            x: 1,
+            label: "",
+          ),
+        ]),
+        "[Point(x: 1, label: \"a": Ok([
+          Point(
+            x: 1,
+            label: "a",
+          ),
+        ]),
+        "[Point(x: 1, label: \"a\"), Point(x: -2": Ok([
+          Point(
+            x: 1,
+            label: "a",
+          ),
+          Point(
+            x: -2,
+            label: "",
+          ),
+        ]),
+      },
+      "default behavior except no randomized trailer": {
+        "": Ok([]),
+        "[Point(x: 1": Ok([
+          Point(
+            x: 1,
+            label: "",
+          ),
+        ]),
+        "[Point(x: 1, label: \"a\"": Ok([
+          Point(
+            x: 1,
+            label: "a",
+          ),
+        ]),
+        "[Point(x: 1, label: \"a\"), Point(x: -2": Ok([
+          Point(
+            x: 1,
+            label: "a",
+          ),
+          Point(
+            x: -2,
+            label: "",
+          ),
+        ]),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok([]),
+        "[": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[Point(x: 1": Ok([
+          Point(
+            x: 1,
+            label: "",
+          ),
+        ]),
+        "[Point(x: 1, label:": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[Point(x: 1, label: \"": Ok([
+          Point(
+            x: 1,
+            label: "",
+          ),
+        ]),
+        "[Point(x: 1, label: \"a": Ok([
+          Point(
+            x: 1,
+            label: "a",
+          ),
+        ]),
+        "[Point(x: 1, label: \"a\")": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[Point(x: 1, label: \"a\"), Point(x: -2": Ok([
+          Point(
+            x: 1,
+            label: "a",
+          ),
+          Point(
+            x: -2,
+            label: "",
+          ),
+        ]),
+        "[Point(x: 1, label: \"a\"), Point(x: -2)": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[Point(x: 1, label: \"a\"), Point(x: -2)]": Ok([
+          Point(
+            x: 1,
+            label: "a",
+          ),
+          Point(
+            x: -2,
+            label: "",
+          ),
+        ]),
+      },
+      "no fallbacks, 0 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "[": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[Point(x: 1, label: \"a\"), Point(x: -2)]": Ok([
+          Point(
+            x: 1,
+            label: "a",
+          ),
+          Point(
+            x: -2,
+            label: "",
+          ),
+        ]),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "[": Ok([]),
+        "[Point(": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[Point(x: 1": Ok([
+          Point(
+            x: 1,
+            label: "",
+          ),
+        ]),
+        "[Point(x: 1, label: \"a": Ok([
+          Point(
+            x: 1,
+            label: "a",
+          ),
+        ]),
+        "[Point(x: 1, label: \"a\"), Point(": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[Point(x: 1, label: \"a\"), Point(x: -2": Ok([
+          Point(
+            x: 1,
+            label: "a",
+          ),
+          Point(
+            x: -2,
+            label: "",
+          ),
+        ]),
+      },
+      "default behavior, 1 backtracks": {
+        "": Ok([]),
+        "[Point(": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[Point(x: 1": Ok([
+          Point(
+            x: 1,
+            label: "",
+          ),
+        ]),
+        "[Point(x: 1, label: \"a": Ok([
+          Point(
+            x: 1,
+            label: "a",
+          ),
+        ]),
+        "[Point(x: 1, label: \"a\"), Point(": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[Point(x: 1, label: \"a\"), Point(x: -2": Ok([
+          Point(
+            x: 1,
+            label: "a",
+          ),
+          Point(
+            x: -2,
+            label: "",
+          ),
+        ]),
+      },
+      "strict behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "[Point(x: 1, label: \"a\"), Point(x: -2)]": Ok([
+          Point(
+            x: 1,
+            label: "a",
+          ),
+          Point(
+            x: -2,
+            label: "",
+          ),
+        ]),
+      },
+    }
+    "###)
+}
+
+#[test]
+fn test_anonymous_struct() {
+    insta::assert_ron_snapshot!(
+        run_ron_modes_on_prefixes_and_format_outputs::<Vec<Point>>(&default_modes(), r#"[(label: "a", x: 1), (x: 2)]"#),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok([]),
+        "[(label: \"a\", x: 1": Ok([
+          Point(
+            x: 1,
+            label: "a",
+          ),
+        ]),
+        "[(label: \"a\", x: 1), (x: 2": Ok([
+          Point(
+            x: 1,
+            label: "a",
+          ),
+          Point(
+            x: 2,
+            label: "",
+          ),
+        ]),
+      },
+      "default behavior except no randomized trailer": {
+        "": Ok([]),
+        "[(label: \"a\", x: 1": Ok([
+          Point(
+            x: 1,
+            label: "a",
+          ),
+        ]),
+        "[(label: \"a\", x: 1), (x: 2": Ok([
+          Point(
+            x: 1,
+            label: "a",
+          ),
+          Point(
+            x: 2,
+            label: "",
+          ),
+        ]),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok([]),
+        "[": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[(label: \"a\", x: 1": Ok([
+          Point(
+            x: 1,
+            label: "a",
+          ),
+        ]),
+        "[(label: \"a\", x: 1)": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[(label: \"a\", x: 1), (x: 2": Ok([
+          Point(
+            x: 1,
+            label: "a",
+          ),
+          Point(
+            x: 2,
+            label: "",
+          ),
+        ]),
+        "[(label: \"a\", x: 1), (x: 2)": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[(label: \"a\", x: 1), (x: 2)]": Ok([
+          Point(
+            x: 1,
+            label: "a",
+          ),
+          Point(
+            x: 2,
+            label: "",
+          ),
+        ]),
+      },
+      "no fallbacks, 0 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "[": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[(label: \"a\", x: 1), (x: 2)]": Ok([
+          Point(
+            x: 1,
+            label: "a",
+          ),
+          Point(
+            x: 2,
+            label: "",
+          ),
+        ]),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "[": Ok([]),
+        "[(": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[(label: \"a\", x: 1": Ok([
+          Point(
+            x: 1,
+            label: "a",
+          ),
+        ]),
+        "[(label: \"a\", x: 1), (": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[(label: \"a\", x: 1), (x: 2": Ok([
+          Point(
+            x: 1,
+            label: "a",
+          ),
+          Point(
+            x: 2,
+            label: "",
+          ),
+        ]),
+      },
+      "default behavior, 1 backtracks": {
+        "": Ok([]),
+        "[(": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[(label: \"a\", x: 1": Ok([
+          Point(
+            x: 1,
+            label: "a",
+          ),
+        ]),
+        "[(label: \"a\", x: 1), (": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[(label: \"a\", x: 1), (x: 2": Ok([
+          Point(
+            x: 1,
+            label: "a",
+          ),
+          Point(
+            x: 2,
+            label: "",
+          ),
+        ]),
+      },
+      "strict behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "[(label: \"a\", x: 1), (x: 2)]": Ok([
+          Point(
+            x: 1,
+            label: "a",
+          ),
+          Point(
+            x: 2,
+            label: "",
+          ),
+        ]),
+      },
+    }
+    "###)
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+struct Pair(u32, #[serde(default)] String);
+
+#[test]
+fn test_tuple_struct() {
+    insta::assert_ron_snapshot!(
+        run_ron_modes_on_prefixes_and_format_outputs::<Vec<Pair>>(&default_modes(), r#"[Pair(1, "a"), (2)]"#),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok([]),
+        "[Pair(1": Ok([
+          Pair(1, ""),
+        ]),
+        "[Pair(1, \"a": Ok([
+          Pair(1, "a"),
+        ]),
+        "[Pair(1, \"a\"), (2": Ok([
+          Pair(1, "a"),
+          Pair(2, ""),
+        ]),
+      },
+      "default behavior except no randomized trailer": {
+        "": Ok([]),
+        "[Pair(1": Ok([
+          Pair(1, ""),
+        ]),
+        "[Pair(1, \"a\"": Ok([
+          Pair(1, "a"),
+        ]),
+        "[Pair(1, \"a\"), (2": Ok([
+          Pair(1, "a"),
+          Pair(2, ""),
+        ]),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok([]),
+        "[": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[Pair(1, \"a\"), (2)]": Ok([
+          Pair(1, "a"),
+          Pair(2, ""),
+        ]),
+      },
+      "no fallbacks, 0 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "[": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[Pair(1, \"a\"), (2)]": Ok([
+          Pair(1, "a"),
+          Pair(2, ""),
+        ]),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "[": Ok([]),
+        "[Pair(": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[Pair(1": Ok([
+          Pair(1, ""),
+        ]),
+        "[Pair(1, \"a": Ok([
+          Pair(1, "a"),
+        ]),
+        "[Pair(1, \"a\"), (": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[Pair(1, \"a\"), (2": Ok([
+          Pair(1, "a"),
+          Pair(2, ""),
+        ]),
+      },
+      "default behavior, 1 backtracks": {
+        "": Ok([]),
+        "[Pair(": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[Pair(1": Ok([
+          Pair(1, ""),
+        ]),
+        "[Pair(1, \"a": Ok([
+          Pair(1, "a"),
+        ]),
+        "[Pair(1, \"a\"), (": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[Pair(1, \"a\"), (2": Ok([
+          Pair(1, "a"),
+          Pair(2, ""),
+        ]),
+      },
+      "strict behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "[Pair(1, \"a\"), (2)]": Ok([
+          Pair(1, "a"),
+          Pair(2, ""),
+        ]),
+      },
+    }
+    "###)
+}
@@ -0,0 +1,256 @@
+use serde::{Deserialize, Serialize};
+
+use super::{default_modes, run_toml_modes_on_prefixes_and_format_outputs};
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+#[serde(default)]
+struct Arrays {
+    a: Vec<u32>,
+    b: Vec<Vec<bool>>,
+}
+
+#[test]
+fn test_array() {
+    insta::assert_ron_snapshot!(
+        run_toml_modes_on_prefixes_and_format_outputs::<Arrays>(&default_modes(), "a = [1, 23, ]"),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok(Arrays(
+          a: [],
+          b: [],
+        )),
+        "a = [1": Ok(Arrays(
+          a: [
+            1,
+          ],
+          b: [],
+        )),
+        "a = [1, 2": Ok(Arrays(
+          a: [
+            1,
+            2,
+          ],
+          b: [],
+        )),
+        "a = [1, 23": Ok(Arrays(
+          a: [
+            1,
+            23,
+          ],
+          b: [],
+        )),
+      },
+      "default behavior except no randomized trailer": {
+        "": Ok(Arrays(
+          a: [],
+          b: [],
+        )),
+        "a = [1, 23, ]": Ok(Arrays(
+          a: [
+            1,
+            23,
+          ],
+          b: [],
+        )),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok(Arrays(
+          a: [],
+          b: [],
+        )),
+        "a ": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "a = [1, 23, ]": Ok(Arrays(
+          a: [
+            1,
+            23,
+          ],
+          b: [],
+        )),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Ok(Arrays(
+          a: [],
+          b: [],
+        )),
+        "a = [1": Ok(Arrays(
+          a: [
+            1,
+          ],
+          b: [],
+        )),
+        "a = [1, 2": Ok(Arrays(
+          a: [
+            1,
+            2,
+          ],
+          b: [],
+        )),
+        "a = [1, 23": Ok(Arrays(
+          a: [
+            1,
+            23,
+          ],
+          b: [],
+        )),
+      },
+      "strict behavior": {
+        "": Ok(Arrays(
+          a: [],
+          b: [],
+        )),
+        "a": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "a = [1, 23, ]": Ok(Arrays(
+          a: [
+            1,
+            23,
+          ],
+          b: [],
+        )),
+      },
+    }
+    "###)
+}
+
+#[test]
+fn test_nested_array() {
+    insta::assert_ron_snapshot!(
+        run_toml_modes_on_prefixes_and_format_outputs::<Arrays>(&default_modes(), "b = [[true], [\n  false, # no\n]]"),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok(Arrays(
+          a: [],
+          b: [],
+        )),
+        "b = [[": Ok(Arrays(
+          a: [],
+          b: [
+            [],
+          ],
+        )),
+        "b = [[true": Ok(Arrays(
+          a: [],
+          b: [
+            [
+              true,
+            ],
+          ],
+        )),
+        "b = [[true], [": Ok(Arrays(
+          a: [],
+          b: [
+            [
+              true,
+            ],
+            [],
+          ],
+        )),
+        "b = [[true], [\n  false": Ok(Arrays(
+          a: [],
+          b: [
+            [
+              true,
+            ],
+            [
+              false,
+            ],
+          ],
+        )),
+      },
+      "default behavior except no randomized trailer": {
+        "": Ok(Arrays(
+          a: [],
+          b: [],
+        )),
+        "b = [[true], [\n  false, # no\n]]": Ok(Arrays(
+          a: [],
+          b: [
+            [
+              true,
+            ],
+            [
+              false,
+            ],
+          ],
+        )),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok(Arrays(
+          a: [],
+          b: [],
+        )),
+        "b ": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "b = [[true], [\n  false, # no\n]]": Ok(Arrays(
+          a: [],
+          b: [
+            [
+              true,
+            ],
+            [
+              false,
+            ],
+          ],
+        )),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Ok(Arrays(
+          a: [],
+          b: [],
+        )),
+        "b = [[": Ok(Arrays(
+          a: [],
+          b: [
+            [],
+          ],
+        )),
+        "b = [[true": Ok(Arrays(
+          a: [],
+          b: [
+            [
+              true,
+            ],
+          ],
+        )),
+        "b = [[true], [": Ok(Arrays(
+          a: [],
+          b: [
+            [
+              true,
+            ],
+            [],
+          ],
+        )),
+        "b = [[true], [\n  false": Ok(Arrays(
+          a: [],
+          b: [
+            [
+              true,
+            ],
+            [
+              false,
+            ],
+          ],
+        )),
+      },
+      "strict behavior": {
+        "": Ok(Arrays(
+          a: [],
+          b: [],
+        )),
+        "b": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "b = [[true], [\n  false, # no\n]]": Ok(Arrays(
+          a: [],
+          b: [
+            [
+              true,
+            ],
+            [
+              false,
+            ],
+          ],
+        )),
+      },
+    }
+    "###)
+}
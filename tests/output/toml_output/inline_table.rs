@@ -0,0 +1,417 @@
+use serde::{Deserialize, Serialize};
+
+use super::{default_modes, run_toml_modes_on_prefixes_and_format_outputs};
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+#[serde(default)]
+struct Outer {
+    point: Point,
+    points: Vec<Point>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+#[serde(default)]
+struct Point {
+    x: i32,
+    #[serde(rename = "y.z")]
+    y_z: i32,
+}
+
+#[test]
+fn test_inline_table() {
+    insta::assert_ron_snapshot!(
+        run_toml_modes_on_prefixes_and_format_outputs::<Outer>(&default_modes(), "point = { x = 1, \"y.z\" = -2 }"),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok(Outer(
+          point: Point(
+            x: 0,
+            r#y.z: 0,
+          ),
+          points: [],
+        )),
+        "point = { x = 1": Ok(Outer(
+          point: Point(
+            x: 1,
+            r#y.z: 0,
+          ),
+          points: [],
+        )),
+        "point = { x = 1, \"y.z\" = -2": Ok(Outer(
+          point: Point(
+            x: 1,
+            r#y.z: -2,
+          ),
+          points: [],
+        )),
+      },
+      "default behavior except no randomized trailer": {
+        "": Ok(Outer(
+          point: Point(
+            x: 0,
+            r#y.z: 0,
+          ),
+          points: [],
+        )),
+        "point = { x = 1, \"y.z\" = -2 }": Ok(Outer(
+          point: Point(
+            x: 1,
+            r#y.z: -2,
+          ),
+          points: [],
+        )),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok(Outer(
+          point: Point(
+            x: 0,
+            r#y.z: 0,
+          ),
+          points: [],
+        )),
+        "point =": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "point = {": Ok(Outer(
+          point: Point(
+            x: 0,
+            r#y.z: 0,
+          ),
+          points: [],
+        )),
+        "point = { x ": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "point = { x = 1": Ok(Outer(
+          point: Point(
+            x: 1,
+            r#y.z: 0,
+          ),
+          points: [],
+        )),
+        "point = { x = 1, \"y.z\"": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "point = { x = 1, \"y.z\" = -2": Ok(Outer(
+          point: Point(
+            x: 1,
+            r#y.z: -2,
+          ),
+          points: [],
+        )),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Ok(Outer(
+          point: Point(
+            x: 0,
+            r#y.z: 0,
+          ),
+          points: [],
+        )),
+        "point = { x = 1": Ok(Outer(
+          point: Point(
+            x: 1,
+            r#y.z: 0,
+          ),
+          points: [],
+        )),
+        "point = { x = 1, \"y.z\" = -2": Ok(Outer(
+          point: Point(
+            x: 1,
+            r#y.z: -2,
+          ),
+          points: [],
+        )),
+      },
+      "strict behavior": {
+        "": Ok(Outer(
+          point: Point(
+            x: 0,
+            r#y.z: 0,
+          ),
+          points: [],
+        )),
+        "p": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "point = { x = 1, \"y.z\" = -2 }": Ok(Outer(
+          point: Point(
+            x: 1,
+            r#y.z: -2,
+          ),
+          points: [],
+        )),
+      },
+    }
+    "###)
+}
+
+#[test]
+fn test_array_of_inline_tables() {
+    insta::assert_ron_snapshot!(
+        run_toml_modes_on_prefixes_and_format_outputs::<Outer>(&default_modes(), "points = [{ x = 1 }, {x=2}]"),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok(Outer(
+          point: Point(
+            x: 0,
+            r#y.z: 0,
+          ),
+          points: [],
+        )),
+        "points = [{": Ok(Outer(
+          point: Point(
+            x: 0,
+            r#y.z: 0,
+          ),
+          points: [
+            Point(
+              x: 0,
+              r#y.z: 0,
+            ),
+          ],
+        )),
+        "points = [{ x = 1": Ok(Outer(
+          point: Point(
+            x: 0,
+            r#y.z: 0,
+          ),
+          points: [
+            Point(
+              x: 1,
+              r#y.z: 0,
+            ),
+          ],
+        )),
+        "points = [{ x = 1 }, {": Ok(Outer(
+          point: Point(
+            x: 0,
+            r#y.z: 0,
+          ),
+          points: [
+            Point(
+              x: 1,
+              r#y.z: 0,
+            ),
+            Point(
+              x: 0,
+              r#y.z: 0,
+            ),
+          ],
+        )),
+        "points = [{ x = 1 }, {x=2": Ok(Outer(
+          point: Point(
+            x: 0,
+            r#y.z: 0,
+          ),
+          points: [
+            Point(
+              x: 1,
+              r#y.z: 0,
+            ),
+            Point(
+              x: 2,
+              r#y.z: 0,
+            ),
+          ],
+        )),
+      },
+      "default behavior except no randomized trailer": {
+        "": Ok(Outer(
+          point: Point(
+            x: 0,
+            r#y.z: 0,
+          ),
+          points: [],
+        )),
+        "points = [{ x = 1 }, {x=2}]": Ok(Outer(
+          point: Point(
+            x: 0,
+            r#y.z: 0,
+          ),
+          points: [
+            Point(
+              x: 1,
+              r#y.z: 0,
+            ),
+            Point(
+              x: 2,
+              r#y.z: 0,
+            ),
+          ],
+        )),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok(Outer(
+          point: Point(
+            x: 0,
+            r#y.z: 0,
+          ),
+          points: [],
+        )),
+        "points ": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "points = [{": Ok(Outer(
+          point: Point(
+            x: 0,
+            r#y.z: 0,
+          ),
+          points: [
+            Point(
+              x: 0,
+              r#y.z: 0,
+            ),
+          ],
+        )),
+        "points = [{ x ": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "points = [{ x = 1": Ok(Outer(
+          point: Point(
+            x: 0,
+            r#y.z: 0,
+          ),
+          points: [
+            Point(
+              x: 1,
+              r#y.z: 0,
+            ),
+          ],
+        )),
+        "points = [{ x = 1 }": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "points = [{ x = 1 }, {": Ok(Outer(
+          point: Point(
+            x: 0,
+            r#y.z: 0,
+          ),
+          points: [
+            Point(
+              x: 1,
+              r#y.z: 0,
+            ),
+            Point(
+              x: 0,
+              r#y.z: 0,
+            ),
+          ],
+        )),
+        "points = [{ x = 1 }, {x=": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "points = [{ x = 1 }, {x=2": Ok(Outer(
+          point: Point(
+            x: 0,
+            r#y.z: 0,
+          ),
+          points: [
+            Point(
+              x: 1,
+              r#y.z: 0,
+            ),
+            Point(
+              x: 2,
+              r#y.z: 0,
+            ),
+          ],
+        )),
+        "points = [{ x = 1 }, {x=2}": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "points = [{ x = 1 }, {x=2}]": Ok(Outer(
+          point: Point(
+            x: 0,
+            r#y.z: 0,
+          ),
+          points: [
+            Point(
+              x: 1,
+              r#y.z: 0,
+            ),
+            Point(
+              x: 2,
+              r#y.z: 0,
+            ),
+          ],
+        )),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Ok(Outer(
+          point: Point(
+            x: 0,
+            r#y.z: 0,
+          ),
+          points: [],
+        )),
+        "points = [{": Ok(Outer(
+          point: Point(
+            x: 0,
+            r#y.z: 0,
+          ),
+          points: [
+            Point(
+              x: 0,
+              r#y.z: 0,
+            ),
+          ],
+        )),
+        "points = [{ x = 1": Ok(Outer(
+          point: Point(
+            x: 0,
+            r#y.z: 0,
+          ),
+          points: [
+            Point(
+              x: 1,
+              r#y.z: 0,
+            ),
+          ],
+        )),
+        "points = [{ x = 1 }, {": Ok(Outer(
+          point: Point(
+            x: 0,
+            r#y.z: 0,
+          ),
+          points: [
+            Point(
+              x: 1,
+              r#y.z: 0,
+            ),
+            Point(
+              x: 0,
+              r#y.z: 0,
+            ),
+          ],
+        )),
+        "points = [{ x = 1 }, {x=2": Ok(Outer(
+          point: Point(
+            x: 0,
+            r#y.z: 0,
+          ),
+          points: [
+            Point(
+              x: 1,
+              r#y.z: 0,
+            ),
+            Point(
+              x: 2,
+              r#y.z: 0,
+            ),
+          ],
+        )),
+      },
+      "strict behavior": {
+        "": Ok(Outer(
+          point: Point(
+            x: 0,
+            r#y.z: 0,
+          ),
+          points: [],
+        )),
+        "p": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "points = [{ x = 1 }, {x=2}]": Ok(Outer(
+          point: Point(
+            x: 0,
+            r#y.z: 0,
+          ),
+          points: [
+            Point(
+              x: 1,
+              r#y.z: 0,
+            ),
+            Point(
+              x: 2,
+              r#y.z: 0,
+            ),
+          ],
+        )),
+      },
+    }
+    "###)
+}
@@ -0,0 +1,590 @@
+use serde::{Deserialize, Serialize};
+
+use super::{default_modes, run_toml_modes_on_prefixes_and_format_outputs};
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+#[serde(default)]
+struct Strings {
+    s: String,
+}
+
+#[test]
+fn test_basic_string() {
+    insta::assert_ron_snapshot!(
+        run_toml_modes_on_prefixes_and_format_outputs::<Strings>(&default_modes(), r#"s = "a\"b\u00e9c""#),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok(Strings(
+          s: "",
+        )),
+        "s = \"a": Ok(Strings(
+          s: "a",
+        )),
+        "s = \"a\\\"": Ok(Strings(
+          s: "a\"",
+        )),
+        "s = \"a\\\"b": Ok(Strings(
+          s: "a\"b",
+        )),
+        "s = \"a\\\"b\\u00e9": Ok(Strings(
+          s: "a\"bé",
+        )),
+        "s = \"a\\\"b\\u00e9c": Ok(Strings(
+          s: "a\"béc",
+        )),
+      },
+      "default behavior except no randomized trailer": {
+        "": Ok(Strings(
+          s: "",
+        )),
+        "s = \"a\\\"b\\u00e9c\"": Ok(Strings(
+          s: "a\"béc",
+        )),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok(Strings(
+          s: "",
+        )),
+        "s ": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "s = \"": Ok(Strings(
+          s: "",
+        )),
+        "s = \"a": Ok(Strings(
+          s: "a",
+        )),
+        "s = \"a\\\"": Ok(Strings(
+          s: "a\"",
+        )),
+        "s = \"a\\\"b": Ok(Strings(
+          s: "a\"b",
+        )),
+        "s = \"a\\\"b\\u00e9": Ok(Strings(
+          s: "a\"bé",
+        )),
+        "s = \"a\\\"b\\u00e9c": Ok(Strings(
+          s: "a\"béc",
+        )),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Ok(Strings(
+          s: "",
+        )),
+        "s = \"a": Ok(Strings(
+          s: "a",
+        )),
+        "s = \"a\\\"": Ok(Strings(
+          s: "a\"",
+        )),
+        "s = \"a\\\"b": Ok(Strings(
+          s: "a\"b",
+        )),
+        "s = \"a\\\"b\\u00e9": Ok(Strings(
+          s: "a\"bé",
+        )),
+        "s = \"a\\\"b\\u00e9c": Ok(Strings(
+          s: "a\"béc",
+        )),
+      },
+      "strict behavior": {
+        "": Ok(Strings(
+          s: "",
+        )),
+        "s": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "s = \"": Ok(Strings(
+          s: "",
+        )),
+        "s = \"a": Ok(Strings(
+          s: "a",
+        )),
+        "s = \"a\\\"": Ok(Strings(
+          s: "a\"",
+        )),
+        "s = \"a\\\"b": Ok(Strings(
+          s: "a\"b",
+        )),
+        "s = \"a\\\"b\\u00e9": Ok(Strings(
+          s: "a\"bé",
+        )),
+        "s = \"a\\\"b\\u00e9c": Ok(Strings(
+          s: "a\"béc",
+        )),
+      },
+    }
+    "###)
+}
+
+#[test]
+fn test_literal_string() {
+    insta::assert_ron_snapshot!(
+        run_toml_modes_on_prefixes_and_format_outputs::<Strings>(&default_modes(), r"s = 'C:\ab'"),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok(Strings(
+          s: "",
+        )),
+        "s = \'C": Ok(Strings(
+          s: "C",
+        )),
+        "s = \'C:": Ok(Strings(
+          s: "C:",
+        )),
+        "s = \'C:\\": Ok(Strings(
+          s: "C:\\",
+        )),
+        "s = \'C:\\a": Ok(Strings(
+          s: "C:\\a",
+        )),
+        "s = \'C:\\ab": Ok(Strings(
+          s: "C:\\ab",
+        )),
+      },
+      "default behavior except no randomized trailer": {
+        "": Ok(Strings(
+          s: "",
+        )),
+        "s = \'C:\\ab\'": Ok(Strings(
+          s: "C:\\ab",
+        )),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok(Strings(
+          s: "",
+        )),
+        "s ": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "s = \'": Ok(Strings(
+          s: "",
+        )),
+        "s = \'C": Ok(Strings(
+          s: "C",
+        )),
+        "s = \'C:": Ok(Strings(
+          s: "C:",
+        )),
+        "s = \'C:\\": Ok(Strings(
+          s: "C:\\",
+        )),
+        "s = \'C:\\a": Ok(Strings(
+          s: "C:\\a",
+        )),
+        "s = \'C:\\ab": Ok(Strings(
+          s: "C:\\ab",
+        )),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Ok(Strings(
+          s: "",
+        )),
+        "s = \'C": Ok(Strings(
+          s: "C",
+        )),
+        "s = \'C:": Ok(Strings(
+          s: "C:",
+        )),
+        "s = \'C:\\": Ok(Strings(
+          s: "C:\\",
+        )),
+        "s = \'C:\\a": Ok(Strings(
+          s: "C:\\a",
+        )),
+        "s = \'C:\\ab": Ok(Strings(
+          s: "C:\\ab",
+        )),
+      },
+      "strict behavior": {
+        "": Ok(Strings(
+          s: "",
+        )),
+        "s": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "s = \'": Ok(Strings(
+          s: "",
+        )),
+        "s = \'C": Ok(Strings(
+          s: "C",
+        )),
+        "s = \'C:": Ok(Strings(
+          s: "C:",
+        )),
+        "s = \'C:\\": Ok(Strings(
+          s: "C:\\",
+        )),
+        "s = \'C:\\a": Ok(Strings(
+          s: "C:\\a",
+        )),
+        "s = \'C:\\ab": Ok(Strings(
+          s: "C:\\ab",
+        )),
+      },
+    }
+    "###)
+}
+
+#[test]
+fn test_multi_line_basic_string() {
+    insta::assert_ron_snapshot!(
+        run_toml_modes_on_prefixes_and_format_outputs::<Strings>(&default_modes(), "s = \"\"\"\na\"\"b \\\n  c\"\"\""),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok(Strings(
+          s: "",
+        )),
+        "s = \"\"\"\na": Ok(Strings(
+          s: "a",
+        )),
+        "s = \"\"\"\na\"": Ok(Strings(
+          s: "a\"",
+        )),
+        "s = \"\"\"\na\"\"": Ok(Strings(
+          s: "a\"\"",
+        )),
+        "s = \"\"\"\na\"\"b": Ok(Strings(
+          s: "a\"\"b",
+        )),
+        "s = \"\"\"\na\"\"b ": Ok(Strings(
+          s: "a\"\"b ",
+        )),
+        "s = \"\"\"\na\"\"b \\\n  c": Ok(Strings(
+          s: "a\"\"b c",
+        )),
+        "s = \"\"\"\na\"\"b \\\n  c\"": Ok(Strings(
+          s: "a\"\"b c\"",
+        )),
+        "s = \"\"\"\na\"\"b \\\n  c\"\"": Ok(Strings(
+          s: "a\"\"b c\"\"",
+        )),
+        "s = \"\"\"\na\"\"b \\\n  c\"\"\"": Ok(Strings(
+          s: "a\"\"b c",
+        )),
+      },
+      "default behavior except no randomized trailer": {
+        "": Ok(Strings(
+          s: "",
+        )),
+        "s = \"\"\"\na\"\"b \\\n  c\"\"\"": Ok(Strings(
+          s: "a\"\"b c",
+        )),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok(Strings(
+          s: "",
+        )),
+        "s ": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "s = \"": Ok(Strings(
+          s: "",
+        )),
+        "s = \"\"\"\na": Ok(Strings(
+          s: "a",
+        )),
+        "s = \"\"\"\na\"": Ok(Strings(
+          s: "a\"",
+        )),
+        "s = \"\"\"\na\"\"": Ok(Strings(
+          s: "a\"\"",
+        )),
+        "s = \"\"\"\na\"\"b": Ok(Strings(
+          s: "a\"\"b",
+        )),
+        "s = \"\"\"\na\"\"b ": Ok(Strings(
+          s: "a\"\"b ",
+        )),
+        "s = \"\"\"\na\"\"b \\\n  c": Ok(Strings(
+          s: "a\"\"b c",
+        )),
+        "s = \"\"\"\na\"\"b \\\n  c\"": Ok(Strings(
+          s: "a\"\"b c\"",
+        )),
+        "s = \"\"\"\na\"\"b \\\n  c\"\"": Ok(Strings(
+          s: "a\"\"b c\"\"",
+        )),
+        "s = \"\"\"\na\"\"b \\\n  c\"\"\"": Ok(Strings(
+          s: "a\"\"b c",
+        )),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Ok(Strings(
+          s: "",
+        )),
+        "s = \"\"\"\na": Ok(Strings(
+          s: "a",
+        )),
+        "s = \"\"\"\na\"": Ok(Strings(
+          s: "a\"",
+        )),
+        "s = \"\"\"\na\"\"": Ok(Strings(
+          s: "a\"\"",
+        )),
+        "s = \"\"\"\na\"\"b": Ok(Strings(
+          s: "a\"\"b",
+        )),
+        "s = \"\"\"\na\"\"b ": Ok(Strings(
+          s: "a\"\"b ",
+        )),
+        "s = \"\"\"\na\"\"b \\\n  c": Ok(Strings(
+          s: "a\"\"b c",
+        )),
+        "s = \"\"\"\na\"\"b \\\n  c\"": Ok(Strings(
+          s: "a\"\"b c\"",
+        )),
+        "s = \"\"\"\na\"\"b \\\n  c\"\"": Ok(Strings(
+          s: "a\"\"b c\"\"",
+        )),
+        "s = \"\"\"\na\"\"b \\\n  c\"\"\"": Ok(Strings(
+          s: "a\"\"b c",
+        )),
+      },
+      "strict behavior": {
+        "": Ok(Strings(
+          s: "",
+        )),
+        "s": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "s = \"": Ok(Strings(
+          s: "",
+        )),
+        "s = \"\"\"\na": Ok(Strings(
+          s: "a",
+        )),
+        "s = \"\"\"\na\"": Ok(Strings(
+          s: "a\"",
+        )),
+        "s = \"\"\"\na\"\"": Ok(Strings(
+          s: "a\"\"",
+        )),
+        "s = \"\"\"\na\"\"b": Ok(Strings(
+          s: "a\"\"b",
+        )),
+        "s = \"\"\"\na\"\"b ": Ok(Strings(
+          s: "a\"\"b ",
+        )),
+        "s = \"\"\"\na\"\"b \\\n  c": Ok(Strings(
+          s: "a\"\"b c",
+        )),
+        "s = \"\"\"\na\"\"b \\\n  c\"": Ok(Strings(
+          s: "a\"\"b c\"",
+        )),
+        "s = \"\"\"\na\"\"b \\\n  c\"\"": Ok(Strings(
+          s: "a\"\"b c\"\"",
+        )),
+        "s = \"\"\"\na\"\"b \\\n  c\"\"\"": Ok(Strings(
+          s: "a\"\"b c",
+        )),
+      },
+    }
+    "###)
+}
+
+#[test]
+fn test_multi_line_literal_string() {
+    insta::assert_ron_snapshot!(
+        run_toml_modes_on_prefixes_and_format_outputs::<Strings>(&default_modes(), "s = '''a\n'b''''"),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok(Strings(
+          s: "",
+        )),
+        "s = \'\'\'a": Ok(Strings(
+          s: "a",
+        )),
+        "s = \'\'\'a\n": Ok(Strings(
+          s: "a\n",
+        )),
+        "s = \'\'\'a\n\'": Ok(Strings(
+          s: "a\n\'",
+        )),
+        "s = \'\'\'a\n\'b": Ok(Strings(
+          s: "a\n\'b",
+        )),
+        "s = \'\'\'a\n\'b\'": Ok(Strings(
+          s: "a\n\'b\'",
+        )),
+        "s = \'\'\'a\n\'b\'\'": Ok(Strings(
+          s: "a\n\'b\'\'",
+        )),
+        "s = \'\'\'a\n\'b\'\'\'": Ok(Strings(
+          s: "a\n\'b",
+        )),
+        "s = \'\'\'a\n\'b\'\'\'\'": Ok(Strings(
+          s: "a\n\'b\'",
+        )),
+      },
+      "default behavior except no randomized trailer": {
+        "": Ok(Strings(
+          s: "",
+        )),
+        "s = \'\'\'a\n\'b\'\'\'": Ok(Strings(
+          s: "a\n\'b",
+        )),
+        "s = \'\'\'a\n\'b\'\'\'\'": Ok(Strings(
+          s: "a\n\'b\'",
+        )),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok(Strings(
+          s: "",
+        )),
+        "s ": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "s = \'": Ok(Strings(
+          s: "",
+        )),
+        "s = \'\'\'a": Ok(Strings(
+          s: "a",
+        )),
+        "s = \'\'\'a\n": Ok(Strings(
+          s: "a\n",
+        )),
+        "s = \'\'\'a\n\'": Ok(Strings(
+          s: "a\n\'",
+        )),
+        "s = \'\'\'a\n\'b": Ok(Strings(
+          s: "a\n\'b",
+        )),
+        "s = \'\'\'a\n\'b\'": Ok(Strings(
+          s: "a\n\'b\'",
+        )),
+        "s = \'\'\'a\n\'b\'\'": Ok(Strings(
+          s: "a\n\'b\'\'",
+        )),
+        "s = \'\'\'a\n\'b\'\'\'": Ok(Strings(
+          s: "a\n\'b",
+        )),
+        "s = \'\'\'a\n\'b\'\'\'\'": Ok(Strings(
+          s: "a\n\'b\'",
+        )),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Ok(Strings(
+          s: "",
+        )),
+        "s = \'\'\'a": Ok(Strings(
+          s: "a",
+        )),
+        "s = \'\'\'a\n": Ok(Strings(
+          s: "a\n",
+        )),
+        "s = \'\'\'a\n\'": Ok(Strings(
+          s: "a\n\'",
+        )),
+        "s = \'\'\'a\n\'b": Ok(Strings(
+          s: "a\n\'b",
+        )),
+        "s = \'\'\'a\n\'b\'": Ok(Strings(
+          s: "a\n\'b\'",
+        )),
+        "s = \'\'\'a\n\'b\'\'": Ok(Strings(
+          s: "a\n\'b\'\'",
+        )),
+        "s = \'\'\'a\n\'b\'\'\'": Ok(Strings(
+          s: "a\n\'b",
+        )),
+        "s = \'\'\'a\n\'b\'\'\'\'": Ok(Strings(
+          s: "a\n\'b\'",
+        )),
+      },
+      "strict behavior": {
+        "": Ok(Strings(
+          s: "",
+        )),
+        "s": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "s = \'": Ok(Strings(
+          s: "",
+        )),
+        "s = \'\'\'a": Ok(Strings(
+          s: "a",
+        )),
+        "s = \'\'\'a\n": Ok(Strings(
+          s: "a\n",
+        )),
+        "s = \'\'\'a\n\'": Ok(Strings(
+          s: "a\n\'",
+        )),
+        "s = \'\'\'a\n\'b": Ok(Strings(
+          s: "a\n\'b",
+        )),
+        "s = \'\'\'a\n\'b\'": Ok(Strings(
+          s: "a\n\'b\'",
+        )),
+        "s = \'\'\'a\n\'b\'\'": Ok(Strings(
+          s: "a\n\'b\'\'",
+        )),
+        "s = \'\'\'a\n\'b\'\'\'": Ok(Strings(
+          s: "a\n\'b",
+        )),
+        "s = \'\'\'a\n\'b\'\'\'\'": Ok(Strings(
+          s: "a\n\'b\'",
+        )),
+      },
+    }
+    "###)
+}
+
+#[test]
+fn test_unicode_string() {
+    insta::assert_ron_snapshot!(
+        run_toml_modes_on_prefixes_and_format_outputs::<Strings>(&default_modes(), "s = \"ü€\""),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok(Strings(
+          s: "",
+        )),
+        "s = \"ü": Ok(Strings(
+          s: "ü",
+        )),
+        "s = \"ü€": Ok(Strings(
+          s: "ü€",
+        )),
+      },
+      "default behavior except no randomized trailer": {
+        "": Ok(Strings(
+          s: "",
+        )),
+        "s = \"ü€\"": Ok(Strings(
+          s: "ü€",
+        )),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok(Strings(
+          s: "",
+        )),
+        "s ": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "s = \"": Ok(Strings(
+          s: "",
+        )),
+        "s = \"ü": Ok(Strings(
+          s: "ü",
+        )),
+        "s = \"ü€": Ok(Strings(
+          s: "ü€",
+        )),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Ok(Strings(
+          s: "",
+        )),
+        "s = \"ü": Ok(Strings(
+          s: "ü",
+        )),
+        "s = \"ü€": Ok(Strings(
+          s: "ü€",
+        )),
+      },
+      "strict behavior": {
+        "": Ok(Strings(
+          s: "",
+        )),
+        "s": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "s = \"": Ok(Strings(
+          s: "",
+        )),
+        "s = \"ü": Ok(Strings(
+          s: "ü",
+        )),
+        "s = \"ü€": Ok(Strings(
+          s: "ü€",
+        )),
+      },
+    }
+    "###)
+}
@@ -0,0 +1,453 @@
+use serde::{Deserialize, Serialize};
+
+use super::{default_modes, run_toml_modes_on_prefixes_and_format_outputs};
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+struct Struct {
+    x: bool,
+    y: u32,
+    z: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(default)]
+struct DefaultStruct {
+    x: bool,
+    y: u32,
+    z: Option<String>,
+}
+
+impl Default for DefaultStruct {
+    fn default() -> Self {
+        Self {
+            x: false,
+            y: 7,
+            z: None,
+        }
+    }
+}
+
+#[test]
+fn test_toplevel_struct() {
+    insta::assert_ron_snapshot!(
+        run_toml_modes_on_prefixes_and_format_outputs::<Struct>(&default_modes(), "x = true\ny = 12\nz = \"ab\"\n"),
+        @r###"
+    {
+      "default behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "x": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 1 backtracks)"),
+        "x = true": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "x = true\ny": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 1 backtracks)"),
+        "x = true\ny = 1": Ok(Struct(
+          x: true,
+          y: 1,
+          z: None,
+        )),
+        "x = true\ny = 12": Ok(Struct(
+          x: true,
+          y: 12,
+          z: None,
+        )),
+        "x = true\ny = 12\nz = \"": Ok(Struct(
+          x: true,
+          y: 12,
+          z: Some(""),
+        )),
+        "x = true\ny = 12\nz = \"a": Ok(Struct(
+          x: true,
+          y: 12,
+          z: Some("a"),
+        )),
+        "x = true\ny = 12\nz = \"ab": Ok(Struct(
+          x: true,
+          y: 12,
+          z: Some("ab"),
+        )),
+      },
+      "default behavior except no randomized trailer": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "x = true\ny = 1": Ok(Struct(
+          x: true,
+          y: 1,
+          z: None,
+        )),
+        "x = true\ny = 12": Ok(Struct(
+          x: true,
+          y: 12,
+          z: None,
+        )),
+        "x = true\ny = 12\nz": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "x = true\ny = 12\nz = \"ab\"": Ok(Struct(
+          x: true,
+          y: 12,
+          z: Some("ab"),
+        )),
+      },
+      "default behavior, 0 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "x": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "x = true": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "x = true\ny": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "x = true\ny = 1": Ok(Struct(
+          x: true,
+          y: 1,
+          z: None,
+        )),
+        "x = true\ny = 12": Ok(Struct(
+          x: true,
+          y: 12,
+          z: None,
+        )),
+        "x = true\ny = 12\nz ": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "x = true\ny = 12\nz = \"": Ok(Struct(
+          x: true,
+          y: 12,
+          z: Some(""),
+        )),
+        "x = true\ny = 12\nz = \"a": Ok(Struct(
+          x: true,
+          y: 12,
+          z: Some("a"),
+        )),
+        "x = true\ny = 12\nz = \"ab": Ok(Struct(
+          x: true,
+          y: 12,
+          z: Some("ab"),
+        )),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "x": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 1 backtracks)"),
+        "x = true": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "x = true\ny": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 1 backtracks)"),
+        "x = true\ny = 1": Ok(Struct(
+          x: true,
+          y: 1,
+          z: None,
+        )),
+        "x = true\ny = 12": Ok(Struct(
+          x: true,
+          y: 12,
+          z: None,
+        )),
+        "x = true\ny = 12\nz = \"": Ok(Struct(
+          x: true,
+          y: 12,
+          z: Some(""),
+        )),
+        "x = true\ny = 12\nz = \"a": Ok(Struct(
+          x: true,
+          y: 12,
+          z: Some("a"),
+        )),
+        "x = true\ny = 12\nz = \"ab": Ok(Struct(
+          x: true,
+          y: 12,
+          z: Some("ab"),
+        )),
+      },
+      "strict behavior": {
+        "": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "x = true\ny = 1": Ok(Struct(
+          x: true,
+          y: 1,
+          z: None,
+        )),
+        "x = true\ny = 12": Ok(Struct(
+          x: true,
+          y: 12,
+          z: None,
+        )),
+        "x = true\ny = 12\nz": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "x = true\ny = 12\nz = \"": Ok(Struct(
+          x: true,
+          y: 12,
+          z: Some(""),
+        )),
+        "x = true\ny = 12\nz = \"a": Ok(Struct(
+          x: true,
+          y: 12,
+          z: Some("a"),
+        )),
+        "x = true\ny = 12\nz = \"ab": Ok(Struct(
+          x: true,
+          y: 12,
+          z: Some("ab"),
+        )),
+      },
+    }
+    "###)
+}
+
+#[test]
+fn test_toplevel_default_struct() {
+    insta::assert_ron_snapshot!(
+        run_toml_modes_on_prefixes_and_format_outputs::<DefaultStruct>(&default_modes(), "x = true\ny = 12\nz = \"ab\"\n"),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok(DefaultStruct(
+          x: false,
+          y: 7,
+          z: None,
+        )),
+        "x = true": Ok(DefaultStruct(
+          x: true,
+          y: 7,
+          z: None,
+        )),
+        "x = true\ny = 1": Ok(DefaultStruct(
+          x: true,
+          y: 1,
+          z: None,
+        )),
+        "x = true\ny = 12": Ok(DefaultStruct(
+          x: true,
+          y: 12,
+          z: None,
+        )),
+        "x = true\ny = 12\nz = \"": Ok(DefaultStruct(
+          x: true,
+          y: 12,
+          z: Some(""),
+        )),
+        "x = true\ny = 12\nz = \"a": Ok(DefaultStruct(
+          x: true,
+          y: 12,
+          z: Some("a"),
+        )),
+        "x = true\ny = 12\nz = \"ab": Ok(DefaultStruct(
+          x: true,
+          y: 12,
+          z: Some("ab"),
+        )),
+      },
+      "default behavior except no randomized trailer": {
+        "": Ok(DefaultStruct(
+          x: false,
+          y: 7,
+          z: None,
+        )),
+        "x = true": Ok(DefaultStruct(
+          x: true,
+          y: 7,
+          z: None,
+        )),
+        "x = true\ny": Ok(DefaultStruct(
+          x: false,
+          y: 7,
+          z: None,
+        )),
+        "x = true\ny = 1": Ok(DefaultStruct(
+          x: true,
+          y: 1,
+          z: None,
+        )),
+        "x = true\ny = 12": Ok(DefaultStruct(
+          x: true,
+          y: 12,
+          z: None,
+        )),
+        "x = true\ny = 12\nz": Ok(DefaultStruct(
+          x: false,
+          y: 7,
+          z: None,
+        )),
+        "x = true\ny = 12\nz = \"ab\"": Ok(DefaultStruct(
+          x: true,
+          y: 12,
+          z: Some("ab"),
+        )),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok(DefaultStruct(
+          x: false,
+          y: 7,
+          z: None,
+        )),
+        "x ": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "x = true": Ok(DefaultStruct(
+          x: true,
+          y: 7,
+          z: None,
+        )),
+        "x = true\ny ": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "x = true\ny = 1": Ok(DefaultStruct(
+          x: true,
+          y: 1,
+          z: None,
+        )),
+        "x = true\ny = 12": Ok(DefaultStruct(
+          x: true,
+          y: 12,
+          z: None,
+        )),
+        "x = true\ny = 12\nz ": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "x = true\ny = 12\nz = \"": Ok(DefaultStruct(
+          x: true,
+          y: 12,
+          z: Some(""),
+        )),
+        "x = true\ny = 12\nz = \"a": Ok(DefaultStruct(
+          x: true,
+          y: 12,
+          z: Some("a"),
+        )),
+        "x = true\ny = 12\nz = \"ab": Ok(DefaultStruct(
+          x: true,
+          y: 12,
+          z: Some("ab"),
+        )),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Ok(DefaultStruct(
+          x: false,
+          y: 7,
+          z: None,
+        )),
+        "x = true": Ok(DefaultStruct(
+          x: true,
+          y: 7,
+          z: None,
+        )),
+        "x = true\ny = 1": Ok(DefaultStruct(
+          x: true,
+          y: 1,
+          z: None,
+        )),
+        "x = true\ny = 12": Ok(DefaultStruct(
+          x: true,
+          y: 12,
+          z: None,
+        )),
+        "x = true\ny = 12\nz = \"": Ok(DefaultStruct(
+          x: true,
+          y: 12,
+          z: Some(""),
+        )),
+        "x = true\ny = 12\nz = \"a": Ok(DefaultStruct(
+          x: true,
+          y: 12,
+          z: Some("a"),
+        )),
+        "x = true\ny = 12\nz = \"ab": Ok(DefaultStruct(
+          x: true,
+          y: 12,
+          z: Some("ab"),
+        )),
+      },
+      "strict behavior": {
+        "": Ok(DefaultStruct(
+          x: false,
+          y: 7,
+          z: None,
+        )),
+        "x": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "x = true": Ok(DefaultStruct(
+          x: true,
+          y: 7,
+          z: None,
+        )),
+        "x = true\ny": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "x = true\ny = 1": Ok(DefaultStruct(
+          x: true,
+          y: 1,
+          z: None,
+        )),
+        "x = true\ny = 12": Ok(DefaultStruct(
+          x: true,
+          y: 12,
+          z: None,
+        )),
+        "x = true\ny = 12\nz": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "x = true\ny = 12\nz = \"": Ok(DefaultStruct(
+          x: true,
+          y: 12,
+          z: Some(""),
+        )),
+        "x = true\ny = 12\nz = \"a": Ok(DefaultStruct(
+          x: true,
+          y: 12,
+          z: Some("a"),
+        )),
+        "x = true\ny = 12\nz = \"ab": Ok(DefaultStruct(
+          x: true,
+          y: 12,
+          z: Some("ab"),
+        )),
+      },
+    }
+    "###)
+}
+
+#[test]
+fn test_comments() {
+    insta::assert_ron_snapshot!(
+        run_toml_modes_on_prefixes_and_format_outputs::<DefaultStruct>(&default_modes(), "# x\ny = 3 # y\n"),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok(DefaultStruct(
+          x: false,
+          y: 7,
+          z: None,
+        )),
+        "# x\ny = 3": Ok(DefaultStruct(
+          x: false,
+          y: 3,
+          z: None,
+        )),
+      },
+      "default behavior except no randomized trailer": {
+        "": Ok(DefaultStruct(
+          x: false,
+          y: 7,
+          z: None,
+        )),
+        "# x\ny = 3": Ok(DefaultStruct(
+          x: false,
+          y: 3,
+          z: None,
+        )),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok(DefaultStruct(
+          x: false,
+          y: 7,
+          z: None,
+        )),
+        "# x\ny ": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "# x\ny = 3": Ok(DefaultStruct(
+          x: false,
+          y: 3,
+          z: None,
+        )),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Ok(DefaultStruct(
+          x: false,
+          y: 7,
+          z: None,
+        )),
+        "# x\ny = 3": Ok(DefaultStruct(
+          x: false,
+          y: 3,
+          z: None,
+        )),
+      },
+      "strict behavior": {
+        "": Ok(DefaultStruct(
+          x: false,
+          y: 7,
+          z: None,
+        )),
+        "# x\ny": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "# x\ny = 3": Ok(DefaultStruct(
+          x: false,
+          y: 3,
+          z: None,
+        )),
+      },
+    }
+    "###)
+}
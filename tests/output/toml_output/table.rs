@@ -0,0 +1,945 @@
+use serde::{Deserialize, Serialize};
+
+use super::{default_modes, run_toml_modes_on_prefixes_and_format_outputs};
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+#[serde(default)]
+struct Document {
+    title: String,
+    server: Server,
+    user: Vec<User>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+#[serde(default)]
+struct Server {
+    host: String,
+    port: u16,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+#[serde(default)]
+struct User {
+    name: String,
+}
+
+#[test]
+fn test_table() {
+    insta::assert_ron_snapshot!(
+        run_toml_modes_on_prefixes_and_format_outputs::<Document>(&default_modes(), "title = \"t\"\n[server]\nhost = \"h\"\nport = 80\n"),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "title = \"t": Ok(Document(
+          title: "t",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "title = \"t\"\n[server]\nhost = \"h": Ok(Document(
+          title: "t",
+          server: Server(
+            host: "h",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "title = \"t\"\n[server]\nhost = \"h\"\nport = 8": Ok(Document(
+          title: "t",
+          server: Server(
+            host: "h",
+            port: 8,
+          ),
+          user: [],
+        )),
+        "title = \"t\"\n[server]\nhost = \"h\"\nport = 80": Ok(Document(
+          title: "t",
+          server: Server(
+            host: "h",
+            port: 80,
+          ),
+          user: [],
+        )),
+      },
+      "default behavior except no randomized trailer": {
+        "": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "title = \"t\"": Ok(Document(
+          title: "t",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "title = \"t\"\n[": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "title = \"t\"\n[server]": Ok(Document(
+          title: "t",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "title = \"t\"\n[server]\nh": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "title = \"t\"\n[server]\nhost = \"h\"": Ok(Document(
+          title: "t",
+          server: Server(
+            host: "h",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "title = \"t\"\n[server]\nhost = \"h\"\np": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "title = \"t\"\n[server]\nhost = \"h\"\nport = 8": Ok(Document(
+          title: "t",
+          server: Server(
+            host: "h",
+            port: 8,
+          ),
+          user: [],
+        )),
+        "title = \"t\"\n[server]\nhost = \"h\"\nport = 80": Ok(Document(
+          title: "t",
+          server: Server(
+            host: "h",
+            port: 80,
+          ),
+          user: [],
+        )),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "title ": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "title = \"": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "title = \"t": Ok(Document(
+          title: "t",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "title = \"t\"\n[server]\nhost ": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "title = \"t\"\n[server]\nhost = \"": Ok(Document(
+          title: "t",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "title = \"t\"\n[server]\nhost = \"h": Ok(Document(
+          title: "t",
+          server: Server(
+            host: "h",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "title = \"t\"\n[server]\nhost = \"h\"\nport ": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "title = \"t\"\n[server]\nhost = \"h\"\nport = 8": Ok(Document(
+          title: "t",
+          server: Server(
+            host: "h",
+            port: 8,
+          ),
+          user: [],
+        )),
+        "title = \"t\"\n[server]\nhost = \"h\"\nport = 80": Ok(Document(
+          title: "t",
+          server: Server(
+            host: "h",
+            port: 80,
+          ),
+          user: [],
+        )),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "title = \"t": Ok(Document(
+          title: "t",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "title = \"t\"\n[server]\nhost = \"h": Ok(Document(
+          title: "t",
+          server: Server(
+            host: "h",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "title = \"t\"\n[server]\nhost = \"h\"\nport = 8": Ok(Document(
+          title: "t",
+          server: Server(
+            host: "h",
+            port: 8,
+          ),
+          user: [],
+        )),
+        "title = \"t\"\n[server]\nhost = \"h\"\nport = 80": Ok(Document(
+          title: "t",
+          server: Server(
+            host: "h",
+            port: 80,
+          ),
+          user: [],
+        )),
+      },
+      "strict behavior": {
+        "": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "t": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "title = \"": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "title = \"t": Ok(Document(
+          title: "t",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "title = \"t\"\n[": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "title = \"t\"\n[server]": Ok(Document(
+          title: "t",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "title = \"t\"\n[server]\nh": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "title = \"t\"\n[server]\nhost = \"": Ok(Document(
+          title: "t",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "title = \"t\"\n[server]\nhost = \"h": Ok(Document(
+          title: "t",
+          server: Server(
+            host: "h",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "title = \"t\"\n[server]\nhost = \"h\"\np": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "title = \"t\"\n[server]\nhost = \"h\"\nport = 8": Ok(Document(
+          title: "t",
+          server: Server(
+            host: "h",
+            port: 8,
+          ),
+          user: [],
+        )),
+        "title = \"t\"\n[server]\nhost = \"h\"\nport = 80": Ok(Document(
+          title: "t",
+          server: Server(
+            host: "h",
+            port: 80,
+          ),
+          user: [],
+        )),
+      },
+    }
+    "###)
+}
+
+#[test]
+fn test_dotted_keys() {
+    insta::assert_ron_snapshot!(
+        run_toml_modes_on_prefixes_and_format_outputs::<Document>(&default_modes(), "server.port = 80\nserver . host = 'h'"),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "server.port = 8": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 8,
+          ),
+          user: [],
+        )),
+        "server.port = 80": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 80,
+          ),
+          user: [],
+        )),
+        "server.port = 80\nserver . host = \'h": Ok(Document(
+          title: "",
+          server: Server(
+            host: "h",
+            port: 80,
+          ),
+          user: [],
+        )),
+      },
+      "default behavior except no randomized trailer": {
+        "": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "server.port = 8": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 8,
+          ),
+          user: [],
+        )),
+        "server.port = 80": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 80,
+          ),
+          user: [],
+        )),
+        "server.port = 80\ns": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "server.port = 80\nserver . host = \'h\'": Ok(Document(
+          title: "",
+          server: Server(
+            host: "h",
+            port: 80,
+          ),
+          user: [],
+        )),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "server.port ": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "server.port = 8": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 8,
+          ),
+          user: [],
+        )),
+        "server.port = 80": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 80,
+          ),
+          user: [],
+        )),
+        "server.port = 80\nserver . host ": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "server.port = 80\nserver . host = \'": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 80,
+          ),
+          user: [],
+        )),
+        "server.port = 80\nserver . host = \'h": Ok(Document(
+          title: "",
+          server: Server(
+            host: "h",
+            port: 80,
+          ),
+          user: [],
+        )),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "server.port = 8": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 8,
+          ),
+          user: [],
+        )),
+        "server.port = 80": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 80,
+          ),
+          user: [],
+        )),
+        "server.port = 80\nserver . host = \'h": Ok(Document(
+          title: "",
+          server: Server(
+            host: "h",
+            port: 80,
+          ),
+          user: [],
+        )),
+      },
+      "strict behavior": {
+        "": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "s": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "server.port = 8": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 8,
+          ),
+          user: [],
+        )),
+        "server.port = 80": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 80,
+          ),
+          user: [],
+        )),
+        "server.port = 80\ns": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "server.port = 80\nserver . host = \'": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 80,
+          ),
+          user: [],
+        )),
+        "server.port = 80\nserver . host = \'h": Ok(Document(
+          title: "",
+          server: Server(
+            host: "h",
+            port: 80,
+          ),
+          user: [],
+        )),
+      },
+    }
+    "###)
+}
+
+#[test]
+fn test_array_of_tables() {
+    insta::assert_ron_snapshot!(
+        run_toml_modes_on_prefixes_and_format_outputs::<Document>(&default_modes(), "[[user]]\nname = \"a\"\n\n[[user]]\nname = \"b\"\n"),
+        @r###"
+    {
+      "default behavior": {
+        "": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "[[user]": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [
+            User(
+              name: "",
+            ),
+          ],
+        )),
+        "[[user]]\nname = \"a": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [
+            User(
+              name: "a",
+            ),
+          ],
+        )),
+        "[[user]]\nname = \"a\"\n\n[[user]": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [
+            User(
+              name: "a",
+            ),
+            User(
+              name: "",
+            ),
+          ],
+        )),
+        "[[user]]\nname = \"a\"\n\n[[user]]\nname = \"b": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [
+            User(
+              name: "a",
+            ),
+            User(
+              name: "b",
+            ),
+          ],
+        )),
+      },
+      "default behavior except no randomized trailer": {
+        "": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "[[user]]": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [
+            User(
+              name: "",
+            ),
+          ],
+        )),
+        "[[user]]\nn": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "[[user]]\nname = \"a\"": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [
+            User(
+              name: "a",
+            ),
+          ],
+        )),
+        "[[user]]\nname = \"a\"\n\n[": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "[[user]]\nname = \"a\"\n\n[[user]]": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [
+            User(
+              name: "a",
+            ),
+            User(
+              name: "",
+            ),
+          ],
+        )),
+        "[[user]]\nname = \"a\"\n\n[[user]]\nn": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "[[user]]\nname = \"a\"\n\n[[user]]\nname = \"b\"": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [
+            User(
+              name: "a",
+            ),
+            User(
+              name: "b",
+            ),
+          ],
+        )),
+      },
+      "default behavior, 0 backtracks": {
+        "": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "[[user]": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [
+            User(
+              name: "",
+            ),
+          ],
+        )),
+        "[[user]]\nname ": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[[user]]\nname = \"": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [
+            User(
+              name: "",
+            ),
+          ],
+        )),
+        "[[user]]\nname = \"a": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [
+            User(
+              name: "a",
+            ),
+          ],
+        )),
+        "[[user]]\nname = \"a\"\n\n[[user]": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [
+            User(
+              name: "a",
+            ),
+            User(
+              name: "",
+            ),
+          ],
+        )),
+        "[[user]]\nname = \"a\"\n\n[[user]]\nname ": Err("the maximum number of backtracks has been exceeded (see tracing logs for pointers to avoid a high number of backtracks)"),
+        "[[user]]\nname = \"a\"\n\n[[user]]\nname = \"": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [
+            User(
+              name: "a",
+            ),
+            User(
+              name: "",
+            ),
+          ],
+        )),
+        "[[user]]\nname = \"a\"\n\n[[user]]\nname = \"b": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [
+            User(
+              name: "a",
+            ),
+            User(
+              name: "b",
+            ),
+          ],
+        )),
+      },
+      "no fallbacks, 1 backtracks": {
+        "": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "[[user]": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [
+            User(
+              name: "",
+            ),
+          ],
+        )),
+        "[[user]]\nname = \"a": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [
+            User(
+              name: "a",
+            ),
+          ],
+        )),
+        "[[user]]\nname = \"a\"\n\n[[user]": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [
+            User(
+              name: "a",
+            ),
+            User(
+              name: "",
+            ),
+          ],
+        )),
+        "[[user]]\nname = \"a\"\n\n[[user]]\nname = \"b": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [
+            User(
+              name: "a",
+            ),
+            User(
+              name: "b",
+            ),
+          ],
+        )),
+      },
+      "strict behavior": {
+        "": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [],
+        )),
+        "[": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "[[user]": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [
+            User(
+              name: "",
+            ),
+          ],
+        )),
+        "[[user]]\nn": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "[[user]]\nname = \"": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [
+            User(
+              name: "",
+            ),
+          ],
+        )),
+        "[[user]]\nname = \"a": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [
+            User(
+              name: "a",
+            ),
+          ],
+        )),
+        "[[user]]\nname = \"a\"\n\n[": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "[[user]]\nname = \"a\"\n\n[[user]": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [
+            User(
+              name: "a",
+            ),
+            User(
+              name: "",
+            ),
+          ],
+        )),
+        "[[user]]\nname = \"a\"\n\n[[user]]\nn": Err("could not find a potential backtrack point (do you have #[serde(default)] on your top-level type? are your settings too strict?) (after 0 backtracks)"),
+        "[[user]]\nname = \"a\"\n\n[[user]]\nname = \"": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [
+            User(
+              name: "a",
+            ),
+            User(
+              name: "",
+            ),
+          ],
+        )),
+        "[[user]]\nname = \"a\"\n\n[[user]]\nname = \"b": Ok(Document(
+          title: "",
+          server: Server(
+            host: "",
+            port: 0,
+          ),
+          user: [
+            User(
+              name: "a",
+            ),
+            User(
+              name: "b",
+            ),
+          ],
+        )),
+      },
+    }
+    "###)
+}
@@ -1,3 +1,5 @@
+use deser_incomplete::Options;
+
 use super::{default_modes, run_yaml_modes_on_prefixes_and_format_outputs};
 
 #[test]
@@ -1763,3 +1765,29 @@ fn test_f32() {
     "###
     );
 }
+
+#[test]
+fn test_withhold_trailing_numbers() {
+    insta::assert_ron_snapshot!(
+        run_yaml_modes_on_prefixes_and_format_outputs::<Vec<f64>>(
+            &[(
+                "withhold trailing numbers",
+                Options::new_yaml().withhold_trailing_numbers()
+            )],
+            &"- 12\n- -3.5e2\n"
+        ),
+        @r###"
+    {
+      "withhold trailing numbers": {
+        "": Ok([]),
+        "- 12\n": Ok([
+          12.0,
+        ]),
+        "- 12\n- -3.5e2\n": Ok([
+          12.0,
+          -350.0,
+        ]),
+      },
+    }
+    "###);
+}
@@ -0,0 +1,101 @@
+//! Replay recorded Server-Sent Events streams in chunks of different sizes.
+
+#![cfg(all(feature = "serde_json", feature = "rand"))]
+
+use deser_incomplete::SseJsonFragments;
+
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize)]
+struct Weather {
+    #[serde(default)]
+    location: String,
+    #[serde(default)]
+    unit: String,
+    #[serde(default)]
+    days: Vec<u32>,
+}
+
+fn fixture(name: &str) -> Vec<u8> {
+    let path = format!("{}/tests/sse_fixtures/{name}", env!("CARGO_MANIFEST_DIR"));
+    std::fs::read(&path).unwrap_or_else(|err| panic!("cannot read {path}: {err}"))
+}
+
+/// Push the stream in chunks, and check that the partial values only grow.
+fn replay(name: &str, pointer: &str, chunk_size: usize) -> (Vec<Weather>, Weather) {
+    let mut fragments: SseJsonFragments<Weather> = SseJsonFragments::new_json(pointer);
+    let mut partials: Vec<Weather> = Vec::new();
+    for chunk in fixture(name).chunks(chunk_size) {
+        if let Some(weather) = fragments.push(chunk).unwrap() {
+            if let Some(previous) = partials.last() {
+                assert!(weather.location.starts_with(&previous.location));
+                assert!(weather.days.starts_with(&previous.days));
+            }
+            partials.push(weather);
+        }
+    }
+    (partials, fragments.finish().unwrap())
+}
+
+#[test]
+fn test_openai_tool_call() {
+    let pointer = "/choices/0/delta/tool_calls/0/function/arguments";
+    for chunk_size in [1, 7, 64, 100_000] {
+        let (partials, weather) = replay("openai-tool-call.sse", pointer, chunk_size);
+        assert_eq!(
+            weather,
+            Weather {
+                location: "Paris, France".into(),
+                unit: "celsius".into(),
+                days: vec![1, 2, 3],
+            }
+        );
+        assert_eq!(partials.last(), Some(&weather));
+    }
+
+    let (partials, _) = replay("openai-tool-call.sse", pointer, 1);
+    assert!(partials.iter().any(|weather| weather.location == "Paris"));
+}
+
+#[test]
+fn test_anthropic_tool_use() {
+    for chunk_size in [1, 7, 64, 100_000] {
+        let (_, weather) = replay("anthropic-tool-use.sse", "/delta/partial_json", chunk_size);
+        assert_eq!(
+            weather,
+            Weather {
+                location: "San Francisco, CA".into(),
+                unit: "fahrenheit".into(),
+                days: vec![4, 5],
+            }
+        );
+    }
+
+    let (partials, _) = replay("anthropic-tool-use.sse", "/delta/partial_json", 100_000);
+    assert_eq!(partials.len(), 1);
+}
+
+#[test]
+fn test_error_event() {
+    for chunk_size in [1, 7, 100_000] {
+        let (_, weather) = replay("error-event.sse", "/delta/partial_json", chunk_size);
+        assert_eq!(
+            weather,
+            Weather {
+                location: "Oslo".into(),
+                unit: String::new(),
+                days: vec![7],
+            }
+        );
+    }
+}
+
+#[test]
+fn test_cut_off_stream() {
+    let stream = fixture("anthropic-tool-use.sse");
+    let cut = stream.len() / 2;
+    let mut fragments: SseJsonFragments<Weather> =
+        SseJsonFragments::new_json("/delta/partial_json");
+    fragments.push(&stream[..cut]).unwrap();
+    let json = String::from_utf8(fragments.json().to_vec()).unwrap();
+    let weather = fragments.finish().unwrap();
+    assert!("San Francisco, CA".starts_with(&weather.location), "{json}");
+}